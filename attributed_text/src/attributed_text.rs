@@ -1,17 +1,75 @@
 // Copyright 2025 the Parley Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use alloc::collections::BTreeSet;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::ops::Range;
 
-use crate::{Endpoint, Error, TextStorage};
+use crate::span_index::SpanIndex;
+use crate::{Endpoint, Error, KeyedAttribute, SpanExpansion, TextStorage, TextStorageMut};
 
 /// A block of text with attributes applied to ranges within the text.
 #[derive(Debug)]
 pub struct AttributedText<T: Debug + TextStorage, Attr: Debug> {
     text: T,
-    attributes: Vec<(Range<usize>, Attr)>,
+    attributes: Vec<AttributeSpan<Attr>>,
+    index: SpanIndex,
+}
+
+/// An attribute applied to a range of the text.
+#[derive(Debug)]
+struct AttributeSpan<Attr: Debug> {
+    range: Range<usize>,
+    attribute: Attr,
+    expansion: SpanExpansion,
+}
+
+impl<Attr: Debug> AttributeSpan<Attr> {
+    /// Adjust the range of the span for the replacement of the text in `range` with `inserted`
+    /// bytes, as described in [`AttributedText::replace_range`].
+    ///
+    /// Returns `false` if the span ends up empty because of the edit.
+    fn replace_range(&mut self, range: &Range<usize>, inserted: usize) -> bool {
+        let deleted = range.end - range.start;
+        let was_empty = self.range.is_empty();
+
+        // Remove the replaced text.
+        let delete = |index: usize| {
+            if index <= range.start {
+                index
+            } else if index >= range.end {
+                index - deleted
+            } else {
+                range.start
+            }
+        };
+        let start = delete(self.range.start);
+        let end = delete(self.range.end);
+
+        // Insert the replacement at the start of the range. A span whose text was entirely
+        // replaced takes the replacement if it grows at either end.
+        let at = range.start;
+        if !was_empty && start == end {
+            let expands = self.expansion.includes_start() || self.expansion.includes_end();
+            self.range = if expands { at..at + inserted } else { at..at };
+            return !self.range.is_empty();
+        }
+        let start =
+            if inserted > 0 && (at < start || (at == start && !self.expansion.includes_start())) {
+                start + inserted
+            } else {
+                start
+            };
+        let end = if inserted > 0 && (at < end || (at == end && self.expansion.includes_end())) {
+            end + inserted
+        } else {
+            end
+        };
+
+        self.range = start..end.max(start);
+        was_empty || !self.range.is_empty()
+    }
 }
 
 impl<T: Debug + TextStorage, Attr: Debug> AttributedText<T, Attr> {
@@ -20,6 +78,7 @@ impl<T: Debug + TextStorage, Attr: Debug> AttributedText<T, Attr> {
         Self {
             text,
             attributes: Vec::default(),
+            index: SpanIndex::default(),
         }
     }
 
@@ -47,75 +106,195 @@ impl<T: Debug + TextStorage, Attr: Debug> AttributedText<T, Attr> {
     }
 
     /// Apply an `attribute` to a `range` within the text.
+    ///
+    /// The span uses the default [`SpanExpansion`] when the text is edited.
     pub fn apply_attribute(&mut self, range: Range<usize>, attribute: Attr) -> Result<(), Error> {
-        let text_len = self.text.len();
-        if range.start > range.end {
-            return Err(Error::invalid_range(range.start, range.end, text_len));
-        }
-        if range.start > text_len || range.end > text_len {
-            return Err(Error::invalid_bounds(range.start, range.end, text_len));
-        }
-        if !self.text.is_char_boundary(range.start) {
-            return Err(Error::not_on_char_boundary(
-                &self.text,
-                range.start,
-                range.end,
-                text_len,
-                Endpoint::Start,
-                range.start,
-            ));
-        }
-        if !self.text.is_char_boundary(range.end) {
-            return Err(Error::not_on_char_boundary(
-                &self.text,
-                range.start,
-                range.end,
-                text_len,
-                Endpoint::End,
-                range.end,
-            ));
-        }
-        self.attributes.push((range, attribute));
+        self.apply_attribute_with_expansion(range, attribute, SpanExpansion::default())
+    }
+
+    /// Apply an `attribute` to a `range` within the text, with the given `expansion` behavior
+    /// for text inserted at the ends of the span.
+    pub fn apply_attribute_with_expansion(
+        &mut self,
+        range: Range<usize>,
+        attribute: Attr,
+        expansion: SpanExpansion,
+    ) -> Result<(), Error> {
+        self.check_range(&range)?;
+        self.index.insert(range.clone(), self.attributes.len());
+        self.attributes.push(AttributeSpan {
+            range,
+            attribute,
+            expansion,
+        });
         Ok(())
     }
 
+    /// Replace the text in `range` with `replacement`, adjusting the attribute spans.
+    ///
+    /// Spans after the edit are shifted, and spans overlapping the replaced text are shrunk to
+    /// exclude it. The replacement text is then covered by the spans that contain the start of
+    /// `range`, as decided by their [`SpanExpansion`], and by the spans whose text was entirely
+    /// replaced unless they are [`SpanExpansion::Exclusive`]. Spans that end up empty because of
+    /// the edit are removed.
+    pub fn replace_range(&mut self, range: Range<usize>, replacement: &str) -> Result<(), Error>
+    where
+        T: TextStorageMut,
+    {
+        self.check_range(&range)?;
+        self.text.replace_range(range.clone(), replacement);
+
+        let inserted = replacement.len();
+        // The new ids of the spans, or `None` for the removed ones.
+        let mut new_ids = Vec::with_capacity(self.attributes.len());
+        let mut next_id = 0;
+        self.attributes.retain_mut(|span| {
+            let keep = span.replace_range(&range, inserted);
+            new_ids.push(keep.then(|| {
+                next_id += 1;
+                next_id - 1
+            }));
+            keep
+        });
+        let attributes = &self.attributes;
+        self.index.update(|id| {
+            let new_id = new_ids[id]?;
+            Some((new_id, attributes[new_id].range.clone()))
+        });
+        Ok(())
+    }
+
+    /// Insert `text` at `index`, adjusting the attribute spans.
+    ///
+    /// See [`AttributedText::replace_range`].
+    pub fn insert(&mut self, index: usize, text: &str) -> Result<(), Error>
+    where
+        T: TextStorageMut,
+    {
+        self.replace_range(index..index, text)
+    }
+
+    /// Delete the text in `range`, adjusting the attribute spans.
+    ///
+    /// See [`AttributedText::replace_range`].
+    pub fn delete(&mut self, range: Range<usize>) -> Result<(), Error>
+    where
+        T: TextStorageMut,
+    {
+        self.replace_range(range, "")
+    }
+
     /// Iterate over all attributes and the ranges they apply to.
     ///
     /// Attributes are yielded in the order they were applied.
     pub fn attributes_iter(&self) -> impl ExactSizeIterator<Item = (&Range<usize>, &Attr)> {
-        self.attributes.iter().map(|(range, attr)| (range, attr))
+        self.attributes
+            .iter()
+            .map(|span| (&span.range, &span.attribute))
     }
 
     /// Get an iterator over the attributes that apply at the given `index`.
     ///
+    /// Attributes are yielded in the order they were applied. This takes logarithmic time in the
+    /// number of spans, plus the number of attributes yielded.
+    ///
     /// This doesn't handle conflicting attributes, it just reports everything.
     ///
     /// TODO: Decide if this should also return the spans' ranges.
     pub fn attributes_at(&self, index: usize) -> impl Iterator<Item = &Attr> {
-        self.attributes.iter().filter_map(move |(attr_span, attr)| {
-            if attr_span.contains(&index) {
-                Some(attr)
-            } else {
-                None
-            }
-        })
+        self.attributes_for_range(index..index.saturating_add(1))
     }
 
     /// Get an iterator over the attributes that apply to the given `range`.
     ///
+    /// Attributes are yielded in the order they were applied. This takes logarithmic time in the
+    /// number of spans, plus the number of attributes yielded.
+    ///
     /// This doesn't handle conflicting attributes, it just reports everything.
     ///
     /// TODO: Decide if this should also return the spans' ranges.
     pub fn attributes_for_range(&self, range: Range<usize>) -> impl Iterator<Item = &Attr> {
-        self.attributes.iter().filter_map(move |(attr_span, attr)| {
-            if (attr_span.start < range.end) && (attr_span.end > range.start) {
-                Some(attr)
-            } else {
-                None
+        self.index
+            .overlapping(range)
+            .into_iter()
+            .map(|id| &self.attributes[id].attribute)
+    }
+
+    /// Iterate over the maximal runs of the text in which the set of applied attributes is
+    /// constant.
+    ///
+    /// The runs cover the whole text, in order, including runs with no attributes. The
+    /// attributes of each run are in the order they were applied.
+    pub fn style_runs(&self) -> impl Iterator<Item = (Range<usize>, Vec<&Attr>)> {
+        let mut starts = Vec::new();
+        let mut ends = Vec::new();
+        for (id, span) in self.attributes.iter().enumerate() {
+            if !span.range.is_empty() {
+                starts.push((span.range.start, id));
+                ends.push((span.range.end, id));
+            }
+        }
+        starts.sort_unstable();
+        ends.sort_unstable();
+
+        let len = self.text.len();
+        let mut active = BTreeSet::new();
+        let (mut next_start, mut next_end) = (0, 0);
+        let mut position = 0;
+        core::iter::from_fn(move || {
+            if position >= len {
+                return None;
+            }
+            while let Some(&(_, id)) = ends.get(next_end).filter(|end| end.0 == position) {
+                active.remove(&id);
+                next_end += 1;
+            }
+            while let Some(&(_, id)) = starts.get(next_start).filter(|start| start.0 == position) {
+                active.insert(id);
+                next_start += 1;
             }
+            let run_end = [starts.get(next_start), ends.get(next_end)]
+                .into_iter()
+                .flatten()
+                .map(|&(index, _)| index)
+                .fold(len, usize::min);
+            let run = position..run_end;
+            position = run_end;
+            let attributes = active
+                .iter()
+                .map(|&id| &self.attributes[id].attribute)
+                .collect();
+            Some((run, attributes))
         })
     }
 
+    /// Get the effective attributes at the given `index`.
+    ///
+    /// Of the attributes that apply at `index` and share a [key](KeyedAttribute), only the one
+    /// applied last is effective. The effective attributes are yielded in the order they were
+    /// applied.
+    pub fn resolved_at(&self, index: usize) -> impl Iterator<Item = &Attr>
+    where
+        Attr: KeyedAttribute,
+    {
+        let mut resolved: Vec<&Attr> = Vec::new();
+        let mut keys = Vec::new();
+        for id in self
+            .index
+            .overlapping(index..index.saturating_add(1))
+            .into_iter()
+            .rev()
+        {
+            let attribute = &self.attributes[id].attribute;
+            let key = attribute.key();
+            if !keys.contains(&key) {
+                keys.push(key);
+                resolved.push(attribute);
+            }
+        }
+        resolved.into_iter().rev()
+    }
+
     /// Returns the number of attribute spans applied to the text.
     pub fn attributes_len(&self) -> usize {
         self.attributes.len()
@@ -124,13 +303,106 @@ impl<T: Debug + TextStorage, Attr: Debug> AttributedText<T, Attr> {
     /// Remove all applied attribute spans.
     pub fn clear_attributes(&mut self) {
         self.attributes.clear();
+        self.index.clear();
+    }
+
+    /// Merge spans of equal attributes that overlap or are adjacent, to keep the list of spans
+    /// compact.
+    ///
+    /// Spans are only merged when they have the same [`SpanExpansion`], and when no span applied
+    /// between them overlaps the merged range, so that the attributes that apply to the text and
+    /// their precedence don't change. The merged span takes the place of the earlier one in the
+    /// order the attributes were applied.
+    ///
+    /// Spans with different ranges are only merged when they are [`SpanExpansion::Inclusive`].
+    /// Otherwise, text later inserted at a boundary inside the merged span, or replacing all of the
+    /// text of one of the spans, would not be covered the same way as by the separate spans.
+    pub fn coalesce_attributes(&mut self)
+    where
+        Attr: PartialEq,
+    {
+        let mut kept: Vec<AttributeSpan<Attr>> = Vec::with_capacity(self.attributes.len());
+        // An index of the kept spans. A span that grows is inserted again with its new range,
+        // which covers the old one.
+        let mut index = SpanIndex::default();
+        for span in core::mem::take(&mut self.attributes) {
+            if !span.range.is_empty() {
+                let neighbors = span.range.start.saturating_sub(1)..span.range.end + 1;
+                let target = index
+                    .overlapping(neighbors)
+                    .into_iter()
+                    .rev()
+                    .filter(|&id| {
+                        let candidate = &kept[id];
+                        !candidate.range.is_empty()
+                            && candidate.expansion == span.expansion
+                            && candidate.attribute == span.attribute
+                            && (candidate.range == span.range
+                                || span.expansion == SpanExpansion::Inclusive)
+                    })
+                    .find(|&id| {
+                        let merged = kept[id].range.start.min(span.range.start)
+                            ..kept[id].range.end.max(span.range.end);
+                        index.overlapping(merged.clone()).into_iter().all(|other| {
+                            let range = &kept[other].range;
+                            other <= id
+                                || range.is_empty()
+                                || range.start >= merged.end
+                                || range.end <= merged.start
+                        })
+                    });
+                if let Some(id) = target {
+                    let range = &mut kept[id].range;
+                    *range = range.start.min(span.range.start)..range.end.max(span.range.end);
+                    index.insert(range.clone(), id);
+                    continue;
+                }
+            }
+            index.insert(span.range.clone(), kept.len());
+            kept.push(span);
+        }
+        self.attributes = kept;
+        self.index = SpanIndex::new(self.attributes.iter().map(|span| span.range.clone()));
+    }
+
+    /// Check that `range` is a valid range of the text, with both ends on character boundaries.
+    fn check_range(&self, range: &Range<usize>) -> Result<(), Error> {
+        let text_len = self.text.len();
+        if range.start > range.end {
+            return Err(Error::invalid_range(range.start, range.end, text_len));
+        }
+        if range.start > text_len || range.end > text_len {
+            return Err(Error::invalid_bounds(range.start, range.end, text_len));
+        }
+        if !self.text.is_char_boundary(range.start) {
+            return Err(Error::not_on_char_boundary(
+                &self.text,
+                range.start,
+                range.end,
+                text_len,
+                Endpoint::Start,
+                range.start,
+            ));
+        }
+        if !self.text.is_char_boundary(range.end) {
+            return Err(Error::not_on_char_boundary(
+                &self.text,
+                range.start,
+                range.end,
+                text_len,
+                Endpoint::End,
+                range.end,
+            ));
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{AttributedText, Endpoint, ErrorKind};
+    use crate::{AttributedText, Endpoint, ErrorKind, KeyedAttribute, SpanExpansion};
     use alloc::format;
+    use alloc::string::{String, ToString};
     use alloc::vec::Vec;
 
     #[derive(Debug, PartialEq)]
@@ -236,4 +508,297 @@ mod tests {
         // Using proper boundaries is OK
         assert!(at.apply_attribute(0..2, TestAttribute::Keep).is_ok());
     }
+
+    fn ranges(at: &AttributedText<String, TestAttribute>) -> Vec<(usize, usize)> {
+        at.attributes_iter()
+            .map(|(range, _)| (range.start, range.end))
+            .collect()
+    }
+
+    #[test]
+    fn insert_with_expansion() {
+        let mut at = AttributedText::new("Hello world".to_string());
+        for expansion in [
+            SpanExpansion::Exclusive,
+            SpanExpansion::InclusiveStart,
+            SpanExpansion::InclusiveEnd,
+            SpanExpansion::Inclusive,
+        ] {
+            at.apply_attribute_with_expansion(6..11, TestAttribute::Keep, expansion)
+                .unwrap();
+        }
+
+        // Inserting before a span shifts it.
+        at.insert(0, ">").unwrap();
+        assert_eq!(ranges(&at), [(7, 12), (7, 12), (7, 12), (7, 12)]);
+
+        // Inserting at the start only grows spans with an inclusive start.
+        at.insert(7, "big ").unwrap();
+        assert_eq!(at.as_str(), ">Hello big world");
+        assert_eq!(ranges(&at), [(11, 16), (7, 16), (11, 16), (7, 16)]);
+
+        // Inserting at the end only grows spans with an inclusive end.
+        at.insert(16, "!").unwrap();
+        assert_eq!(ranges(&at), [(11, 16), (7, 16), (11, 17), (7, 17)]);
+
+        // Inserting inside grows every span.
+        at.insert(12, "-").unwrap();
+        assert_eq!(ranges(&at), [(11, 17), (7, 17), (11, 18), (7, 18)]);
+    }
+
+    #[test]
+    fn delete_shrinks_and_removes_spans() {
+        let mut at = AttributedText::new("Hello world".to_string());
+        at.apply_attribute(0..5, TestAttribute::Keep).unwrap();
+        at.apply_attribute(4..8, TestAttribute::Keep).unwrap();
+        at.apply_attribute(6..11, TestAttribute::Remove).unwrap();
+
+        at.delete(3..6).unwrap();
+        assert_eq!(at.as_str(), "Helworld");
+        assert_eq!(ranges(&at), [(0, 3), (3, 5), (3, 8)]);
+
+        // Spans that lose all their text are removed.
+        at.delete(0..5).unwrap();
+        assert_eq!(at.as_str(), "rld");
+        assert_eq!(ranges(&at), [(0, 3)]);
+        assert_eq!(
+            at.attributes_at(0).collect::<Vec<_>>(),
+            [&TestAttribute::Remove]
+        );
+    }
+
+    #[test]
+    fn replace_range_over_span() {
+        let mut at = AttributedText::new("a bold word".to_string());
+        at.apply_attribute(2..6, TestAttribute::Keep).unwrap();
+        at.apply_attribute_with_expansion(2..6, TestAttribute::Remove, SpanExpansion::Exclusive)
+            .unwrap();
+
+        // Replacing all of the text of a span keeps the style, unless it is exclusive.
+        at.replace_range(2..6, "strong").unwrap();
+        assert_eq!(at.as_str(), "a strong word");
+        assert_eq!(ranges(&at), [(2, 8)]);
+
+        // Replacing text across the end of a span grows it over the replacement.
+        at.replace_range(5..10, "ange w").unwrap();
+        assert_eq!(at.as_str(), "a strange word");
+        assert_eq!(ranges(&at), [(2, 11)]);
+    }
+
+    #[test]
+    fn edit_not_on_char_boundary() {
+        let mut at = AttributedText::new("éclair".to_string());
+        at.apply_attribute(0..2, TestAttribute::Keep).unwrap();
+
+        match at.insert(1, "x") {
+            Err(e) => assert_eq!(e.kind(), ErrorKind::NotOnCharBoundary),
+            _ => panic!("expected NotOnCharBoundary"),
+        }
+        match at.delete(0..8) {
+            Err(e) => assert_eq!(e.kind(), ErrorKind::InvalidBounds),
+            _ => panic!("expected InvalidBounds"),
+        }
+        // Failed edits leave the text and the spans untouched.
+        assert_eq!(at.as_str(), "éclair");
+        assert_eq!(ranges(&at), [(0, 2)]);
+
+        at.insert(2, "ü").unwrap();
+        assert_eq!(ranges(&at), [(0, 4)]);
+        for (range, _) in at.attributes_iter() {
+            assert!(at.as_str().is_char_boundary(range.start));
+            assert!(at.as_str().is_char_boundary(range.end));
+        }
+    }
+
+    #[test]
+    fn attributes_in_application_order() {
+        let mut at = AttributedText::new("Hello world".to_string());
+        at.apply_attribute(4..8, TestAttribute::Remove).unwrap();
+        at.apply_attribute(0..5, TestAttribute::Keep).unwrap();
+
+        assert_eq!(
+            at.attributes_at(4).collect::<Vec<_>>(),
+            [&TestAttribute::Remove, &TestAttribute::Keep]
+        );
+        assert_eq!(
+            at.attributes_for_range(7..9).collect::<Vec<_>>(),
+            [&TestAttribute::Remove]
+        );
+        assert!(at.attributes_at(8).next().is_none());
+
+        // Queries follow the spans as the text is edited.
+        at.insert(0, "Oh, ").unwrap();
+        assert_eq!(
+            at.attributes_at(4).collect::<Vec<_>>(),
+            [&TestAttribute::Keep]
+        );
+        assert!(at.attributes_at(0).next().is_none());
+        at.clear_attributes();
+        assert!(at.attributes_at(8).next().is_none());
+    }
+
+    #[test]
+    fn style_runs() {
+        let mut at = AttributedText::new("Hello world".to_string());
+        at.apply_attribute(6..11, TestAttribute::Remove).unwrap();
+        at.apply_attribute(2..8, TestAttribute::Keep).unwrap();
+        // Empty spans don't cover any text.
+        at.apply_attribute(1..1, TestAttribute::Keep).unwrap();
+
+        let runs: Vec<_> = at.style_runs().collect();
+        assert_eq!(
+            runs,
+            [
+                (0..2, Vec::new()),
+                (2..6, [&TestAttribute::Keep].into()),
+                (6..8, [&TestAttribute::Remove, &TestAttribute::Keep].into()),
+                (8..11, [&TestAttribute::Remove].into()),
+            ]
+        );
+
+        assert_eq!(
+            AttributedText::<_, TestAttribute>::new("")
+                .style_runs()
+                .count(),
+            0
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Style {
+        Color(u8),
+        Weight(u16),
+    }
+
+    impl KeyedAttribute for Style {
+        type Key = core::mem::Discriminant<Self>;
+
+        fn key(&self) -> Self::Key {
+            core::mem::discriminant(self)
+        }
+    }
+
+    #[test]
+    fn resolved_at() {
+        let mut at = AttributedText::new("Hello world");
+        at.apply_attribute(0..10, Style::Color(1)).unwrap();
+        at.apply_attribute(2..6, Style::Weight(700)).unwrap();
+        at.apply_attribute(4..8, Style::Color(2)).unwrap();
+
+        assert_eq!(at.resolved_at(1).collect::<Vec<_>>(), [&Style::Color(1)]);
+        assert_eq!(
+            at.resolved_at(3).collect::<Vec<_>>(),
+            [&Style::Color(1), &Style::Weight(700)]
+        );
+        assert_eq!(
+            at.resolved_at(5).collect::<Vec<_>>(),
+            [&Style::Weight(700), &Style::Color(2)]
+        );
+        assert_eq!(at.resolved_at(9).collect::<Vec<_>>(), [&Style::Color(1)]);
+        assert!(at.resolved_at(10).next().is_none());
+    }
+
+    #[test]
+    fn coalesce_attributes() {
+        let text = "Some text to style";
+        let inclusive = SpanExpansion::Inclusive;
+        let mut at = AttributedText::new(text);
+        at.apply_attribute_with_expansion(0..3, Style::Color(1), inclusive)
+            .unwrap();
+        at.apply_attribute_with_expansion(3..6, Style::Color(1), inclusive)
+            .unwrap();
+        at.apply_attribute(8..12, Style::Color(2)).unwrap();
+        // Merged with the first span, as the span in between doesn't overlap.
+        at.apply_attribute_with_expansion(5..8, Style::Color(1), inclusive)
+            .unwrap();
+        at.apply_attribute_with_expansion(10..14, Style::Color(1), inclusive)
+            .unwrap();
+        at.apply_attribute_with_expansion(12..16, Style::Color(1), SpanExpansion::Exclusive)
+            .unwrap();
+        // Not merged with the previous equal span, as the exclusive span is in between.
+        at.apply_attribute_with_expansion(14..18, Style::Color(1), inclusive)
+            .unwrap();
+        // Adjacent spans that don't grow at both ends aren't merged, but equal ranges are.
+        at.apply_attribute(0..4, Style::Weight(700)).unwrap();
+        at.apply_attribute(4..9, Style::Weight(700)).unwrap();
+        at.apply_attribute(0..4, Style::Weight(700)).unwrap();
+
+        let resolved: Vec<Vec<_>> = (0..text.len())
+            .map(|index| at.resolved_at(index).cloned().collect())
+            .collect();
+        at.coalesce_attributes();
+
+        let spans: Vec<_> = at
+            .attributes_iter()
+            .map(|(range, attribute)| (range.start, range.end, attribute))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                (0, 8, &Style::Color(1)),
+                (8, 12, &Style::Color(2)),
+                (10, 14, &Style::Color(1)),
+                (12, 16, &Style::Color(1)),
+                (14, 18, &Style::Color(1)),
+                (0, 4, &Style::Weight(700)),
+                (4, 9, &Style::Weight(700)),
+            ]
+        );
+        for (index, resolved) in resolved.iter().enumerate() {
+            assert_eq!(
+                &at.resolved_at(index).cloned().collect::<Vec<_>>(),
+                resolved,
+                "index {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn coalesce_attributes_then_edit() {
+        let styled = || {
+            let inclusive = SpanExpansion::Inclusive;
+            let mut at = AttributedText::new("The quick brown fox jumps".to_string());
+            at.apply_attribute_with_expansion(0..4, Style::Color(1), inclusive)
+                .unwrap();
+            at.apply_attribute_with_expansion(4..10, Style::Color(1), inclusive)
+                .unwrap();
+            at.apply_attribute_with_expansion(8..12, Style::Color(1), inclusive)
+                .unwrap();
+            at.apply_attribute(2..6, Style::Weight(700)).unwrap();
+            at.apply_attribute(6..9, Style::Weight(700)).unwrap();
+            at.apply_attribute(2..6, Style::Weight(700)).unwrap();
+            at.apply_attribute(16..19, Style::Color(2)).unwrap();
+            at.apply_attribute(19..25, Style::Color(2)).unwrap();
+            at
+        };
+        let mut separate = styled();
+        let mut coalesced = styled();
+        coalesced.coalesce_attributes();
+        assert!(coalesced.attributes_len() < separate.attributes_len());
+
+        let edits = [
+            // At the boundaries between spans.
+            (4..4, "very "),
+            (6..6, "x"),
+            (24..24, "-"),
+            // Over all of the text of a span.
+            (0..4, "A"),
+            (19..24, "red"),
+            // Across spans.
+            (3..12, ""),
+            (1..5, "long "),
+        ];
+        for (range, replacement) in edits {
+            separate.replace_range(range.clone(), replacement).unwrap();
+            coalesced.replace_range(range.clone(), replacement).unwrap();
+            assert_eq!(separate.as_str(), coalesced.as_str());
+            for index in 0..separate.as_str().len() {
+                assert_eq!(
+                    separate.resolved_at(index).collect::<Vec<_>>(),
+                    coalesced.resolved_at(index).collect::<Vec<_>>(),
+                    "index {index} after replacing {range:?} with {replacement:?}"
+                );
+            }
+        }
+    }
 }
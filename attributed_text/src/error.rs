@@ -76,6 +76,17 @@ impl Error {
         }
     }
 
+    #[cfg(feature = "html")]
+    pub(crate) fn invalid_markup(start: usize, end: usize, len: usize) -> Self {
+        Self {
+            kind: ErrorKind::InvalidMarkup,
+            start,
+            end,
+            len,
+            boundary: None,
+        }
+    }
+
     pub(crate) fn not_on_char_boundary<T: TextStorage>(
         text: &T,
         start: usize,
@@ -130,6 +141,9 @@ impl core::fmt::Display for Error {
                     )
                 }
             }
+            ErrorKind::InvalidMarkup => {
+                write!(f, "invalid markup at {}..{}", self.start, self.end)
+            }
         }
     }
 }
@@ -148,6 +162,9 @@ pub enum ErrorKind {
 
     /// Either `start` or `end` was not aligned to a UTF-8 character boundary.
     NotOnCharBoundary,
+
+    /// Markup being parsed was malformed within the range.
+    InvalidMarkup,
 }
 
 /// Identifies which endpoint of a range failed boundary validation.
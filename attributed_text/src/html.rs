@@ -0,0 +1,453 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conversion of [`AttributedText`] to and from a subset of HTML.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::markup::{LINE_SEPARATOR, common_prefix, decode_entity, tagged_paragraphs};
+use crate::{AttributedText, Error, TextStorage};
+
+/// An HTML element that represents an attribute.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HtmlTag {
+    /// `<b>`, also parsed from `<strong>`.
+    Bold,
+    /// `<i>`, also parsed from `<em>`.
+    Italic,
+    /// `<u>`, also parsed from `<ins>`.
+    Underline,
+    /// `<s>`, also parsed from `<strike>` and `<del>`.
+    Strikethrough,
+    /// `<span>` with the given value of its `style` attribute.
+    Span(String),
+}
+
+impl HtmlTag {
+    fn from_name(name: &str, style: Option<String>) -> Option<Self> {
+        Some(match name {
+            "b" | "strong" => Self::Bold,
+            "i" | "em" => Self::Italic,
+            "u" | "ins" => Self::Underline,
+            "s" | "strike" | "del" => Self::Strikethrough,
+            "span" => Self::Span(style.unwrap_or_default()),
+            _ => return None,
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Bold => "b",
+            Self::Italic => "i",
+            Self::Underline => "u",
+            Self::Strikethrough => "s",
+            Self::Span(_) => "span",
+        }
+    }
+}
+
+/// A mapping between attribute values and [HTML tags](HtmlTag).
+pub trait HtmlAttribute: Sized {
+    /// Returns the tag that represents this attribute, or `None` if it is not represented in
+    /// HTML.
+    fn to_html(&self) -> Option<HtmlTag>;
+
+    /// Returns the attribute represented by `tag`, or `None` if the tag should be ignored.
+    fn from_html(tag: &HtmlTag) -> Option<Self>;
+}
+
+impl<T: Debug + TextStorage + AsRef<str>, A: Debug + HtmlAttribute> AttributedText<T, A> {
+    /// Serialize the text and its attributes as HTML.
+    ///
+    /// Each line of the text becomes a `<p>` element, and U+2028 LINE SEPARATOR becomes `<br>`.
+    /// Attributes become nested elements within the paragraphs, so a span that crosses a line
+    /// break is split in two. Attributes that [`HtmlAttribute::to_html`] maps to `None` are
+    /// omitted.
+    pub fn to_html(&self) -> String {
+        let text = self.as_str();
+        let mut html = String::new();
+        for paragraph in tagged_paragraphs(self, A::to_html) {
+            html.push_str("<p>");
+            let mut open: Vec<HtmlTag> = Vec::new();
+            let mut collapsing = true;
+            for (range, tags) in paragraph {
+                let keep = common_prefix(&open, &tags);
+                for tag in open.drain(keep..).rev() {
+                    html.push_str("</");
+                    html.push_str(tag.name());
+                    html.push('>');
+                }
+                for tag in &tags[keep..] {
+                    html.push('<');
+                    html.push_str(tag.name());
+                    if let HtmlTag::Span(style) = tag {
+                        html.push_str(" style=\"");
+                        escape_attribute(&mut html, style);
+                        html.push('"');
+                    }
+                    html.push('>');
+                }
+                open.extend(tags.into_iter().skip(keep));
+                escape_text(&mut html, &text[range], &mut collapsing);
+            }
+            for tag in open.iter().rev() {
+                html.push_str("</");
+                html.push_str(tag.name());
+                html.push('>');
+            }
+            html.push_str("</p>");
+        }
+        html
+    }
+}
+
+impl<A: Debug + HtmlAttribute> AttributedText<String, A> {
+    /// Parse text and attributes from HTML.
+    ///
+    /// This accepts the subset of HTML produced by [`to_html`](Self::to_html): paragraphs
+    /// (`<p>`), which are separated by `'\n'` in the text, line breaks (`<br>`), which become
+    /// U+2028 LINE SEPARATOR, and the elements of [`HtmlTag`]. Other elements and comments are
+    /// skipped, but their content is kept. As in HTML, runs of whitespace within text become a
+    /// single space, and whitespace between paragraphs is ignored.
+    ///
+    /// Returns an error of kind [`InvalidMarkup`](crate::ErrorKind::InvalidMarkup), with the
+    /// range of the offending input, for unterminated tags and comments, and for end tags
+    /// without a matching start tag.
+    pub fn from_html(html: &str) -> Result<Self, Error> {
+        let mut text = HtmlText::default();
+        // The open elements, with the start of their content and the order they were opened in.
+        let mut open: Vec<(String, HtmlTag, usize, usize)> = Vec::new();
+        let mut spans = Vec::new();
+        let mut opened = 0_usize;
+        let mut position = 0;
+        while position < html.len() {
+            let rest = &html[position..];
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                let end = comment
+                    .find("-->")
+                    .ok_or_else(|| Error::invalid_markup(position, html.len(), html.len()))?;
+                position += end + 7;
+                continue;
+            }
+            let Some(tag) = rest.strip_prefix('<') else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                let content = &rest[..end];
+                position += end;
+                if text.in_paragraph || !content.trim().is_empty() {
+                    text.start_implicit_paragraph();
+                    text.push_html(content);
+                }
+                continue;
+            };
+            let end = tag
+                .find('>')
+                .ok_or_else(|| Error::invalid_markup(position, html.len(), html.len()))?;
+            let tag = &tag[..end];
+            let tag_range = position..position + end + 2;
+            position = tag_range.end;
+            if let Some(name) = tag.strip_prefix('/') {
+                let name = name.trim().to_ascii_lowercase();
+                if name == "p" {
+                    text.in_paragraph = false;
+                } else if HtmlTag::from_name(&name, None).is_some() {
+                    let index = open
+                        .iter()
+                        .rposition(|(open, ..)| *open == name)
+                        .ok_or_else(|| {
+                            Error::invalid_markup(tag_range.start, tag_range.end, html.len())
+                        })?;
+                    // Elements left open within this one are closed along with it.
+                    for (_, tag, start, order) in open.drain(index..) {
+                        spans.push((order, start..text.text.len(), tag));
+                    }
+                }
+                continue;
+            }
+            let tag = tag.strip_suffix('/').unwrap_or(tag);
+            let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+            let name = tag[..name_end].to_ascii_lowercase();
+            match name.as_str() {
+                "p" => text.start_paragraph(),
+                "br" => {
+                    text.start_implicit_paragraph();
+                    text.push(LINE_SEPARATOR);
+                }
+                _ => {
+                    let style = attribute_value(&tag[name_end..], "style");
+                    if let Some(tag) = HtmlTag::from_name(&name, style) {
+                        open.push((name, tag, text.text.len(), opened));
+                        opened += 1;
+                    }
+                }
+            }
+        }
+        for (_, tag, start, order) in open {
+            spans.push((order, start..text.text.len(), tag));
+        }
+
+        // Apply the spans in the order their elements were opened, so that outer elements come
+        // first.
+        spans.sort_unstable_by_key(|(order, ..)| *order);
+        let mut attributed = Self::new(text.text);
+        for (_, range, tag) in spans {
+            if let Some(attribute) = A::from_html(&tag) {
+                attributed.apply_attribute(range, attribute)?;
+            }
+        }
+        Ok(attributed)
+    }
+}
+
+/// Text parsed from HTML.
+#[derive(Default)]
+struct HtmlText {
+    text: String,
+    /// The number of paragraphs started so far.
+    paragraphs: usize,
+    /// Whether a paragraph is open, so that whitespace in it isn't ignored.
+    in_paragraph: bool,
+    /// Whether the text ends with whitespace from the source, which further whitespace
+    /// collapses into.
+    collapsing: bool,
+}
+
+impl HtmlText {
+    fn start_paragraph(&mut self) {
+        if self.paragraphs > 0 {
+            self.text.push('\n');
+        }
+        self.paragraphs += 1;
+        self.in_paragraph = true;
+        self.collapsing = true;
+    }
+
+    /// Start a paragraph for content outside any `<p>` element, unless one is open.
+    fn start_implicit_paragraph(&mut self) {
+        if !self.in_paragraph {
+            self.start_paragraph();
+        }
+    }
+
+    fn push(&mut self, c: char) {
+        self.text.push(c);
+        self.collapsing = c == LINE_SEPARATOR;
+    }
+
+    /// Append HTML text content, decoding character references and collapsing whitespace.
+    fn push_html(&mut self, mut content: &str) {
+        while let Some(c) = content.chars().next() {
+            if c == '&' {
+                if let Some((c, len)) = decode_entity(content) {
+                    self.push(c);
+                    content = &content[len..];
+                    continue;
+                }
+            }
+            if c.is_ascii_whitespace() {
+                if !self.collapsing {
+                    self.text.push(' ');
+                    self.collapsing = true;
+                }
+            } else {
+                self.push(c);
+            }
+            content = &content[c.len_utf8()..];
+        }
+    }
+}
+
+/// Append the text content of a paragraph to `html`, escaping the characters that are special
+/// in HTML.
+///
+/// Whitespace that would be collapsed or ignored when parsed is written as character
+/// references. `collapsing` tracks whether the preceding character was whitespace, and should
+/// be `true` at the start of a paragraph.
+fn escape_text(html: &mut String, text: &str, collapsing: &mut bool) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            ' ' if !*collapsing => html.push(' '),
+            LINE_SEPARATOR => html.push_str("<br>"),
+            _ if c.is_ascii_whitespace() => {
+                html.push_str("&#");
+                html.push_str(&u32::from(c).to_string());
+                html.push(';');
+            }
+            _ => html.push(c),
+        }
+        *collapsing = c == ' ' || c == LINE_SEPARATOR;
+    }
+}
+
+/// Append an attribute value to `html`, escaping the characters that are special in HTML.
+fn escape_attribute(html: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '"' => html.push_str("&quot;"),
+            _ => html.push(c),
+        }
+    }
+}
+
+/// Find the value of the attribute `name` among the attributes of a start tag.
+fn attribute_value(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes.trim_start();
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let attribute = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+        let mut value = "";
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (raw, remaining) = match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let end = after[1..].find(quote).map_or(after.len(), |end| end + 1);
+                    (&after[1..end], after.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            value = raw;
+            rest = remaining.trim_start();
+        }
+        if attribute.eq_ignore_ascii_case(name) {
+            let mut decoded = String::new();
+            let mut value = value;
+            while let Some(c) = value.chars().next() {
+                let (c, len) =
+                    if c == '&' { decode_entity(value) } else { None }.unwrap_or((c, c.len_utf8()));
+                decoded.push(c);
+                value = &value[len..];
+            }
+            return Some(decoded);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::{HtmlAttribute, HtmlTag};
+    use crate::markup::tests::{attributed, represented_runs, runs};
+    use crate::{AttributedText, ErrorKind};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Style {
+        Bold,
+        Italic,
+        Underline,
+        Strike,
+        Color(String),
+        Link,
+    }
+
+    impl HtmlAttribute for Style {
+        fn to_html(&self) -> Option<HtmlTag> {
+            Some(match self {
+                Self::Bold => HtmlTag::Bold,
+                Self::Italic => HtmlTag::Italic,
+                Self::Underline => HtmlTag::Underline,
+                Self::Strike => HtmlTag::Strikethrough,
+                Self::Color(color) => HtmlTag::Span(alloc::format!("color: {color}")),
+                Self::Link => return None,
+            })
+        }
+
+        fn from_html(tag: &HtmlTag) -> Option<Self> {
+            Some(match tag {
+                HtmlTag::Bold => Self::Bold,
+                HtmlTag::Italic => Self::Italic,
+                HtmlTag::Underline => Self::Underline,
+                HtmlTag::Strikethrough => Self::Strike,
+                HtmlTag::Span(style) => Self::Color(String::from(style.strip_prefix("color: ")?)),
+            })
+        }
+    }
+
+    #[test]
+    fn to_html() {
+        let text = attributed(
+            "Bold <italic> & red\nline\u{2028}break  twice",
+            &[
+                (0, 13, Style::Bold),
+                (5, 19, Style::Italic),
+                (16, 19, Style::Color(String::from("\"red\""))),
+                (0, 4, Style::Link),
+            ],
+        );
+        assert_eq!(
+            text.to_html(),
+            "<p><b>Bold <i>&lt;italic&gt;</i></b><i> &amp; <span style=\"color: &quot;red&quot;\">red</span></i></p>\
+             <p>line<br>break &#32;twice</p>"
+        );
+    }
+
+    #[test]
+    fn from_html() {
+        let text = AttributedText::<String, Style>::from_html(
+            "<!-- comment --><p>\n  <STRONG>Bold <em>and</strong> italic&nbsp;&#x41;&#66;</p>\n\
+             <div>no <span style='color: blue' class=x>p</span></div><ins>unclosed",
+        )
+        .unwrap();
+        assert_eq!(text.as_str(), "Bold and italic\u{a0}AB\nno punclosed");
+        assert_eq!(
+            runs(&text),
+            [
+                ("Bold ", Vec::from([Style::Bold])),
+                ("and", Vec::from([Style::Bold, Style::Italic])),
+                (" italic\u{a0}AB\nno ", Vec::new()),
+                ("p", Vec::from([Style::Color(String::from("blue"))])),
+                ("unclosed", Vec::from([Style::Underline])),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_invalid_html() {
+        let error = AttributedText::<String, Style>::from_html("<p>a</b></p>").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidMarkup);
+        assert_eq!((error.start(), error.end()), (4, 8));
+        let error = AttributedText::<String, Style>::from_html("<p>a<b").unwrap_err();
+        assert_eq!((error.start(), error.end()), (4, 6));
+        let error = AttributedText::<String, Style>::from_html("a<!-- b").unwrap_err();
+        assert_eq!((error.start(), error.end()), (1, 7));
+    }
+
+    #[test]
+    fn html_round_trip() {
+        let text = attributed(
+            "  Leading space, <b>tags</b> & \"quotes\"\n\n\ttab\u{2028} after break\nlast ",
+            &[
+                (0, 15, Style::Bold),
+                (9, 20, Style::Italic),
+                (9, 20, Style::Underline),
+                (17, 39, Style::Strike),
+                (41, 45, Style::Strike),
+                (30, 38, Style::Color(String::from("rgb(0, 0, 0)"))),
+                (41, 48, Style::Link),
+            ],
+        );
+        let html = text.to_html();
+        let parsed = AttributedText::<String, Style>::from_html(&html).unwrap();
+        assert_eq!(parsed.as_str(), text.as_str(), "{html}");
+        // Attributes that aren't represented in HTML are lost.
+        assert_eq!(
+            represented_runs(&parsed, |_| true),
+            represented_runs(&text, |style| *style != Style::Link),
+            "{html}"
+        );
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// An attribute that belongs to a category, identified by its key.
+///
+/// Where attributes with the same key overlap, the one applied last is effective, while
+/// attributes with different keys all apply. For example, two color attributes would share a
+/// key, but a color attribute and a font weight attribute would not.
+///
+/// See [`AttributedText::resolved_at`](crate::AttributedText::resolved_at).
+pub trait KeyedAttribute {
+    /// The type of the keys of the attributes.
+    type Key: PartialEq;
+
+    /// Returns the key of the attribute.
+    fn key(&self) -> Self::Key;
+}
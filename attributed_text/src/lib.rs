@@ -6,6 +6,12 @@
 //! ## Features
 //!
 //! - `std` (enabled by default): This is currently unused and is provided for forward compatibility.
+//! - `ropey`: Implements [`TextStorage`], [`TextStorageMut`] and [`TextChunks`] for the rope
+//!   type of the [`ropey`](https://docs.rs/ropey) crate.
+//! - `html`: Converts [`AttributedText`] to and from a subset of HTML, given an
+//!   [`HtmlAttribute`] mapping between attributes and tags.
+//! - `markdown`: Converts [`AttributedText`] to and from `CommonMark` inline markup, given a
+//!   [`MarkdownAttribute`] mapping between attributes and markup.
 // LINEBENDER LINT SET - lib.rs - v3
 // See https://linebender.org/wiki/canonical-lints/
 // These lints shouldn't apply to examples or tests.
@@ -22,8 +28,25 @@ extern crate alloc;
 
 mod attributed_text;
 mod error;
+#[cfg(feature = "html")]
+mod html;
+mod keyed_attribute;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(any(feature = "html", feature = "markdown"))]
+mod markup;
+#[cfg(feature = "ropey")]
+mod rope;
+mod span_expansion;
+mod span_index;
 mod text_storage;
 
 pub use crate::attributed_text::AttributedText;
 pub use crate::error::{BoundaryInfo, Endpoint, Error, ErrorKind};
-pub use crate::text_storage::TextStorage;
+#[cfg(feature = "html")]
+pub use crate::html::{HtmlAttribute, HtmlTag};
+pub use crate::keyed_attribute::KeyedAttribute;
+#[cfg(feature = "markdown")]
+pub use crate::markdown::{MarkdownAttribute, MarkdownTag};
+pub use crate::span_expansion::SpanExpansion;
+pub use crate::text_storage::{TextChunks, TextStorage, TextStorageMut};
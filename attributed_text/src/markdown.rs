@@ -0,0 +1,1040 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conversion of [`AttributedText`] to and from `CommonMark` inline markup.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt::Debug;
+use core::ops::Range;
+
+use crate::markup::{LINE_SEPARATOR, TaggedRun, common_prefix, decode_entity, tagged_paragraphs};
+use crate::{AttributedText, TextStorage};
+
+/// Inline markup that represents an attribute.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarkdownTag {
+    /// Emphasis, written as `*text*` or `_text_`.
+    Emphasis,
+    /// Strong emphasis, written as `**text**` or `__text__`.
+    Strong,
+    /// Strikethrough, written as `~~text~~`, as in GitHub Flavored Markdown.
+    Strikethrough,
+    /// A code span, written as `` `text` ``.
+    Code,
+    /// A link to the given destination, written as `[text](destination)`.
+    Link(String),
+}
+
+/// A mapping between attribute values and [markdown tags](MarkdownTag).
+pub trait MarkdownAttribute: Sized {
+    /// Returns the tag that represents this attribute, or `None` if it is not represented in
+    /// markdown.
+    fn to_markdown(&self) -> Option<MarkdownTag>;
+
+    /// Returns the attribute represented by `tag`, or `None` if the tag should be ignored.
+    fn from_markdown(tag: &MarkdownTag) -> Option<Self>;
+}
+
+impl<T: Debug + TextStorage + AsRef<str>, A: Debug + MarkdownAttribute> AttributedText<T, A> {
+    /// Serialize the text and its attributes as `CommonMark` inline markup.
+    ///
+    /// Each line of the text becomes a paragraph, and U+2028 LINE SEPARATOR becomes a hard line
+    /// break. Characters that would otherwise be parsed as markup are escaped. Attributes that
+    /// [`MarkdownAttribute::to_markdown`] maps to `None` are omitted.
+    ///
+    /// Markdown can't represent everything an `AttributedText` can, so some information is
+    /// lost:
+    /// - Empty lines, and whitespace at the end of a line.
+    /// - Markup around whitespace at the edges of a span, which is moved outside of the span.
+    /// - Markup within a code span.
+    /// - Emphasis and strikethrough that wouldn't be parsed as written, such as when it starts or
+    ///   ends next to punctuation within a word, which is omitted.
+    pub fn to_markdown(&self) -> String {
+        let text = self.as_str();
+        let mut markdown = String::new();
+        for (index, runs) in tagged_paragraphs(self, A::to_markdown)
+            .into_iter()
+            .enumerate()
+        {
+            if index > 0 {
+                markdown.push_str("\n\n");
+            }
+            write_paragraph(&mut markdown, text, normalize_runs(text, runs));
+        }
+        markdown
+    }
+}
+
+impl<A: Debug + MarkdownAttribute> AttributedText<String, A> {
+    /// Parse text and attributes from `CommonMark` inline markup.
+    ///
+    /// Paragraphs, which are separated by blank lines, are separated by `'\n'` in the text, and
+    /// hard line breaks become U+2028 LINE SEPARATOR. Soft line breaks become spaces. Inline
+    /// markup is parsed as described by the `CommonMark` specification, with the addition of
+    /// `~~strikethrough~~`. Block structure, images, autolinks, reference links and raw HTML
+    /// aren't supported, and are parsed as text.
+    pub fn from_markdown(markdown: &str) -> Self {
+        let mut text = String::new();
+        let mut spans = Vec::new();
+        let mut lines: Vec<&str> = Vec::new();
+        let mut first = true;
+        for line in markdown.lines().chain([""]) {
+            if !line.trim().is_empty() {
+                lines.push(line.trim_start());
+                continue;
+            }
+            if lines.is_empty() {
+                continue;
+            }
+            if !first {
+                text.push('\n');
+            }
+            first = false;
+            let paragraph = lines.join("\n");
+            parse_inline(paragraph.trim_end(), &mut text, &mut spans);
+            lines.clear();
+        }
+
+        // Outer spans come first, and of spans with the same range, the one matched last is
+        // outermost.
+        let mut spans: Vec<_> = spans.into_iter().enumerate().collect();
+        spans.sort_by_key(|(order, (range, _))| (range.start, Reverse(range.end), Reverse(*order)));
+        let mut attributed = Self::new(text);
+        for (_, (range, tag)) in spans {
+            if let Some(attribute) = A::from_markdown(&tag) {
+                // The ranges are produced by the parser, so they are always valid.
+                let _ = attributed.apply_attribute(range, attribute);
+            }
+        }
+        attributed
+    }
+}
+
+/// Prepare the runs of a paragraph for writing.
+///
+/// Code spans can't contain other markup, so [`MarkdownTag::Code`] is moved innermost. Markup
+/// that starts or ends at whitespace isn't parsed, so runs of whitespace keep only the markup
+/// that continues from the previous run.
+fn normalize_runs(text: &str, runs: Vec<TaggedRun<MarkdownTag>>) -> Vec<TaggedRun<MarkdownTag>> {
+    let mut normalized: Vec<TaggedRun<MarkdownTag>> = Vec::with_capacity(runs.len());
+    for (range, mut tags) in runs {
+        tags.sort_by_key(|tag| *tag == MarkdownTag::Code);
+        if text[range.clone()].trim().is_empty() {
+            let previous = normalized.last().map_or(&[][..], |(_, tags)| tags);
+            tags.truncate(common_prefix(previous, &tags));
+        }
+        match normalized.last_mut() {
+            Some((last, last_tags)) if *last_tags == tags => last.end = range.end,
+            _ => normalized.push((range, tags)),
+        }
+    }
+    normalized
+}
+
+/// Write the runs of a paragraph, omitting the emphasis and strikethrough markup that wouldn't be
+/// parsed as written.
+fn write_paragraph(markdown: &mut String, text: &str, mut runs: Vec<TaggedRun<MarkdownTag>>) {
+    let mut paragraph = String::new();
+    let mut delimiters = Vec::new();
+    loop {
+        paragraph.clear();
+        delimiters.clear();
+        write_runs(&mut paragraph, &mut delimiters, text, &runs);
+        if let Some(delimiter) = misplaced_delimiter(&paragraph, &delimiters) {
+            let (run, tag) = (delimiter.run, delimiter.tag.clone());
+            runs = drop_markup(text, runs, run, &tag);
+        } else if delimiters.is_empty() || parses_as(&paragraph, text, &runs) {
+            break;
+        } else {
+            // The delimiters flank the text as they should, but aren't matched as written, such
+            // as because of the "rule of 3". This is rare, so all of them are omitted.
+            for (_, tags) in &mut runs {
+                tags.retain(|tag| !is_delimited(tag));
+            }
+            runs = normalize_runs(text, runs);
+        }
+    }
+    markdown.push_str(&paragraph);
+}
+
+/// A run of delimiter characters written for emphasis or strikethrough markup.
+#[derive(Debug)]
+struct WrittenDelimiter {
+    /// The range of the delimiter characters in the markdown.
+    range: Range<usize>,
+    opening: bool,
+    /// The index of the run that the markup applies to, next to the delimiter.
+    run: usize,
+    tag: MarkdownTag,
+}
+
+/// Returns whether `tag` is written with delimiter runs, which are only parsed as markup when
+/// they flank the text.
+fn is_delimited(tag: &MarkdownTag) -> bool {
+    matches!(
+        tag,
+        MarkdownTag::Emphasis | MarkdownTag::Strong | MarkdownTag::Strikethrough
+    )
+}
+
+fn write_runs(
+    markdown: &mut String,
+    delimiters: &mut Vec<WrittenDelimiter>,
+    text: &str,
+    runs: &[TaggedRun<MarkdownTag>],
+) {
+    // The markup that is open, which is the markup of the previous run.
+    let mut open: &[MarkdownTag] = &[];
+    let mut line_start = true;
+    // Whitespace at the end of a run is written after the markup that closes there.
+    let mut trailing = "";
+    for (index, (range, tags)) in runs.iter().enumerate() {
+        let keep = common_prefix(open, tags);
+        if keep < open.len() {
+            write_closing_tags(markdown, delimiters, &open[keep..], index - 1);
+        }
+        escape(markdown, trailing, &mut line_start);
+        let mut content = &text[range.clone()];
+        if keep < tags.len() {
+            let trimmed = content.trim_start();
+            escape(
+                markdown,
+                &content[..content.len() - trimmed.len()],
+                &mut line_start,
+            );
+            content = trimmed;
+        }
+        let next = runs.get(index + 1).map_or(&[][..], |(_, tags)| tags);
+        trailing = "";
+        if common_prefix(tags, next) < tags.len() {
+            let trimmed = content.trim_end();
+            trailing = &content[trimmed.len()..];
+            content = trimmed;
+        }
+        for tag in &tags[keep..] {
+            line_start = false;
+            let start = markdown.len();
+            match tag {
+                MarkdownTag::Emphasis => markdown.push('*'),
+                MarkdownTag::Strong => markdown.push_str("**"),
+                MarkdownTag::Strikethrough => markdown.push_str("~~"),
+                MarkdownTag::Link(_) => markdown.push('['),
+                MarkdownTag::Code => write_code(markdown, content),
+            }
+            if is_delimited(tag) {
+                delimiters.push(WrittenDelimiter {
+                    range: start..markdown.len(),
+                    opening: true,
+                    run: index,
+                    tag: tag.clone(),
+                });
+            }
+        }
+        if tags.last() != Some(&MarkdownTag::Code) {
+            escape(markdown, content, &mut line_start);
+        }
+        open = tags;
+    }
+    write_closing_tags(markdown, delimiters, open, runs.len().saturating_sub(1));
+    escape(markdown, trailing, &mut line_start);
+}
+
+/// Close the markup of `tags`, innermost first, which applies to the run at index `run`.
+fn write_closing_tags(
+    markdown: &mut String,
+    delimiters: &mut Vec<WrittenDelimiter>,
+    tags: &[MarkdownTag],
+    run: usize,
+) {
+    for tag in tags.iter().rev() {
+        let start = markdown.len();
+        write_closing(markdown, tag);
+        if is_delimited(tag) {
+            delimiters.push(WrittenDelimiter {
+                range: start..markdown.len(),
+                opening: false,
+                run,
+                tag: tag.clone(),
+            });
+        }
+    }
+}
+
+/// Returns the first of the `delimiters` written in `markdown` that wouldn't be parsed as
+/// written.
+///
+/// Adjacent delimiters of the same character are parsed as a single run, which must be
+/// left-flanking to open markup and right-flanking to close it. A run that does both is
+/// ambiguous, so its openers are considered misplaced.
+fn misplaced_delimiter<'a>(
+    markdown: &str,
+    delimiters: &'a [WrittenDelimiter],
+) -> Option<&'a WrittenDelimiter> {
+    let mut start = 0;
+    while start < delimiters.len() {
+        let c = markdown[delimiters[start].range.clone()].chars().next()?;
+        let mut end = start + 1;
+        while end < delimiters.len()
+            && delimiters[end].range.start == delimiters[end - 1].range.end
+            && markdown[delimiters[end].range.start..].starts_with(c)
+        {
+            end += 1;
+        }
+        let run = &delimiters[start..end];
+        let before = markdown[..run[0].range.start].chars().next_back();
+        let after = markdown[run[run.len() - 1].range.end..].chars().next();
+        let (left, right) = flanking(before, after);
+        let closes = run.iter().any(|delimiter| !delimiter.opening);
+        let misplaced = run.iter().find(|delimiter| {
+            if delimiter.opening {
+                !left || closes
+            } else {
+                !right
+            }
+        });
+        if misplaced.is_some() {
+            return misplaced;
+        }
+        start = end;
+    }
+    None
+}
+
+/// Remove the markup of `tag` that applies to the run at `index`, which is written around the
+/// runs before and after it that keep it open.
+fn drop_markup(
+    text: &str,
+    mut runs: Vec<TaggedRun<MarkdownTag>>,
+    index: usize,
+    tag: &MarkdownTag,
+) -> Vec<TaggedRun<MarkdownTag>> {
+    let Some(depth) = runs[index].1.iter().position(|other| other == tag) else {
+        return runs;
+    };
+    let continues = |runs: &[TaggedRun<MarkdownTag>], next: usize| {
+        common_prefix(&runs[next - 1].1, &runs[next].1) > depth
+    };
+    let mut start = index;
+    while start > 0 && continues(&runs, start) {
+        start -= 1;
+    }
+    let mut end = index + 1;
+    while end < runs.len() && continues(&runs, end) {
+        end += 1;
+    }
+    for (_, tags) in &mut runs[start..end] {
+        tags.remove(depth);
+    }
+    normalize_runs(text, runs)
+}
+
+/// Returns whether `markdown` is parsed as the text of `runs`, without markup that they don't
+/// have.
+fn parses_as(markdown: &str, text: &str, runs: &[TaggedRun<MarkdownTag>]) -> bool {
+    let (Some((first, _)), Some((last, _))) = (runs.first(), runs.last()) else {
+        return true;
+    };
+    let offset = first.start;
+    let mut parsed = String::new();
+    let mut spans = Vec::new();
+    parse_inline(markdown.trim_end(), &mut parsed, &mut spans);
+    parsed == text[offset..last.end].trim_end()
+        && spans.iter().all(|(range, tag)| {
+            let first = runs.partition_point(|(run, _)| run.end <= offset + range.start);
+            runs[first..]
+                .iter()
+                .take_while(|(run, _)| run.start < offset + range.end)
+                .all(|(_, tags)| tags.contains(tag))
+        })
+}
+
+fn write_closing(markdown: &mut String, tag: &MarkdownTag) {
+    match tag {
+        MarkdownTag::Emphasis => markdown.push('*'),
+        MarkdownTag::Strong => markdown.push_str("**"),
+        MarkdownTag::Strikethrough => markdown.push_str("~~"),
+        MarkdownTag::Link(destination) => {
+            markdown.push_str("](");
+            let angle = destination.is_empty()
+                || destination.contains(|c: char| c.is_whitespace() || "()<>".contains(c));
+            if angle {
+                markdown.push('<');
+            }
+            for c in destination.chars() {
+                if matches!(c, '\\' | '&' | '<' | '>') {
+                    markdown.push('\\');
+                }
+                markdown.push(c);
+            }
+            if angle {
+                markdown.push('>');
+            }
+            markdown.push(')');
+        }
+        // Code spans are written with their content.
+        MarkdownTag::Code => {}
+    }
+}
+
+/// Write a code span, delimited by more backticks than any run of backticks it contains.
+fn write_code(markdown: &mut String, content: &str) {
+    let longest = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest + 1);
+    let pad = content.starts_with('`')
+        || content.ends_with('`')
+        || (content.starts_with(' ') && content.ends_with(' ') && !content.trim().is_empty());
+    markdown.push_str(&fence);
+    if pad {
+        markdown.push(' ');
+    }
+    markdown.push_str(content);
+    if pad {
+        markdown.push(' ');
+    }
+    markdown.push_str(&fence);
+}
+
+/// Append text to `markdown`, escaping the characters that would be parsed as markup.
+///
+/// `line_start` tracks whether the text is at the start of a line, where more characters start
+/// block structure.
+fn escape(markdown: &mut String, text: &str, line_start: &mut bool) {
+    let mut escape_at = None;
+    for (index, c) in text.char_indices() {
+        if *line_start {
+            *line_start = false;
+            match c {
+                '#' | '>' | '-' | '+' | '=' | '|' => markdown.push('\\'),
+                ' ' | '\t' => {
+                    markdown.push_str(if c == ' ' { "&#32;" } else { "&#9;" });
+                    continue;
+                }
+                '0'..='9' => {
+                    let digits = text[index..]
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(text.len() - index);
+                    if text[index + digits..].starts_with(['.', ')']) {
+                        escape_at = Some(index + digits);
+                    }
+                }
+                _ => {}
+            }
+        }
+        match c {
+            LINE_SEPARATOR => {
+                markdown.push_str("\\\n");
+                *line_start = true;
+                continue;
+            }
+            '\r' => {
+                markdown.push_str("&#13;");
+                continue;
+            }
+            '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '<' | '&' => markdown.push('\\'),
+            // An exclamation mark before a link would start an image.
+            '!' if index + 1 == text.len() => markdown.push('\\'),
+            _ if escape_at == Some(index) => markdown.push('\\'),
+            _ => {}
+        }
+        markdown.push(c);
+    }
+}
+
+/// An element of a paragraph being parsed.
+#[derive(Debug)]
+enum Piece {
+    Text(String),
+    Code(String),
+    Delimiter(Delimiter),
+    /// An opening bracket, which is active while it can still start a link.
+    Bracket(bool),
+}
+
+/// A run of emphasis or strikethrough delimiter characters.
+#[derive(Clone, Copy, Debug)]
+struct Delimiter {
+    c: char,
+    /// The number of characters that haven't been matched.
+    len: usize,
+    /// The number of characters in the run.
+    original: usize,
+    can_open: bool,
+    can_close: bool,
+    active: bool,
+}
+
+/// The extent of matched markup, in terms of pieces.
+#[derive(Debug)]
+enum Bounds {
+    /// The content between two pieces.
+    Between(usize, usize),
+    /// The content of a piece.
+    Piece(usize),
+}
+
+/// Parse the inline markup of a paragraph, appending its text to `text` and the spans of its
+/// markup to `spans`.
+fn parse_inline(source: &str, text: &mut String, spans: &mut Vec<(Range<usize>, MarkdownTag)>) {
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut matched = Vec::new();
+    let mut brackets = Vec::new();
+    let mut position = 0;
+    while let Some(c) = source[position..].chars().next() {
+        let rest = &source[position..];
+        match c {
+            '\\' => match rest[1..].chars().next() {
+                Some('\n') => {
+                    push_text(&mut pieces, LINE_SEPARATOR);
+                    position += 2;
+                }
+                Some(c) if c.is_ascii_punctuation() => {
+                    push_text(&mut pieces, c);
+                    position += 2;
+                }
+                _ => {
+                    push_text(&mut pieces, '\\');
+                    position += 1;
+                }
+            },
+            '`' => {
+                let len = rest.find(|c| c != '`').unwrap_or(rest.len());
+                match find_code_end(&rest[len..], len) {
+                    Some(end) => {
+                        let code = rest[len..len + end].replace('\n', " ");
+                        let code = match code.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+                            Some(stripped) if !code.trim().is_empty() => String::from(stripped),
+                            _ => code,
+                        };
+                        pieces.push(Piece::Code(code));
+                        matched.push((Bounds::Piece(pieces.len() - 1), MarkdownTag::Code));
+                        position += len + end + len;
+                    }
+                    None => {
+                        push_str(&mut pieces, &rest[..len]);
+                        position += len;
+                    }
+                }
+            }
+            '*' | '_' | '~' => {
+                let len = rest.find(|next| next != c).unwrap_or(rest.len());
+                if c == '~' && len > 2 {
+                    push_str(&mut pieces, &rest[..len]);
+                } else {
+                    let before = source[..position].chars().next_back();
+                    let after = rest[len..].chars().next();
+                    let (left, right) = flanking(before, after);
+                    let (can_open, can_close) = if c == '_' {
+                        (
+                            left && (!right || before.is_some_and(is_punctuation)),
+                            right && (!left || after.is_some_and(is_punctuation)),
+                        )
+                    } else {
+                        (left, right)
+                    };
+                    pieces.push(Piece::Delimiter(Delimiter {
+                        c,
+                        len,
+                        original: len,
+                        can_open,
+                        can_close,
+                        active: true,
+                    }));
+                }
+                position += len;
+            }
+            '[' => {
+                brackets.push(pieces.len());
+                pieces.push(Piece::Bracket(true));
+                position += 1;
+            }
+            ']' => {
+                position += 1;
+                let Some(opener) = brackets.pop() else {
+                    push_text(&mut pieces, ']');
+                    continue;
+                };
+                let link = matches!(pieces[opener], Piece::Bracket(true))
+                    .then(|| parse_destination(&source[position..]))
+                    .flatten();
+                let Some((destination, len)) = link else {
+                    pieces[opener] = Piece::Text(String::from("["));
+                    push_text(&mut pieces, ']');
+                    continue;
+                };
+                process_emphasis(&mut pieces, &mut matched, opener + 1);
+                for piece in &mut pieces[opener + 1..] {
+                    if let Piece::Delimiter(delimiter) = piece {
+                        delimiter.active = false;
+                    }
+                }
+                pieces[opener] = Piece::Text(String::new());
+                pieces.push(Piece::Text(String::new()));
+                matched.push((
+                    Bounds::Between(opener, pieces.len() - 1),
+                    MarkdownTag::Link(destination),
+                ));
+                // Links can't contain other links.
+                for &bracket in &brackets {
+                    pieces[bracket] = Piece::Bracket(false);
+                }
+                position += len;
+            }
+            '&' => match decode_entity(rest) {
+                Some((c, len)) => {
+                    push_text(&mut pieces, c);
+                    position += len;
+                }
+                None => {
+                    push_text(&mut pieces, '&');
+                    position += 1;
+                }
+            },
+            '\n' => {
+                let hard = source[..position].ends_with("  ");
+                if let Some(Piece::Text(text)) = pieces.last_mut() {
+                    text.truncate(text.trim_end_matches(' ').len());
+                }
+                push_text(&mut pieces, if hard { LINE_SEPARATOR } else { ' ' });
+                position += 1;
+            }
+            _ => {
+                let len = rest
+                    .find(['\\', '`', '*', '_', '~', '[', ']', '&', '\n'])
+                    .unwrap_or(rest.len());
+                push_str(&mut pieces, &rest[..len]);
+                position += len;
+            }
+        }
+    }
+    process_emphasis(&mut pieces, &mut matched, 0);
+
+    let mut starts = Vec::with_capacity(pieces.len());
+    let mut ends = Vec::with_capacity(pieces.len());
+    for piece in &pieces {
+        starts.push(text.len());
+        match piece {
+            Piece::Text(content) | Piece::Code(content) => text.push_str(content),
+            Piece::Delimiter(delimiter) => text.extend((0..delimiter.len).map(|_| delimiter.c)),
+            Piece::Bracket(_) => text.push('['),
+        }
+        ends.push(text.len());
+    }
+    for (bounds, tag) in matched {
+        let range = match bounds {
+            Bounds::Between(opener, closer) => ends[opener]..starts[closer],
+            Bounds::Piece(piece) => starts[piece]..ends[piece],
+        };
+        if !range.is_empty() {
+            spans.push((range, tag));
+        }
+    }
+}
+
+fn push_text(pieces: &mut Vec<Piece>, c: char) {
+    match pieces.last_mut() {
+        Some(Piece::Text(text)) => text.push(c),
+        _ => pieces.push(Piece::Text(c.into())),
+    }
+}
+
+fn push_str(pieces: &mut Vec<Piece>, s: &str) {
+    match pieces.last_mut() {
+        Some(Piece::Text(text)) => text.push_str(s),
+        _ => pieces.push(Piece::Text(s.into())),
+    }
+}
+
+/// Find the run of exactly `len` backticks that closes a code span, returning its offset.
+fn find_code_end(source: &str, len: usize) -> Option<usize> {
+    let mut position = 0;
+    while let Some(start) = source[position..].find('`') {
+        let start = position + start;
+        let run = source[start..]
+            .find(|c| c != '`')
+            .unwrap_or(source.len() - start);
+        if run == len {
+            return Some(start);
+        }
+        position = start + run;
+    }
+    None
+}
+
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation() || (!c.is_ascii() && !c.is_alphanumeric() && !c.is_whitespace())
+}
+
+/// Returns whether a delimiter run between `before` and `after` is left- and right-flanking.
+fn flanking(before: Option<char>, after: Option<char>) -> (bool, bool) {
+    let before_space = before.is_none_or(char::is_whitespace);
+    let after_space = after.is_none_or(char::is_whitespace);
+    let before_punctuation = before.is_some_and(is_punctuation);
+    let after_punctuation = after.is_some_and(is_punctuation);
+    (
+        !after_space && (!after_punctuation || before_space || before_punctuation),
+        !before_space && (!before_punctuation || after_space || after_punctuation),
+    )
+}
+
+/// Match the delimiters from `bottom` onwards, as in the `CommonMark` "process emphasis"
+/// procedure.
+fn process_emphasis(pieces: &mut [Piece], matched: &mut Vec<(Bounds, MarkdownTag)>, bottom: usize) {
+    for closer in bottom..pieces.len() {
+        while let Piece::Delimiter(close) = pieces[closer] {
+            if !close.active || !close.can_close || close.len == 0 {
+                break;
+            }
+            let opener = (bottom..closer)
+                .rev()
+                .find_map(|index| match pieces[index] {
+                    Piece::Delimiter(open)
+                        if open.active && open.can_open && open.len > 0 && open.c == close.c =>
+                    {
+                        let matches = if close.c == '~' {
+                            open.len == close.len
+                        } else {
+                            // The "rule of 3".
+                            !((open.can_close || close.can_open)
+                                && (open.original + close.original) % 3 == 0
+                                && !(open.original % 3 == 0 && close.original % 3 == 0))
+                        };
+                        matches.then_some((index, open))
+                    }
+                    _ => None,
+                });
+            let Some((opener, open)) = opener else {
+                if let Piece::Delimiter(close) = &mut pieces[closer] {
+                    close.active = close.can_open;
+                }
+                break;
+            };
+            let (used, tag) = if close.c == '~' {
+                (close.len, MarkdownTag::Strikethrough)
+            } else if open.len >= 2 && close.len >= 2 {
+                (2, MarkdownTag::Strong)
+            } else {
+                (1, MarkdownTag::Emphasis)
+            };
+            for (index, piece) in pieces[opener..=closer].iter_mut().enumerate() {
+                if let Piece::Delimiter(delimiter) = piece {
+                    if index == 0 || index == closer - opener {
+                        delimiter.len -= used;
+                    } else {
+                        delimiter.active = false;
+                    }
+                }
+            }
+            matched.push((Bounds::Between(opener, closer), tag));
+        }
+    }
+}
+
+/// Parse the destination of an inline link, which follows its text.
+///
+/// Returns the destination and the length of the source up to the closing parenthesis.
+fn parse_destination(source: &str) -> Option<(String, usize)> {
+    let mut rest = source.strip_prefix('(')?.trim_start();
+    let mut destination = String::new();
+    if let Some(angle) = rest.strip_prefix('<') {
+        rest = angle;
+        loop {
+            match rest.chars().next()? {
+                '>' => {
+                    rest = &rest[1..];
+                    break;
+                }
+                '<' | '\n' => return None,
+                _ => rest = unescape_char(&mut destination, rest),
+            }
+        }
+    } else {
+        let mut depth = 0_usize;
+        while let Some(c) = rest.chars().next() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => break,
+                ')' => depth -= 1,
+                _ if c.is_whitespace() || c.is_control() => break,
+                _ => {}
+            }
+            rest = unescape_char(&mut destination, rest);
+        }
+    }
+    rest = rest.trim_start();
+    // Titles aren't represented, but are skipped.
+    if let Some(quote) = rest
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '"' | '\'' | '('))
+    {
+        let close = if quote == '(' { ')' } else { quote };
+        let mut title = &rest[1..];
+        loop {
+            let c = title.chars().next()?;
+            if c == close {
+                break;
+            }
+            title = &title[c.len_utf8()..];
+            if c == '\\' {
+                title = title
+                    .strip_prefix(|c: char| c.is_ascii_punctuation())
+                    .unwrap_or(title);
+            }
+        }
+        rest = title[1..].trim_start();
+    }
+    let rest = rest.strip_prefix(')')?;
+    Some((destination, source.len() - rest.len()))
+}
+
+/// Append the first character of `source` to `text`, decoding a backslash escape or character
+/// reference, and return the rest of `source`.
+fn unescape_char<'a>(text: &mut String, source: &'a str) -> &'a str {
+    let mut chars = source.chars();
+    match chars.next() {
+        Some('\\') => match chars.next() {
+            Some(c) if c.is_ascii_punctuation() => {
+                text.push(c);
+                &source[2..]
+            }
+            _ => {
+                text.push('\\');
+                &source[1..]
+            }
+        },
+        Some('&') => {
+            let (c, len) = decode_entity(source).unwrap_or(('&', 1));
+            text.push(c);
+            &source[len..]
+        }
+        Some(c) => {
+            text.push(c);
+            &source[c.len_utf8()..]
+        }
+        None => source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::{MarkdownAttribute, MarkdownTag};
+    use crate::AttributedText;
+    use crate::markup::tests::{attributed, represented_runs, runs};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Style {
+        Italic,
+        Bold,
+        Strike,
+        Code,
+        Link(String),
+        Color(u8),
+    }
+
+    impl MarkdownAttribute for Style {
+        fn to_markdown(&self) -> Option<MarkdownTag> {
+            Some(match self {
+                Self::Italic => MarkdownTag::Emphasis,
+                Self::Bold => MarkdownTag::Strong,
+                Self::Strike => MarkdownTag::Strikethrough,
+                Self::Code => MarkdownTag::Code,
+                Self::Link(url) => MarkdownTag::Link(url.clone()),
+                Self::Color(_) => return None,
+            })
+        }
+
+        fn from_markdown(tag: &MarkdownTag) -> Option<Self> {
+            Some(match tag {
+                MarkdownTag::Emphasis => Self::Italic,
+                MarkdownTag::Strong => Self::Bold,
+                MarkdownTag::Strikethrough => Self::Strike,
+                MarkdownTag::Code => Self::Code,
+                MarkdownTag::Link(url) => Self::Link(url.clone()),
+            })
+        }
+    }
+
+    #[test]
+    fn to_markdown() {
+        let text = attributed(
+            "Some bold, italic code and a link.\nSecond\u{2028}line",
+            &[
+                (5, 9, Style::Bold),
+                (11, 17, Style::Italic),
+                (11, 22, Style::Color(1)),
+                (18, 22, Style::Code),
+                (29, 33, Style::Link(String::from("https://example.com/a b"))),
+            ],
+        );
+        assert_eq!(
+            text.to_markdown(),
+            "Some **bold**, *italic* `code` and a [link](<https://example.com/a b>).\n\nSecond\\\nline"
+        );
+    }
+
+    #[test]
+    fn to_markdown_escapes() {
+        let text = attributed(
+            "# 1. *not* `markup` [x] & <y>\n- z!!\n  indented",
+            &[(34, 35, Style::Link(String::from("u")))],
+        );
+        assert_eq!(
+            text.to_markdown(),
+            "\\# 1. \\*not\\* \\`markup\\` \\[x\\] \\& \\<y>\n\n\\- z\\![\\!](u)\n\n&#32; indented"
+        );
+    }
+
+    #[test]
+    fn from_markdown() {
+        let text = AttributedText::<String, Style>::from_markdown(
+            "  *Emphasis* and __strong__\nsoft, ~~gone~~ `` a`b ``  \nhard\n\n\n[a *link*](</x y> \"title\") 3 * 4\\*",
+        );
+        assert_eq!(
+            text.as_str(),
+            "Emphasis and strong soft, gone a`b\u{2028}hard\na link 3 * 4*"
+        );
+        assert_eq!(
+            runs(&text),
+            [
+                ("Emphasis", Vec::from([Style::Italic])),
+                (" and ", Vec::new()),
+                ("strong", Vec::from([Style::Bold])),
+                (" soft, ", Vec::new()),
+                ("gone", Vec::from([Style::Strike])),
+                (" ", Vec::new()),
+                ("a`b", Vec::from([Style::Code])),
+                ("\u{2028}hard\n", Vec::new()),
+                ("a ", Vec::from([Style::Link(String::from("/x y"))])),
+                (
+                    "link",
+                    Vec::from([Style::Link(String::from("/x y")), Style::Italic])
+                ),
+                (" 3 * 4*", Vec::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_markdown_nested_delimiters() {
+        let text = AttributedText::<String, Style>::from_markdown("***both** one* **a*b*c**");
+        assert_eq!(text.as_str(), "both one abc");
+        assert_eq!(
+            runs(&text),
+            [
+                ("both", Vec::from([Style::Italic, Style::Bold])),
+                (" one", Vec::from([Style::Italic])),
+                (" ", Vec::new()),
+                ("a", Vec::from([Style::Bold])),
+                ("b", Vec::from([Style::Bold, Style::Italic])),
+                ("c", Vec::from([Style::Bold])),
+            ]
+        );
+    }
+
+    #[test]
+    fn markdown_round_trip() {
+        let text = attributed(
+            "Bold and italic text, struck * through, with code``s and links!\n\
+             # Not a heading\u{2028}2) not a list, [brackets] & entities&#32;\n\
+             x\\y ~ _ a_b",
+            &[
+                (0, 15, Style::Bold),
+                (9, 15, Style::Italic),
+                (16, 19, Style::Italic),
+                (22, 38, Style::Strike),
+                (45, 52, Style::Code),
+                (57, 62, Style::Link(String::from("https://example.com/(x)"))),
+                (51, 62, Style::Color(3)),
+                (66, 69, Style::Italic),
+                (82, 88, Style::Bold),
+                (97, 107, Style::Strike),
+                (110, 118, Style::Link(String::from("a&b"))),
+                (124, 135, Style::Bold),
+            ],
+        );
+        let markdown = text.to_markdown();
+        let parsed = AttributedText::<String, Style>::from_markdown(&markdown);
+        assert_eq!(parsed.as_str(), text.as_str(), "{markdown}");
+        assert_eq!(
+            represented_runs(&parsed, |_| true),
+            represented_runs(&text, |style| !matches!(style, Style::Color(_))),
+            "{markdown}"
+        );
+    }
+
+    #[test]
+    fn to_markdown_omits_unflanked_delimiters() {
+        // The emphasis reopened after closing both would be written as a single run of
+        // delimiters, which closes and opens.
+        let text = attributed("céccb", &[(0, 4, Style::Bold), (3, 6, Style::Italic)]);
+        assert_eq!(text.to_markdown(), "**cé*c***cb");
+        // The closing delimiter would be preceded by punctuation and followed by a letter.
+        let text = attributed("aébccé", &[(0, 5, Style::Code), (1, 5, Style::Italic)]);
+        assert_eq!(text.to_markdown(), "`aébc`cé");
+    }
+
+    /// A small deterministic pseudo-random number generator.
+    fn lcg(state: &mut u64) -> usize {
+        *state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*state >> 33) as usize
+    }
+
+    #[test]
+    fn markdown_round_trip_random() {
+        const CHARS: &[char] = &[
+            'a', 'b', 'é', ' ', '*', '_', '~', '`', '[', ']', '(', ')', '!', '\\', '&', '.', '1',
+            '#',
+        ];
+        let mut state = 1;
+        for _ in 0..2000 {
+            let mut text: String = (0..1 + lcg(&mut state) % 12)
+                .map(|_| CHARS[lcg(&mut state) % CHARS.len()])
+                .collect();
+            // Whitespace at the end of a paragraph is lost.
+            if text.ends_with(' ') {
+                text.push('a');
+            }
+            let boundaries: Vec<_> = text
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain([text.len()])
+                .collect();
+            let spans: Vec<_> = (0..lcg(&mut state) % 5)
+                .map(|_| {
+                    let a = boundaries[lcg(&mut state) % boundaries.len()];
+                    let b = boundaries[lcg(&mut state) % boundaries.len()];
+                    let style = match lcg(&mut state) % 5 {
+                        0 => Style::Italic,
+                        1 => Style::Bold,
+                        2 => Style::Strike,
+                        3 => Style::Code,
+                        _ => Style::Link(String::from("u")),
+                    };
+                    (a.min(b), a.max(b), style)
+                })
+                .collect();
+            let text = attributed(&text, &spans);
+
+            // The text is kept, and markup may be omitted, but never added.
+            let markdown = text.to_markdown();
+            let parsed = AttributedText::<String, Style>::from_markdown(&markdown);
+            assert_eq!(parsed.as_str(), text.as_str(), "{markdown} from {text:?}");
+            for &index in &boundaries[..boundaries.len() - 1] {
+                let styles: Vec<_> = text.attributes_at(index).collect();
+                assert!(
+                    parsed
+                        .attributes_at(index)
+                        .all(|style| styles.contains(&style)),
+                    "{markdown} from {text:?} at {index}"
+                );
+            }
+        }
+    }
+}
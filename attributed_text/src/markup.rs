@@ -0,0 +1,154 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Helpers shared by the markup serializers.
+//!
+//! Both formats represent the text as paragraphs, which are separated by `'\n'` in the text,
+//! and lines within a paragraph, which are separated by U+2028 LINE SEPARATOR.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::ops::Range;
+
+use crate::{AttributedText, TextStorage};
+
+/// The separator of lines within a paragraph.
+pub(crate) const LINE_SEPARATOR: char = '\u{2028}';
+
+/// A run of text with the tags of the attributes that apply to it.
+pub(crate) type TaggedRun<Tag> = (Range<usize>, Vec<Tag>);
+
+/// Splits the text into paragraphs, each of which is a sequence of maximal runs with the same
+/// tags.
+///
+/// The tags of a run are in the order their attributes were applied, without duplicates.
+/// Attributes that `tag` maps to `None` are ignored.
+pub(crate) fn tagged_paragraphs<T, A, Tag>(
+    text: &AttributedText<T, A>,
+    tag: impl Fn(&A) -> Option<Tag>,
+) -> Vec<Vec<TaggedRun<Tag>>>
+where
+    T: Debug + TextStorage + AsRef<str>,
+    A: Debug,
+    Tag: PartialEq,
+{
+    let str = text.as_str();
+    if str.is_empty() {
+        return Vec::new();
+    }
+    let mut paragraphs = Vec::new();
+    let mut paragraph_start = 0;
+    let mut runs = text.style_runs().peekable();
+    for paragraph_end in str
+        .match_indices('\n')
+        .map(|(index, _)| index)
+        .chain([str.len()])
+    {
+        let mut tagged: Vec<TaggedRun<Tag>> = Vec::new();
+        while let Some((range, attributes)) = runs.peek() {
+            let start = range.start.max(paragraph_start);
+            let end = range.end.min(paragraph_end);
+            if start < end {
+                let mut tags = Vec::new();
+                for tag in attributes.iter().filter_map(|attribute| tag(attribute)) {
+                    if !tags.contains(&tag) {
+                        tags.push(tag);
+                    }
+                }
+                match tagged.last_mut() {
+                    Some((last, last_tags)) if *last_tags == tags => last.end = end,
+                    _ => tagged.push((start..end, tags)),
+                }
+            }
+            if range.end > paragraph_end {
+                break;
+            }
+            runs.next();
+        }
+        paragraphs.push(tagged);
+        paragraph_start = paragraph_end + 1;
+    }
+    paragraphs
+}
+
+/// Returns the number of leading tags that `a` and `b` have in common.
+pub(crate) fn common_prefix<Tag: PartialEq>(a: &[Tag], b: &[Tag]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+/// Decodes the character reference at the start of `text`, such as `&amp;` or `&#x2028;`.
+///
+/// Returns the character and the length of the reference in bytes.
+pub(crate) fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let end = text.get(..32).unwrap_or(text).find(';')?;
+    let name = text.get(1..end)?;
+    let c = if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        char::from_u32(code).filter(|&c| c != '\0')?
+    } else {
+        match name {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{a0}',
+            _ => return None,
+        }
+    };
+    Some((c, end + 1))
+}
+
+/// Fixtures shared by the tests of the markup serializers.
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt::Debug;
+
+    use crate::AttributedText;
+
+    /// Returns `text` with the attributes of `spans` applied in order.
+    pub(crate) fn attributed<A: Clone + Debug>(
+        text: &str,
+        spans: &[(usize, usize, A)],
+    ) -> AttributedText<String, A> {
+        let mut attributed = AttributedText::new(String::from(text));
+        for (start, end, attribute) in spans {
+            attributed
+                .apply_attribute(*start..*end, attribute.clone())
+                .unwrap();
+        }
+        attributed
+    }
+
+    /// Returns the style runs of `text` with their text.
+    pub(crate) fn runs<A: Clone + Debug>(text: &AttributedText<String, A>) -> Vec<(&str, Vec<A>)> {
+        let str = text.as_str();
+        text.style_runs()
+            .map(|(range, attributes)| (&str[range], attributes.into_iter().cloned().collect()))
+            .collect()
+    }
+
+    /// Returns the style runs of `text` with only the attributes that are `represented` in
+    /// markup, merging the runs that are left with the same attributes.
+    pub(crate) fn represented_runs<A: Clone + Debug + PartialEq>(
+        text: &AttributedText<String, A>,
+        represented: impl Fn(&A) -> bool,
+    ) -> Vec<(String, Vec<A>)> {
+        let mut merged: Vec<(String, Vec<A>)> = Vec::new();
+        for (text, mut attributes) in runs(text) {
+            attributes.retain(&represented);
+            match merged.last_mut() {
+                Some((last, last_attributes)) if *last_attributes == attributes => {
+                    last.push_str(text);
+                }
+                _ => merged.push((String::from(text), attributes)),
+            }
+        }
+        merged
+    }
+}
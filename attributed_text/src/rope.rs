@@ -0,0 +1,87 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Text storage implementations for [`ropey::Rope`].
+
+use core::ops::Range;
+
+use ropey::Rope;
+
+use crate::{TextChunks, TextStorage, TextStorageMut};
+
+impl TextStorage for Rope {
+    fn len(&self) -> usize {
+        self.len_bytes()
+    }
+
+    fn is_char_boundary(&self, index: usize) -> bool {
+        // `byte_to_char` returns the char that contains the byte, so an index is on a boundary
+        // exactly when it is the start of that char.
+        index <= self.len_bytes() && self.char_to_byte(self.byte_to_char(index)) == index
+    }
+}
+
+impl TextStorageMut for Rope {
+    fn replace_range(&mut self, range: Range<usize>, replacement: &str) {
+        let start = self.byte_to_char(range.start);
+        let end = self.byte_to_char(range.end);
+        self.remove(start..end);
+        self.insert(start, replacement);
+    }
+}
+
+impl TextChunks for Rope {
+    fn chunks(&self) -> impl Iterator<Item = &str> {
+        let mut chunks = Self::chunks(self)
+            .filter(|chunk| !chunk.is_empty())
+            .peekable();
+        // Yield a single empty chunk for empty text, as contiguous storage does.
+        let empty = chunks.peek().is_none().then_some("");
+        chunks.chain(empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use ropey::Rope;
+
+    use crate::{AttributedText, TextChunks, TextStorage};
+
+    #[test]
+    fn rope_char_boundaries() {
+        let rope = Rope::from_str("éclair 🇯🇵");
+        assert_eq!(TextStorage::len(&rope), "éclair 🇯🇵".len());
+        for index in 0..=rope.len_bytes() + 1 {
+            assert_eq!(
+                rope.is_char_boundary(index),
+                "éclair 🇯🇵".is_char_boundary(index),
+                "index {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn rope_chunks() {
+        let text = "Some long text. ".repeat(200);
+        let rope = Rope::from_str(&text);
+        let chunks: Vec<_> = TextChunks::chunks(&rope).collect();
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), text);
+
+        assert_eq!(TextChunks::chunks(&Rope::new()).collect::<Vec<_>>(), [""]);
+    }
+
+    #[test]
+    fn rope_edits() {
+        let mut at = AttributedText::new(Rope::from_str("Hello wörld"));
+        at.apply_attribute(6..12, ()).unwrap();
+        at.replace_range(7..9, "o").unwrap();
+        at.insert(0, "¡").unwrap();
+        assert_eq!(String::from(at.text()), "¡Hello world");
+        let (range, _) = at.attributes_iter().next().unwrap();
+        assert_eq!(*range, 8..13);
+    }
+}
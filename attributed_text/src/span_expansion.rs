@@ -0,0 +1,41 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// How an attribute span grows when text is inserted at one of its ends.
+///
+/// Text inserted strictly inside a span always becomes part of it, and text inserted strictly
+/// outside of it never does. This only decides what happens at the boundaries, which is what
+/// makes styles "sticky" when typing at the edge of a styled run.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SpanExpansion {
+    /// Text inserted at either end is not covered by the span.
+    ///
+    /// This suits attributes such as links, which shouldn't extend as the user types next to
+    /// them.
+    Exclusive,
+
+    /// Text inserted at the start of the span is covered by it.
+    InclusiveStart,
+
+    /// Text inserted at the end of the span is covered by it.
+    ///
+    /// This matches the behavior of most rich text editors for styles such as bold or italic,
+    /// where typing after a styled word continues the style.
+    #[default]
+    InclusiveEnd,
+
+    /// Text inserted at either end is covered by the span.
+    Inclusive,
+}
+
+impl SpanExpansion {
+    /// Returns `true` if text inserted at the start of a span is covered by it.
+    pub fn includes_start(self) -> bool {
+        matches!(self, Self::InclusiveStart | Self::Inclusive)
+    }
+
+    /// Returns `true` if text inserted at the end of a span is covered by it.
+    pub fn includes_end(self) -> bool {
+        matches!(self, Self::InclusiveEnd | Self::Inclusive)
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// An index over the ranges of attribute spans, answering overlap queries in logarithmic time.
+///
+/// Spans are stored in levels, each of which is a static interval tree: its entries are sorted
+/// by start, and the entry in the middle of each subrange stores the maximum end of that
+/// subrange. Level `i` holds at most `2^i` entries. Inserting a span merges full levels into the
+/// next one, like incrementing a binary counter, so that insertion is amortized `O(log n)` and a
+/// query visits `O(log n)` levels.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SpanIndex {
+    levels: Vec<Level>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Level {
+    entries: Vec<Entry>,
+    /// The maximum end of the entries in the subrange whose middle is at the same index.
+    max_end: Vec<usize>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Entry {
+    start: usize,
+    end: usize,
+    id: usize,
+}
+
+impl SpanIndex {
+    /// Create an index of `ranges`, identified by their position in the iterator.
+    pub(crate) fn new(ranges: impl Iterator<Item = Range<usize>>) -> Self {
+        let mut entries: Vec<Entry> = ranges
+            .enumerate()
+            .map(|(id, range)| Entry {
+                start: range.start,
+                end: range.end,
+                id,
+            })
+            .collect();
+        if entries.is_empty() {
+            return Self::default();
+        }
+        entries.sort_unstable_by_key(|entry| entry.start);
+        // Leave the lower levels empty, so that the next insertions don't merge with this one.
+        let level = entries.len().next_power_of_two().trailing_zeros() as usize;
+        let mut levels = vec![Level::default(); level];
+        levels.push(Level::new(entries));
+        Self { levels }
+    }
+
+    /// Insert a span with the given `range` and `id`.
+    pub(crate) fn insert(&mut self, range: Range<usize>, id: usize) {
+        let mut carry = vec![Entry {
+            start: range.start,
+            end: range.end,
+            id,
+        }];
+        for level in &mut self.levels {
+            if level.entries.is_empty() {
+                *level = Level::new(carry);
+                return;
+            }
+            carry = merge(core::mem::take(&mut level.entries), carry);
+            level.max_end.clear();
+        }
+        self.levels.push(Level::new(carry));
+    }
+
+    /// Update the spans in place, for example after the text is edited.
+    ///
+    /// `update` maps the id of each span to its new id and range, or to `None` to remove the
+    /// span. This takes linear time when the order of the starts of the spans mostly doesn't
+    /// change, as is the case for edits.
+    pub(crate) fn update(
+        &mut self,
+        mut update: impl FnMut(usize) -> Option<(usize, Range<usize>)>,
+    ) {
+        for level in &mut self.levels {
+            level.entries.retain_mut(|entry| {
+                let Some((id, range)) = update(entry.id) else {
+                    return false;
+                };
+                *entry = Entry {
+                    start: range.start,
+                    end: range.end,
+                    id,
+                };
+                true
+            });
+            // A stable sort is linear on runs that are already sorted.
+            level.entries.sort_by_key(|entry| entry.start);
+            level.max_end.truncate(level.entries.len());
+            level.build(0, level.entries.len());
+        }
+    }
+
+    /// Remove all spans.
+    pub(crate) fn clear(&mut self) {
+        self.levels.clear();
+    }
+
+    /// Returns the sorted ids of the spans that start before the end of `range` and end after
+    /// its start.
+    ///
+    /// An id inserted several times is returned once if any of its ranges match.
+    pub(crate) fn overlapping(&self, range: Range<usize>) -> Vec<usize> {
+        let mut ids = Vec::new();
+        for level in &self.levels {
+            level.overlapping(&range, 0, level.entries.len(), &mut ids);
+        }
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+impl Level {
+    fn new(entries: Vec<Entry>) -> Self {
+        let mut level = Self {
+            max_end: vec![0; entries.len()],
+            entries,
+        };
+        level.build(0, level.entries.len());
+        level
+    }
+
+    /// Compute the maximum ends of the subrange `lo..hi`, returning its maximum end.
+    fn build(&mut self, lo: usize, hi: usize) -> usize {
+        if lo >= hi {
+            return 0;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let max_end = self.entries[mid]
+            .end
+            .max(self.build(lo, mid))
+            .max(self.build(mid + 1, hi));
+        self.max_end[mid] = max_end;
+        max_end
+    }
+
+    /// Push the ids of the entries of the subrange `lo..hi` that overlap `range`.
+    fn overlapping(&self, range: &Range<usize>, lo: usize, hi: usize, ids: &mut Vec<usize>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_end[mid] <= range.start {
+            // Every entry in the subrange ends before the range.
+            return;
+        }
+        self.overlapping(range, lo, mid, ids);
+        let entry = self.entries[mid];
+        if entry.start < range.end {
+            if entry.end > range.start {
+                ids.push(entry.id);
+            }
+            self.overlapping(range, mid + 1, hi, ids);
+        }
+    }
+}
+
+/// Merge two lists of entries sorted by start.
+fn merge(a: Vec<Entry>, b: Vec<Entry>) -> Vec<Entry> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+    while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+        if x.start <= y.start {
+            merged.extend(a.next());
+        } else {
+            merged.extend(b.next());
+        }
+    }
+    merged.extend(a);
+    merged.extend(b);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpanIndex;
+    use alloc::vec::Vec;
+    use core::ops::Range;
+
+    /// A small deterministic pseudo-random number generator.
+    fn lcg(state: &mut u64) -> usize {
+        *state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (*state >> 33) as usize
+    }
+
+    fn brute_force(ranges: &[Range<usize>], query: &Range<usize>) -> Vec<usize> {
+        (0..ranges.len())
+            .filter(|&id| ranges[id].start < query.end && ranges[id].end > query.start)
+            .collect()
+    }
+
+    #[test]
+    fn matches_linear_scan() {
+        let mut state = 1;
+        let mut ranges = Vec::new();
+        let mut index = SpanIndex::default();
+        for id in 0..300 {
+            let start = lcg(&mut state) % 200;
+            let len = if id % 50 == 0 {
+                150
+            } else {
+                lcg(&mut state) % 8
+            };
+            let range = start..start + len;
+            index.insert(range.clone(), ranges.len());
+            ranges.push(range);
+
+            if id % 37 == 0 {
+                // Rebuilding from scratch gives the same answers.
+                index = SpanIndex::new(ranges.iter().cloned());
+            }
+            if id % 23 == 0 {
+                // Updating in place, as is done after edits, gives the same answers. Remove
+                // every fifth span and shift the spans after an edit point.
+                let at = lcg(&mut state) % 200;
+                let shift = lcg(&mut state) % 5;
+                let mut new_ids = Vec::new();
+                let mut kept = Vec::new();
+                for (id, range) in ranges.iter().enumerate() {
+                    if id % 5 == 4 {
+                        new_ids.push(None);
+                        continue;
+                    }
+                    let shift = |index: usize| if index > at { index + shift } else { index };
+                    new_ids.push(Some(kept.len()));
+                    kept.push(shift(range.start)..shift(range.end));
+                }
+                index.update(|id| new_ids[id].map(|new_id| (new_id, kept[new_id].clone())));
+                ranges = kept;
+            }
+            for _ in 0..4 {
+                let start = lcg(&mut state) % 220;
+                let query = start..start + lcg(&mut state) % 10;
+                assert_eq!(
+                    index.overlapping(query.clone()),
+                    brute_force(&ranges, &query),
+                    "query {query:?} after {} insertions",
+                    id + 1
+                );
+            }
+        }
+    }
+}
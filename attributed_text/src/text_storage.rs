@@ -3,6 +3,7 @@
 
 use alloc::string::String;
 use alloc::sync::Arc;
+use core::ops::Range;
 
 /// A block of text that will be wrapped by an [`AttributedText`].
 ///
@@ -24,6 +25,33 @@ pub trait TextStorage {
     fn is_char_boundary(&self, index: usize) -> bool;
 }
 
+/// A [`TextStorage`] that can be edited in place.
+///
+/// This is required by the editing operations of [`AttributedText`], such as
+/// [`AttributedText::replace_range`].
+///
+/// [`AttributedText`]: crate::AttributedText
+/// [`AttributedText::replace_range`]: crate::AttributedText::replace_range
+pub trait TextStorageMut: TextStorage {
+    /// Replace the text in `range` with `replacement`.
+    ///
+    /// Callers guarantee that `range` is in bounds and that both of its ends are on
+    /// character boundaries.
+    fn replace_range(&mut self, range: Range<usize>, replacement: &str);
+}
+
+/// A [`TextStorage`] whose text can be read as a sequence of string slices.
+///
+/// Contiguous storage yields a single chunk, while ropes and other piece-wise representations
+/// yield their pieces in order, without flattening the text.
+pub trait TextChunks: TextStorage {
+    /// Iterate over the chunks of the text, in order.
+    ///
+    /// The chunks are non-empty, unless the whole text is empty, and their concatenation is
+    /// the text.
+    fn chunks(&self) -> impl Iterator<Item = &str>;
+}
+
 impl TextStorage for String {
     fn len(&self) -> usize {
         Self::len(self)
@@ -34,6 +62,18 @@ impl TextStorage for String {
     }
 }
 
+impl TextStorageMut for String {
+    fn replace_range(&mut self, range: Range<usize>, replacement: &str) {
+        Self::replace_range(self, range, replacement);
+    }
+}
+
+impl TextChunks for String {
+    fn chunks(&self) -> impl Iterator<Item = &str> {
+        core::iter::once(self.as_str())
+    }
+}
+
 impl TextStorage for &str {
     fn len(&self) -> usize {
         str::len(self)
@@ -44,6 +84,12 @@ impl TextStorage for &str {
     }
 }
 
+impl TextChunks for &str {
+    fn chunks(&self) -> impl Iterator<Item = &str> {
+        core::iter::once(*self)
+    }
+}
+
 impl TextStorage for Arc<str> {
     fn len(&self) -> usize {
         str::len(self)
@@ -54,6 +100,12 @@ impl TextStorage for Arc<str> {
     }
 }
 
+impl TextChunks for Arc<str> {
+    fn chunks(&self) -> impl Iterator<Item = &str> {
+        core::iter::once(&**self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::TextStorage;
@@ -348,7 +348,7 @@ impl Editor {
             );
         });
         if self.cursor_visible {
-            if let Some(cursor) = self.editor.cursor_geometry(1.5) {
+            for cursor in self.editor.all_cursor_geometry(1.5) {
                 scene.fill(
                     Fill::NonZero,
                     transform,
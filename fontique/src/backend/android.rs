@@ -200,9 +200,14 @@ impl SystemFonts {
 
         key.locale()
             .and_then(|locale| {
+                // Compare canonical forms so that, for example, a request
+                // for `zh-TW` matches a `zh-Hant` entry in fonts.xml.
                 self.locale_fallback
                     .iter()
-                    .find(|(other, _)| locale == *other)
+                    .find(|(other, _)| {
+                        locale == *other
+                            || FallbackKey::new(script, Some(other)).locale() == Some(locale)
+                    })
                     .map(|(_, fid)| *fid)
             })
             .or_else(|| {
@@ -16,17 +16,21 @@ use super::{
     backend::SystemFonts,
     fallback::{FallbackKey, FallbackMap},
     family::{FamilyId, FamilyInfo},
-    family_name::{FamilyName, FamilyNameMap},
+    family_name::{FamilyName, FamilyNameMap, InstanceNameMap},
     font::FontInfo,
     generic::GenericFamilyMap,
     source::{SourceId, SourceInfo, SourceKind},
 };
 use crate::AtomicCounter;
-use alloc::{string::String, sync::Arc, vec::Vec};
+use alloc::{format, string::String, sync::Arc, vec::Vec};
 use hashbrown::HashMap;
 use read_fonts::types::NameId;
+use smallvec::SmallVec;
 #[cfg(feature = "std")]
-use std::sync::{Mutex, atomic::Ordering};
+use std::{
+    path::{Path, PathBuf},
+    sync::{Mutex, atomic::Ordering},
+};
 
 type FamilyMap = HashMap<FamilyId, Option<FamilyInfo>>;
 
@@ -200,11 +204,56 @@ impl Collection {
     }
 
     /// Clears this collection. Un-registers all fonts previously registered via
-    /// [`Self::register_fonts`], and unsets all previously-set generic families
-    /// and fallbacks. This will not remove any system fonts.
+    /// [`Self::register_fonts`], and unsets all previously-set generic families,
+    /// fallbacks and scan paths. This will not remove any system fonts.
     pub fn clear(&mut self) {
         self.inner.clear();
     }
+
+    /// Returns the current generation of the collection.
+    ///
+    /// The generation is incremented every time fonts are registered or
+    /// unregistered, or the generic families or fallbacks are changed. This
+    /// can be compared against a previously observed value to determine
+    /// whether anything derived from the collection (such as a text layout)
+    /// is out of date.
+    pub fn generation(&self) -> u64 {
+        self.inner.generation()
+    }
+
+    /// Registers a file or directory in which to look for fonts.
+    ///
+    /// Directories are scanned recursively up to `max_depth` levels deep.
+    /// Fonts under the registered scan paths are loaded, updated and removed
+    /// by [`FontWatcher::poll`](crate::FontWatcher::poll).
+    #[cfg(feature = "std")]
+    pub fn register_scan_path(&mut self, path: impl AsRef<Path>, max_depth: u32) {
+        self.inner.register_scan_path(path.as_ref(), max_depth);
+    }
+
+    /// Unregisters a scan path.
+    ///
+    /// Fonts that were loaded from the path are removed from the collection on
+    /// the next call to [`FontWatcher::poll`](crate::FontWatcher::poll).
+    ///
+    /// Returns true if the path was registered, false otherwise.
+    #[cfg(feature = "std")]
+    pub fn unregister_scan_path(&mut self, path: impl AsRef<Path>) -> bool {
+        self.inner.unregister_scan_path(path.as_ref())
+    }
+
+    /// Returns the registered scan paths, each with its maximum scan depth.
+    #[cfg(feature = "std")]
+    pub fn scan_paths(&self) -> Vec<(PathBuf, u32)> {
+        self.inner.scan_paths()
+    }
+
+    /// Removes fonts loaded from the `removed` paths and registers fonts
+    /// found in the `added` paths.
+    #[cfg(feature = "std")]
+    pub(crate) fn update_font_paths(&mut self, removed: &[PathBuf], added: &[PathBuf]) {
+        self.inner.update_font_paths(removed, added);
+    }
 }
 
 impl Default for Collection {
@@ -254,6 +303,13 @@ impl Inner {
     /// Returns the family identifier for the given family name.
     pub fn family_id(&mut self, name: &str) -> Option<FamilyId> {
         self.sync_shared();
+        self.family_id_by_name(name)
+            .or_else(|| self.named_instance_family(name))
+    }
+
+    /// Returns the identifier of the registered or system family with the
+    /// given name.
+    fn family_id_by_name(&self, name: &str) -> Option<FamilyId> {
         self.data
             .family_names
             .get(name)
@@ -265,6 +321,63 @@ impl Inner {
             .map(|n| n.id())
     }
 
+    /// Returns the identifier of a family containing a single virtual face for
+    /// the named instance with the given full name or PostScript name,
+    /// creating the family if necessary.
+    ///
+    /// Instances of registered fonts are found through an index of their names
+    /// built when the fonts are registered. Instances of system fonts are
+    /// found by looking up each prefix of the name that ends before a space or
+    /// hyphen as a family name, so only matching families are loaded. This
+    /// finds them by full name, and by PostScript name if it starts with the
+    /// family name.
+    fn named_instance_family(&mut self, name: &str) -> Option<FamilyId> {
+        let mut candidates: SmallVec<[FamilyId; 4]> = SmallVec::new();
+        candidates.extend(self.data.instance_names.get(name));
+        candidates.extend(
+            name.char_indices()
+                .rev()
+                .filter(|(_, ch)| matches!(ch, ' ' | '-'))
+                .filter_map(|(index, _)| self.family_id_by_name(&name[..index])),
+        );
+        for id in candidates {
+            let Some(family) = self.family(id) else {
+                continue;
+            };
+            let font = family
+                .fonts()
+                .iter()
+                .filter(|font| font.named_instance().is_none())
+                .find_map(|font| {
+                    let index = font
+                        .named_instances()
+                        .iter()
+                        .position(|instance| instance.matches_name(family.name(), name))?;
+                    font.with_named_instance(index)
+                });
+            if let Some(font) = font {
+                return Some(self.insert_named_instance_family(family.name(), font));
+            }
+        }
+        None
+    }
+
+    /// Inserts a family containing a single virtual face for a named instance.
+    fn insert_named_instance_family(&mut self, family_name: &str, font: FontInfo) -> FamilyId {
+        #[cfg(feature = "std")]
+        if let Some(shared) = &self.shared {
+            let id = shared
+                .data
+                .lock()
+                .unwrap()
+                .insert_named_instance_family(family_name, font);
+            shared.bump_version();
+            self.sync_shared();
+            return id;
+        }
+        self.data.insert_named_instance_family(family_name, font)
+    }
+
     /// Returns the family name for the given family identifier.
     pub fn family_name(&mut self, id: FamilyId) -> Option<&str> {
         self.sync_shared();
@@ -339,14 +452,13 @@ impl Inner {
                 .data
                 .lock()
                 .unwrap()
-                .generic_families
-                .set(generic, families);
+                .set_generic_families(generic, families);
             shared.bump_version();
         } else {
-            self.data.generic_families.set(generic, families);
+            self.data.set_generic_families(generic, families);
         }
         #[cfg(not(feature = "std"))]
-        self.data.generic_families.set(generic, families);
+        self.data.set_generic_families(generic, families);
     }
 
     /// Appends the set of family identifiers to the given generic family.
@@ -362,14 +474,13 @@ impl Inner {
                 .data
                 .lock()
                 .unwrap()
-                .generic_families
-                .append(generic, families);
+                .append_generic_families(generic, families);
             shared.bump_version();
         } else {
-            self.data.generic_families.append(generic, families);
+            self.data.append_generic_families(generic, families);
         }
         #[cfg(not(feature = "std"))]
-        self.data.generic_families.append(generic, families);
+        self.data.append_generic_families(generic, families);
     }
 
     /// Returns an iterator over the fallback families for the given
@@ -414,14 +525,14 @@ impl Inner {
         self.sync_shared();
         #[cfg(feature = "std")]
         if let Some(shared) = &self.shared {
-            let result = shared.data.lock().unwrap().fallbacks.set(key, families);
+            let result = shared.data.lock().unwrap().set_fallbacks(key, families);
             shared.bump_version();
             result
         } else {
-            self.data.fallbacks.set(key, families)
+            self.data.set_fallbacks(key, families)
         }
         #[cfg(not(feature = "std"))]
-        self.data.fallbacks.set(key, families)
+        self.data.set_fallbacks(key, families)
     }
 
     /// Appends the set of family identifiers to the given fallback key.
@@ -433,14 +544,14 @@ impl Inner {
         self.sync_shared();
         #[cfg(feature = "std")]
         if let Some(shared) = &self.shared {
-            let result = shared.data.lock().unwrap().fallbacks.append(key, families);
+            let result = shared.data.lock().unwrap().append_fallbacks(key, families);
             shared.bump_version();
             result
         } else {
-            self.data.fallbacks.append(key, families)
+            self.data.append_fallbacks(key, families)
         }
         #[cfg(not(feature = "std"))]
-        self.data.fallbacks.append(key, families)
+        self.data.append_fallbacks(key, families)
     }
 
     /// Registers all fonts that exist in the given data.
@@ -512,6 +623,78 @@ impl Inner {
         self.data.clear();
     }
 
+    /// Returns the current generation of the collection.
+    pub fn generation(&self) -> u64 {
+        #[cfg(feature = "std")]
+        if let Some(shared) = &self.shared {
+            return shared.data.lock().unwrap().generation;
+        }
+        self.data.generation
+    }
+
+    /// Registers a file or directory in which to look for fonts.
+    #[cfg(feature = "std")]
+    pub fn register_scan_path(&mut self, path: &Path, max_depth: u32) {
+        self.sync_shared();
+        if let Some(shared) = &self.shared {
+            shared
+                .data
+                .lock()
+                .unwrap()
+                .register_scan_path(path, max_depth);
+            shared.bump_version();
+        } else {
+            self.data.register_scan_path(path, max_depth);
+        }
+    }
+
+    /// Unregisters a scan path.
+    #[cfg(feature = "std")]
+    pub fn unregister_scan_path(&mut self, path: &Path) -> bool {
+        self.sync_shared();
+        if let Some(shared) = &self.shared {
+            let result = shared.data.lock().unwrap().unregister_scan_path(path);
+            shared.bump_version();
+            result
+        } else {
+            self.data.unregister_scan_path(path)
+        }
+    }
+
+    /// Returns the registered scan paths.
+    #[cfg(feature = "std")]
+    pub fn scan_paths(&self) -> Vec<(PathBuf, u32)> {
+        if let Some(shared) = &self.shared {
+            shared.data.lock().unwrap().scan_paths.clone()
+        } else {
+            self.data.scan_paths.clone()
+        }
+    }
+
+    /// Removes all fonts loaded from the `removed` paths and registers
+    /// all fonts found in the `added` paths.
+    ///
+    /// Families that no longer contain any fonts are removed from the
+    /// collection, along with any generic family or fallback entries that
+    /// reference them.
+    #[cfg(feature = "std")]
+    pub(crate) fn update_font_paths(&mut self, removed: &[PathBuf], added: &[PathBuf]) {
+        if removed.is_empty() && added.is_empty() {
+            return;
+        }
+        if let Some(shared) = &self.shared {
+            shared
+                .data
+                .lock()
+                .unwrap()
+                .update_font_paths(removed, added);
+            shared.bump_version();
+        } else {
+            self.data.update_font_paths(removed, added);
+            self.fallback_cache.reset();
+        }
+    }
+
     fn sync_shared(&mut self) {
         #[cfg(feature = "std")]
         if let Some(shared) = &self.shared {
@@ -620,21 +803,120 @@ impl System {
 #[derive(Clone, Default)]
 struct CommonData {
     family_names: FamilyNameMap,
+    instance_names: InstanceNameMap,
     families: FamilyMap,
     generic_families: GenericFamilyMap,
     fallbacks: FallbackMap,
+    generation: u64,
+    /// Files and directories in which to look for fonts, with their maximum
+    /// scan depths.
+    #[cfg(feature = "std")]
+    scan_paths: Vec<(PathBuf, u32)>,
 }
 
 impl CommonData {
+    fn bump_generation(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    fn set_generic_families(
+        &mut self,
+        generic: GenericFamily,
+        families: impl Iterator<Item = FamilyId>,
+    ) {
+        self.generic_families.set(generic, families);
+        self.bump_generation();
+    }
+
+    fn append_generic_families(
+        &mut self,
+        generic: GenericFamily,
+        families: impl Iterator<Item = FamilyId>,
+    ) {
+        self.generic_families.append(generic, families);
+        self.bump_generation();
+    }
+
+    fn set_fallbacks(
+        &mut self,
+        key: impl Into<FallbackKey>,
+        families: impl Iterator<Item = FamilyId>,
+    ) -> bool {
+        let result = self.fallbacks.set(key, families);
+        self.bump_generation();
+        result
+    }
+
+    fn append_fallbacks(
+        &mut self,
+        key: impl Into<FallbackKey>,
+        families: impl Iterator<Item = FamilyId>,
+    ) -> bool {
+        let result = self.fallbacks.append(key, families);
+        self.bump_generation();
+        result
+    }
+
+    #[cfg(feature = "std")]
+    fn register_scan_path(&mut self, path: &Path, max_depth: u32) {
+        if let Some(entry) = self.scan_paths.iter_mut().find(|(other, _)| other == path) {
+            entry.1 = max_depth;
+        } else {
+            self.scan_paths.push((path.to_path_buf(), max_depth));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn unregister_scan_path(&mut self, path: &Path) -> bool {
+        let len = self.scan_paths.len();
+        self.scan_paths.retain(|(other, _)| other != path);
+        self.scan_paths.len() != len
+    }
+
+    /// Inserts a family containing a single virtual face for a named
+    /// instance, named with the full name of the instance and aliased with
+    /// its PostScript name.
+    ///
+    /// Returns the identifier of the existing family if there is one.
+    fn insert_named_instance_family(&mut self, family_name: &str, font: FontInfo) -> FamilyId {
+        let (full_name, postscript_name) = match font.named_instance() {
+            Some(instance) => (
+                format!("{} {}", family_name, instance.name()),
+                instance.postscript_name().map(String::from),
+            ),
+            None => (String::from(family_name), None),
+        };
+        if let Some(existing) = self.family_names.get(&full_name) {
+            return existing.id();
+        }
+        let name = self.family_names.get_or_insert(&full_name);
+        if let Some(postscript_name) = postscript_name {
+            self.family_names.add_alias(name.id(), &postscript_name);
+        }
+        let id = name.id();
+        self.families
+            .insert(id, Some(FamilyInfo::new(name, [font])));
+        id
+    }
+
     fn register_fonts(
         &mut self,
         data: Blob<u8>,
         info_override: Option<FontInfoOverride<'_>>,
+    ) -> Vec<(FamilyId, Vec<FontInfo>)> {
+        let source = SourceInfo::new(SourceId::new(), SourceKind::Memory(data.clone()));
+        self.register_source(data.as_ref(), &source, info_override)
+    }
+
+    fn register_source(
+        &mut self,
+        data: &[u8],
+        source: &SourceInfo,
+        info_override: Option<FontInfoOverride<'_>>,
     ) -> Vec<(FamilyId, Vec<FontInfo>)> {
         let mut families: HashMap<FamilyId, (FamilyName, Vec<FontInfo>)> = HashMap::default();
         let mut family_name = String::default();
-        let data_id = SourceId::new();
-        super::scan::scan_memory(data.as_ref(), |scanned_font| {
+        super::scan::scan_memory(data, |scanned_font| {
             family_name.clear();
 
             let family_name =
@@ -655,12 +937,8 @@ impl CommonData {
             if family_name.is_empty() {
                 return;
             }
-            let data = SourceInfo {
-                id: data_id,
-                kind: SourceKind::Memory(data.clone()),
-            };
             let Some(mut font) =
-                FontInfo::from_font_ref(&scanned_font.font, data, scanned_font.index)
+                FontInfo::from_font_ref(&scanned_font.font, source.clone(), scanned_font.index)
             else {
                 return;
             };
@@ -677,6 +955,13 @@ impl CommonData {
                 .push(font);
         });
         for (id, (name, fonts)) in &families {
+            for instance in fonts.iter().flat_map(|font| font.named_instances()) {
+                self.instance_names
+                    .insert(&format!("{} {}", name.name(), instance.name()), *id);
+                if let Some(postscript_name) = instance.postscript_name() {
+                    self.instance_names.insert(postscript_name, *id);
+                }
+            }
             if let Some(Some(family)) = self.families.get_mut(id) {
                 let new_fonts = family.fonts().iter().chain(fonts).cloned();
                 *family = FamilyInfo::new(name.clone(), new_fonts);
@@ -685,12 +970,64 @@ impl CommonData {
                 self.families.insert(*id, Some(family));
             }
         }
+        self.bump_generation();
         families
             .into_iter()
             .map(|(id, (_, fonts))| (id, fonts))
             .collect()
     }
 
+    #[cfg(feature = "std")]
+    fn update_font_paths(&mut self, removed: &[PathBuf], added: &[PathBuf]) {
+        // Strip fonts from removed files first so that a modified file
+        // (which appears in both lists) keeps its family identifiers.
+        if !removed.is_empty() {
+            for (id, family) in self.families.iter_mut() {
+                let Some(family) = family.as_mut() else {
+                    continue;
+                };
+                let is_removed = |font: &FontInfo| match &font.source().kind {
+                    SourceKind::Path(path) => removed.iter().any(|p| **path == **p),
+                    SourceKind::Memory(_) => false,
+                };
+                if !family.fonts().iter().any(is_removed) {
+                    continue;
+                }
+                let Some(name) = self.family_names.get_by_id(*id) else {
+                    continue;
+                };
+                let fonts = family
+                    .fonts()
+                    .iter()
+                    .filter(|font| !is_removed(font))
+                    .cloned();
+                *family = FamilyInfo::new(name.clone(), fonts);
+            }
+        }
+        for path in added {
+            let Some(data) = super::source_cache::load_blob(path) else {
+                continue;
+            };
+            let source = SourceInfo::new(SourceId::new(), SourceKind::Path(path.as_path().into()));
+            self.register_source(data.as_ref(), &source, None);
+        }
+        // Drop families that are now empty.
+        let empty: Vec<FamilyId> = self
+            .families
+            .iter()
+            .filter(|(_, family)| family.as_ref().is_some_and(|f| f.fonts().is_empty()))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in empty {
+            self.families.remove(&id);
+            self.family_names.remove(id);
+            self.instance_names.remove_family(id);
+            self.generic_families.remove_family(id);
+            self.fallbacks.remove_family(id);
+        }
+        self.bump_generation();
+    }
+
     fn unregister_font(
         &mut self,
         family: FamilyId,
@@ -707,12 +1044,16 @@ impl CommonData {
             .filter(|f| f.width() != width || f.style() != style || f.weight() != weight)
             .cloned();
         *family = FamilyInfo::new(family_name.clone(), new_fonts);
+        self.bump_generation();
 
         Some(())
     }
 
     fn clear(&mut self) {
+        let generation = self.generation;
         *self = Self::default();
+        self.generation = generation;
+        self.bump_generation();
     }
 }
 
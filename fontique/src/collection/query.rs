@@ -10,7 +10,10 @@ use super::super::{Collection, SourceCache};
 use alloc::vec::Vec;
 
 use super::{
-    super::{Attributes, Blob, FallbackKey, FamilyId, FamilyInfo, GenericFamily, Synthesis},
+    super::{
+        Attributes, Blob, FallbackKey, FamilyId, FamilyInfo, GenericFamily, NamedInstance,
+        Synthesis,
+    },
     Inner,
 };
 
@@ -128,7 +131,7 @@ impl<'a> Query<'a> {
             let Entry::Ok(family_info) = &family.family else {
                 continue;
             };
-            let mut best_index = None;
+            let mut best_face = None;
             if let Some(font) = load_font(
                 family_info,
                 self.attributes,
@@ -136,14 +139,20 @@ impl<'a> Query<'a> {
                 false,
                 self.source_cache,
             ) {
-                best_index = Some(font.family.1);
+                best_face = Some((font.family.1, font.named_instance.is_some()));
                 if f(font) == QueryStatus::Stop {
                     return;
                 }
             }
             // Don't invoke for the default font if it's the same as the
             // best match.
-            if best_index == Some(family_info.default_font_index()) {
+            let default_face = (
+                family_info.default_font_index(),
+                family_info
+                    .default_font()
+                    .is_some_and(|font| font.named_instance().is_some()),
+            );
+            if best_face == Some(default_face) {
                 continue;
             }
             if let Some(font) = load_font(
@@ -223,6 +232,15 @@ pub struct QueryFont {
     pub synthesis: Synthesis,
     /// Data used for constructing a character map for this font.
     pub charmap_index: CharmapIndex,
+    /// Named instance of a variable font that was selected, if any.
+    ///
+    /// The coordinates of the instance should be applied before the
+    /// variation settings suggested by [`synthesis`](Self::synthesis).
+    pub named_instance: Option<NamedInstance>,
+    /// True if the font has an `opsz` variation axis.
+    ///
+    /// See [`FontInfo::has_optical_size_axis`](crate::FontInfo::has_optical_size_axis).
+    pub has_optical_size_axis: bool,
 }
 
 impl QueryFont {
@@ -246,22 +264,43 @@ fn load_font<'a>(
             // Set to error in case we fail. This simplifies
             // the following code.
             *status = Entry::Error;
-            let family_index = if is_default {
-                family.default_font_index()
+            let (family_index, instance) = if is_default {
+                (family.default_font_index(), None)
             } else {
-                family.match_index(attributes.width, attributes.style, attributes.weight, true)?
+                family.match_face_index(
+                    attributes.width,
+                    attributes.style,
+                    attributes.weight,
+                    true,
+                )?
             };
             let font_info = family.fonts().get(family_index)?;
             let blob = font_info.load(Some(source_cache))?;
             let blob_index = font_info.index();
-            let synthesis =
-                font_info.synthesis(attributes.width, attributes.style, attributes.weight);
+            let (synthesis, named_instance) = if let Some(instance) = instance {
+                (
+                    font_info.named_instance_synthesis(
+                        instance,
+                        attributes.width,
+                        attributes.style,
+                        attributes.weight,
+                    )?,
+                    font_info.named_instances().get(instance).cloned(),
+                )
+            } else {
+                (
+                    font_info.synthesis(attributes.width, attributes.style, attributes.weight),
+                    font_info.named_instance().cloned(),
+                )
+            };
             *status = Entry::Ok(QueryFont {
                 family: (family.id(), family_index),
                 blob: blob.clone(),
                 index: blob_index,
                 synthesis,
                 charmap_index: font_info.charmap_index(),
+                named_instance,
+                has_optical_size_axis: font_info.has_optical_size_axis(),
             });
             if let Entry::Ok(font) = status {
                 Some(font)
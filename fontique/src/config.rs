@@ -0,0 +1,344 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Declarative configuration of generic families and fallbacks.
+//!
+//! The configuration format is a small XML dialect loosely modelled on
+//! `fonts.conf`:
+//!
+//! ```xml
+//! <fontique>
+//!   <generic name="sans-serif">
+//!     <family>Inter</family>
+//!     <family>Noto Sans</family>
+//!   </generic>
+//!   <generic name="emoji" mode="append">
+//!     <family>Twemoji</family>
+//!   </generic>
+//!   <fallback script="Hani" lang="ja">
+//!     <family>Noto Sans CJK JP</family>
+//!   </fallback>
+//!   <fallback script="Arab">
+//!     <family>Noto Naskh Arabic</family>
+//!   </fallback>
+//! </fontique>
+//! ```
+
+use super::{Collection, FallbackKey, GenericFamily, Language, Script};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use roxmltree::{Document, Node};
+
+/// Determines how the families of a configuration entry are combined with
+/// those already present in a collection.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub enum ConfigMode {
+    /// Replace the existing families.
+    #[default]
+    Replace,
+    /// Append to the existing families.
+    Append,
+}
+
+/// Declarative mapping of [generic families] and [fallback keys] to ordered
+/// lists of family names.
+///
+/// This can be parsed from an XML document with [`CollectionConfig::parse`]
+/// and applied to a collection with [`Collection::apply_config`].
+///
+/// [generic families]: GenericFamily
+/// [fallback keys]: FallbackKey
+#[derive(Clone, Default, Debug)]
+pub struct CollectionConfig {
+    generic_families: Vec<(GenericFamily, ConfigMode, Vec<String>)>,
+    fallbacks: Vec<(FallbackKey, ConfigMode, Vec<String>)>,
+}
+
+impl CollectionConfig {
+    /// Creates an empty configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a configuration from an XML document.
+    pub fn parse(xml: &str) -> Result<Self, ConfigError> {
+        let doc = Document::parse(xml).map_err(|e| ConfigError::Xml(e.to_string()))?;
+        let root = doc.root_element();
+        if root.tag_name().name() != "fontique" {
+            return Err(ConfigError::InvalidRoot(root.tag_name().name().to_string()));
+        }
+        let mut config = Self::default();
+        for child in root.children().filter(Node::is_element) {
+            match child.tag_name().name() {
+                "generic" => {
+                    let name = required_attribute(child, "name")?;
+                    let generic = GenericFamily::parse(name)
+                        .ok_or_else(|| ConfigError::invalid_attribute("name", name))?;
+                    config
+                        .generic_families
+                        .push((generic, mode(child)?, families(child)?));
+                }
+                "fallback" => {
+                    let script = required_attribute(child, "script")?;
+                    let script = Script::parse(script)
+                        .map_err(|_| ConfigError::invalid_attribute("script", script))?;
+                    let locale = child
+                        .attribute("lang")
+                        .map(|lang| {
+                            Language::parse(lang)
+                                .map_err(|_| ConfigError::invalid_attribute("lang", lang))
+                        })
+                        .transpose()?;
+                    let key = FallbackKey::new(script, locale.as_ref());
+                    config.fallbacks.push((key, mode(child)?, families(child)?));
+                }
+                other => return Err(ConfigError::UnknownElement(other.to_string())),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Adds an entry for the given generic family.
+    pub fn add_generic_families<'a>(
+        &mut self,
+        generic: GenericFamily,
+        mode: ConfigMode,
+        families: impl IntoIterator<Item = &'a str>,
+    ) {
+        let families = families.into_iter().map(ToString::to_string).collect();
+        self.generic_families.push((generic, mode, families));
+    }
+
+    /// Adds an entry for the given fallback key.
+    pub fn add_fallbacks<'a>(
+        &mut self,
+        key: impl Into<FallbackKey>,
+        mode: ConfigMode,
+        families: impl IntoIterator<Item = &'a str>,
+    ) {
+        let families = families.into_iter().map(ToString::to_string).collect();
+        self.fallbacks.push((key.into(), mode, families));
+    }
+
+    /// Returns `true` if the configuration contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.generic_families.is_empty() && self.fallbacks.is_empty()
+    }
+
+    /// Applies the configuration to the given collection.
+    ///
+    /// Entries are applied in the order in which they were added. Family
+    /// names that are not present in the collection are skipped, as are
+    /// fallback keys that the collection does not [track].
+    ///
+    /// [track]: FallbackKey::is_tracked
+    pub(crate) fn apply(&self, collection: &mut Collection) {
+        let mut ids = Vec::new();
+        for (generic, mode, names) in &self.generic_families {
+            resolve_names(collection, names, &mut ids);
+            match mode {
+                ConfigMode::Replace => collection.set_generic_families(*generic, ids.drain(..)),
+                ConfigMode::Append => collection.append_generic_families(*generic, ids.drain(..)),
+            }
+        }
+        for (key, mode, names) in &self.fallbacks {
+            resolve_names(collection, names, &mut ids);
+            match mode {
+                ConfigMode::Replace => collection.set_fallbacks(*key, ids.drain(..)),
+                ConfigMode::Append => collection.append_fallbacks(*key, ids.drain(..)),
+            };
+        }
+    }
+}
+
+/// Error produced when parsing a [`CollectionConfig`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ConfigError {
+    /// The document is not well-formed XML.
+    Xml(String),
+    /// The root element is not `<fontique>`.
+    InvalidRoot(String),
+    /// An element was not recognized.
+    UnknownElement(String),
+    /// A required attribute was not present.
+    MissingAttribute {
+        /// Name of the element.
+        element: String,
+        /// Name of the missing attribute.
+        attribute: &'static str,
+    },
+    /// An attribute had an invalid value.
+    InvalidAttribute {
+        /// Name of the attribute.
+        attribute: &'static str,
+        /// The invalid value.
+        value: String,
+    },
+}
+
+impl ConfigError {
+    fn invalid_attribute(attribute: &'static str, value: &str) -> Self {
+        Self::InvalidAttribute {
+            attribute,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Xml(message) => write!(f, "invalid XML: {message}"),
+            Self::InvalidRoot(name) => {
+                write!(f, "expected root element `fontique`, found `{name}`")
+            }
+            Self::UnknownElement(name) => write!(f, "unknown element `{name}`"),
+            Self::MissingAttribute { element, attribute } => {
+                write!(f, "element `{element}` is missing attribute `{attribute}`")
+            }
+            Self::InvalidAttribute { attribute, value } => {
+                write!(f, "invalid value `{value}` for attribute `{attribute}`")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {}
+
+impl Collection {
+    /// Applies the generic family and fallback mappings from the given
+    /// configuration to this collection.
+    ///
+    /// See [`CollectionConfig`] for details.
+    pub fn apply_config(&mut self, config: &CollectionConfig) {
+        config.apply(self);
+    }
+}
+
+fn required_attribute<'a>(node: Node<'a, '_>, name: &'static str) -> Result<&'a str, ConfigError> {
+    node.attribute(name)
+        .ok_or_else(|| ConfigError::MissingAttribute {
+            element: node.tag_name().name().to_string(),
+            attribute: name,
+        })
+}
+
+fn mode(node: Node<'_, '_>) -> Result<ConfigMode, ConfigError> {
+    match node.attribute("mode") {
+        None | Some("replace") => Ok(ConfigMode::Replace),
+        Some("append") => Ok(ConfigMode::Append),
+        Some(other) => Err(ConfigError::invalid_attribute("mode", other)),
+    }
+}
+
+fn families(node: Node<'_, '_>) -> Result<Vec<String>, ConfigError> {
+    let mut families = Vec::new();
+    for child in node.children().filter(Node::is_element) {
+        if child.tag_name().name() != "family" {
+            return Err(ConfigError::UnknownElement(
+                child.tag_name().name().to_string(),
+            ));
+        }
+        if let Some(name) = child.text().map(str::trim).filter(|name| !name.is_empty()) {
+            families.push(name.to_string());
+        }
+    }
+    Ok(families)
+}
+
+fn resolve_names(collection: &mut Collection, names: &[String], ids: &mut Vec<super::FamilyId>) {
+    ids.clear();
+    ids.extend(names.iter().filter_map(|name| collection.family_id(name)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CollectionConfig, ConfigError};
+    use crate::{Blob, Collection, CollectionOptions, FontInfoOverride, GenericFamily, Script};
+    use alloc::{sync::Arc, vec::Vec};
+
+    const ROBOTO: &[u8] =
+        include_bytes!("../../parley_dev/assets/fonts/roboto_fonts/Roboto-Regular.ttf");
+
+    fn collection_with(names: &[&str]) -> Collection {
+        let mut collection = Collection::new(CollectionOptions {
+            shared: false,
+            system_fonts: false,
+        });
+        let blob = Blob::new(Arc::new(ROBOTO));
+        for name in names {
+            collection.register_fonts(
+                blob.clone(),
+                Some(FontInfoOverride {
+                    family_name: Some(*name),
+                    ..Default::default()
+                }),
+            );
+        }
+        collection
+    }
+
+    #[test]
+    fn apply_generic_and_fallbacks() {
+        let mut collection = collection_with(&["A", "B", "JP"]);
+        let config = CollectionConfig::parse(
+            r#"
+            <fontique>
+              <generic name="serif">
+                <family>B</family>
+                <family>Missing</family>
+                <family>A</family>
+              </generic>
+              <generic name="serif" mode="append">
+                <family>JP</family>
+              </generic>
+              <fallback script="Hani" lang="ja">
+                <family>JP</family>
+              </fallback>
+            </fontique>
+            "#,
+        )
+        .unwrap();
+        collection.apply_config(&config);
+
+        let ids = |collection: &mut Collection, names: &[&str]| -> Vec<_> {
+            names
+                .iter()
+                .map(|name| collection.family_id(name).unwrap())
+                .collect()
+        };
+        let serif: Vec<_> = collection.generic_families(GenericFamily::Serif).collect();
+        assert_eq!(serif, ids(&mut collection, &["B", "A", "JP"]));
+        let ja: Vec<_> = collection
+            .fallback_families((Script::from_bytes(*b"Hani"), "ja"))
+            .collect();
+        assert_eq!(ja, ids(&mut collection, &["JP"]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            CollectionConfig::parse("<fontconfig/>"),
+            Err(ConfigError::InvalidRoot(_))
+        ));
+        assert!(matches!(
+            CollectionConfig::parse("<fontique><generic/></fontique>"),
+            Err(ConfigError::MissingAttribute { .. })
+        ));
+        assert!(matches!(
+            CollectionConfig::parse(r#"<fontique><generic name="sans"/></fontique>"#),
+            Err(ConfigError::InvalidAttribute { .. })
+        ));
+        assert!(matches!(
+            CollectionConfig::parse("<fontique><alias/></fontique>"),
+            Err(ConfigError::UnknownElement(_))
+        ));
+        assert!(matches!(
+            CollectionConfig::parse("<fontique>"),
+            Err(ConfigError::Xml(_))
+        ));
+    }
+}
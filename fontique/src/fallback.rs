@@ -59,6 +59,19 @@ impl FallbackMap {
         self.set_or_append(key, families, false)
     }
 
+    /// Removes the given family identifier from all fallback lists.
+    #[cfg(feature = "std")]
+    pub fn remove_family(&mut self, id: FamilyId) {
+        for per_script in self.fallbacks.values_mut() {
+            if let Some(default) = &mut per_script.default {
+                default.retain(|family| *family != id);
+            }
+            for (_, families) in &mut per_script.others {
+                families.retain(|family| *family != id);
+            }
+        }
+    }
+
     fn set_or_append(
         &mut self,
         key: impl Into<FallbackKey>,
@@ -247,27 +260,7 @@ fn canonical_locale(script: Script, locale: Option<&Language>) -> Option<(bool,
             ("wal", "") => (false, "wal"),
             _ => return None,
         },
-        b"Hani" => match lang {
-            "ja" => (false, "ja"),
-            "ko" => (false, "ko"),
-            "zh" => {
-                match region {
-                    "HK" => (false, "zh-HK"),
-                    "TW" => (false, "zh-TW"),
-                    "MO" => (false, "zh-MO"),
-                    "SG" => (false, "zh-SG"),
-                    _ => {
-                        if locale.script() == Some("Hant") {
-                            (false, "zh-TW")
-                        } else {
-                            // Default to simplified Chinese
-                            (true, "zh-CN")
-                        }
-                    }
-                }
-            }
-            _ => return None,
-        },
+        b"Hani" => han_locale(*locale)?,
         b"Hebr" => match (lang, region) {
             ("he", "") => (true, "he"),
             ("yi", "") => (false, "yi"),
@@ -285,3 +278,80 @@ fn canonical_locale(script: Script, locale: Option<&Language>) -> Option<(bool,
         Some(Language::parse(token).expect("valid canonical locale")),
     ))
 }
+
+/// Selects the regional variant of Han characters that should be used for
+/// the given locale.
+///
+/// Han characters are shaped differently in Japanese, Korean and the various
+/// forms of Chinese, so the locale is reduced to one of `ja`, `ko`, `zh-CN`
+/// (the default), `zh-TW` or `zh-HK`. Script subtags take precedence over
+/// regions, so `zh-Hant` selects traditional forms and `zh-Hans-HK` selects
+/// simplified forms. Chinese macrolanguage members such as `yue` and `cmn`
+/// are treated as Chinese.
+fn han_locale(locale: Language) -> Option<(bool, &'static str)> {
+    let region = locale.region().unwrap_or_default();
+    let chinese = |script: Option<&str>, default_region: &str| {
+        let region = if region.is_empty() {
+            default_region
+        } else {
+            region
+        };
+        match (script, region) {
+            (Some("Hans"), _) => (true, "zh-CN"),
+            (Some("Hant"), "HK" | "MO") | (None, "HK" | "MO") => (false, "zh-HK"),
+            (Some("Hant"), _) | (None, "TW") => (false, "zh-TW"),
+            _ => (true, "zh-CN"),
+        }
+    };
+    Some(match (locale.language(), locale.script()) {
+        ("ja", _) | (_, Some("Jpan" | "Hira" | "Kana" | "Hrkt")) => (false, "ja"),
+        ("ko", _) | (_, Some("Kore" | "Hang")) => (false, "ko"),
+        ("yue", script) => chinese(script, "HK"),
+        (
+            "zh" | "cmn" | "cdo" | "cjy" | "cpx" | "czh" | "czo" | "gan" | "hak" | "hsn" | "lzh"
+            | "mnp" | "nan" | "wuu",
+            script,
+        ) => chinese(script, ""),
+        (_, script @ Some("Hans" | "Hant")) => chinese(script, ""),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FallbackKey;
+    use crate::Script;
+
+    #[test]
+    fn han_locales() {
+        for (locale, expected) in [
+            ("ja", Some("ja")),
+            ("ja-JP", Some("ja")),
+            ("ko-KR", Some("ko")),
+            ("und-Kore", Some("ko")),
+            ("zh", Some("zh-CN")),
+            ("zh-CN", Some("zh-CN")),
+            ("zh-SG", Some("zh-CN")),
+            ("zh-Hans", Some("zh-CN")),
+            ("zh-Hans-HK", Some("zh-CN")),
+            ("zh-Hant", Some("zh-TW")),
+            ("zh-TW", Some("zh-TW")),
+            ("zh-Hant-HK", Some("zh-HK")),
+            ("zh-HK", Some("zh-HK")),
+            ("zh-MO", Some("zh-HK")),
+            ("yue", Some("zh-HK")),
+            ("yue-Hans", Some("zh-CN")),
+            ("cmn-Hant", Some("zh-TW")),
+            ("und-Hant", Some("zh-TW")),
+            ("en", None),
+        ] {
+            let key = FallbackKey::from((Script::from_bytes(*b"Hani"), locale));
+            let actual = key.is_tracked().then(|| key.locale_str()).flatten();
+            assert_eq!(actual, expected, "{locale}");
+        }
+        let default = FallbackKey::from((Script::from_bytes(*b"Hani"), "zh-Hans"));
+        assert!(default.is_default());
+        let tc = FallbackKey::from((Script::from_bytes(*b"Hani"), "zh-Hant"));
+        assert!(!tc.is_default());
+    }
+}
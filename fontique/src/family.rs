@@ -90,6 +90,22 @@ impl FamilyInfo {
         super::matching::match_font(self.fonts(), width, style, weight, synthesize_style)
     }
 
+    /// Returns the index of the best font from the family for the given
+    /// attributes, treating the named instances of variable fonts as
+    /// additional faces.
+    ///
+    /// If a named instance was selected, its index in
+    /// [`FontInfo::named_instances`] is returned as well.
+    pub fn match_face_index(
+        &self,
+        width: FontWidth,
+        style: FontStyle,
+        weight: FontWeight,
+        synthesize_style: bool,
+    ) -> Option<(usize, Option<usize>)> {
+        super::matching::match_face(self.fonts(), width, style, weight, synthesize_style)
+    }
+
     /// Selects the best font from the family for the given attributes.
     pub fn match_font(
         &self,
@@ -88,12 +88,52 @@ impl FamilyNameMap {
         }
     }
 
+    /// Removes the family with the given identifier along with all of its
+    /// aliases.
+    #[cfg(feature = "std")]
+    pub fn remove(&mut self, id: FamilyId) {
+        if self.id_map.remove(&id).is_some() {
+            self.name_map.retain(|_, name| name.id != id);
+        }
+    }
+
     /// Returns an iterator over all of the font family names.
     pub fn iter(&self) -> impl Iterator<Item = &FamilyName> + Clone {
         self.name_map.values()
     }
 }
 
+/// Map that associates the full names and PostScript names of the named
+/// instances of variable fonts with the families that contain them.
+#[derive(Clone, Default)]
+pub struct InstanceNameMap {
+    map: HashMap<Arc<[u8]>, FamilyId>,
+}
+
+impl InstanceNameMap {
+    /// Returns the identifier of the family containing the instance with the
+    /// given name.
+    pub fn get(&self, name: &str) -> Option<FamilyId> {
+        let key = NameKey::from_str(name);
+        self.map.get(key.as_bytes()).copied()
+    }
+
+    /// Associates `name` with the given family identifier, unless it is
+    /// already associated with a family.
+    pub fn insert(&mut self, name: &str, id: FamilyId) {
+        let key = NameKey::from_str(name);
+        if !self.map.contains_key(key.as_bytes()) {
+            self.map.insert(key.as_bytes().into(), id);
+        }
+    }
+
+    /// Removes all names associated with the given family identifier.
+    #[cfg(feature = "std")]
+    pub fn remove_family(&mut self, id: FamilyId) {
+        self.map.retain(|_, family| *family != id);
+    }
+}
+
 /// Key for case-insensitive lookup of family names.
 #[derive(Default)]
 struct NameKey {
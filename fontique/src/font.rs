@@ -8,6 +8,7 @@ use crate::CharmapIndex;
 use super::source::{SourceInfo, SourceKind};
 use super::{Blob, source_cache::SourceCache};
 use crate::{FontStyle, FontWeight, FontWidth};
+use alloc::{string::String, sync::Arc};
 use core::fmt;
 use read_fonts::{FontRef, TableProvider as _, types::Tag};
 use smallvec::SmallVec;
@@ -24,6 +25,8 @@ pub struct FontInfo {
     weight: FontWeight,
     axes: AxisVec,
     attr_axes: u8,
+    instances: Arc<[NamedInstance]>,
+    instance: Option<u16>,
     charmap_index: CharmapIndex,
 }
 
@@ -87,55 +90,52 @@ impl FontInfo {
     }
 
     /// Returns synthesis suggestions for this font with the given attributes.
+    ///
+    /// Fonts that represent a [named instance] are treated as static faces,
+    /// so the only variation settings suggested for them are the coordinates
+    /// of the instance.
+    ///
+    /// [named instance]: Self::named_instance
     pub fn synthesis(&self, width: FontWidth, style: FontStyle, weight: FontWeight) -> Synthesis {
-        let mut synth = Synthesis::default();
-        let mut len = 0_usize;
-        if self.has_width_axis() && self.width != width {
-            synth.vars[len] = (Tag::new(b"wdth"), width.percentage());
-            len += 1;
+        let attrs = (self.width, self.style, self.weight);
+        match self.named_instance() {
+            Some(instance) => synthesize(
+                attrs,
+                0,
+                Some((self.instance.unwrap_or_default(), instance.coords())),
+                width,
+                style,
+                weight,
+            ),
+            None => synthesize(attrs, self.attr_axes, None, width, style, weight),
         }
-        if self.weight != weight {
-            if self.has_weight_axis() {
-                synth.vars[len] = (Tag::new(b"wght"), weight.value());
-                len += 1;
-            } else if weight.value() > self.weight.value() {
-                synth.embolden = true;
-            }
-        }
-        if self.style != style {
-            match style {
-                FontStyle::Normal => {}
-                FontStyle::Italic => {
-                    if self.style == FontStyle::Normal {
-                        if self.has_italic_axis() {
-                            synth.vars[len] = (Tag::new(b"ital"), 1.0);
-                            len += 1;
-                        } else if self.has_slant_axis() {
-                            synth.vars[len] = (Tag::new(b"slnt"), 14.0);
-                            len += 1;
-                        } else {
-                            synth.skew = 14;
-                        }
-                    }
-                }
-                FontStyle::Oblique(angle) => {
-                    if self.style == FontStyle::Normal {
-                        let degrees = angle.unwrap_or(14.0);
-                        if self.has_slant_axis() {
-                            synth.vars[len] = (Tag::new(b"slnt"), degrees);
-                            len += 1;
-                        } else if self.has_italic_axis() && degrees > 0. {
-                            synth.vars[len] = (Tag::new(b"ital"), 1.0);
-                            len += 1;
-                        } else {
-                            synth.skew = degrees as i8;
-                        }
-                    }
-                }
-            }
-        }
-        synth.len = len as u8;
-        synth
+    }
+
+    /// Returns synthesis suggestions for the [named instance] at the given
+    /// index with the given attributes.
+    ///
+    /// The suggested [variation settings](Synthesis::variation_settings)
+    /// include the coordinates of the instance for the width, weight and
+    /// slope axes, adjusted to match the requested attributes.
+    ///
+    /// [named instance]: Self::named_instances
+    pub fn named_instance_synthesis(
+        &self,
+        index: usize,
+        width: FontWidth,
+        style: FontStyle,
+        weight: FontWeight,
+    ) -> Option<Synthesis> {
+        let instance = self.instances.get(index)?;
+        let attrs = (instance.width, instance.style, instance.weight);
+        Some(synthesize(
+            attrs,
+            self.attr_axes,
+            Some((u16::try_from(index).ok()?, instance.coords())),
+            width,
+            style,
+            weight,
+        ))
     }
 
     /// Returns the variation [axes] for the font.
@@ -195,6 +195,33 @@ impl FontInfo {
         self.attr_axes & OPTICAL_SIZE_AXIS != 0
     }
 
+    /// Returns the named instances defined by the `fvar` table of the font.
+    pub fn named_instances(&self) -> &[NamedInstance] {
+        &self.instances
+    }
+
+    /// Returns the named instance that this font represents, if any.
+    ///
+    /// Such fonts are virtual faces of a variable font that are created by
+    /// [`with_named_instance`](Self::with_named_instance).
+    pub fn named_instance(&self) -> Option<&NamedInstance> {
+        self.instances.get(usize::from(self.instance?))
+    }
+
+    /// Returns a virtual face of this font for the named instance at the
+    /// given index.
+    ///
+    /// The attributes of the returned font are those of the instance.
+    pub fn with_named_instance(&self, index: usize) -> Option<Self> {
+        let instance = self.instances.get(index)?;
+        let mut font = self.clone();
+        font.width = instance.width;
+        font.style = instance.style;
+        font.weight = instance.weight;
+        font.instance = Some(u16::try_from(index).ok()?);
+        Some(font)
+    }
+
     /// Returns the index used for constructing a [Charmap] for this font.
     ///
     /// [Charmap]: crate::Charmap
@@ -237,6 +264,7 @@ impl FontInfo {
         } else {
             (SmallVec::default(), 0)
         };
+        let instances = read_named_instances(font, (width, style, weight));
         Some(Self {
             source,
             index,
@@ -245,6 +273,8 @@ impl FontInfo {
             weight,
             axes,
             attr_axes,
+            instances,
+            instance: None,
             charmap_index,
         })
     }
@@ -326,6 +356,74 @@ pub struct AxisInfo {
     pub default: f32,
 }
 
+/// A named instance of a variable font.
+///
+/// Instances of this can be obtained from [`FontInfo::named_instances`].
+///
+/// Named instances are predefined positions in the design space of a
+/// variable font, such as `Condensed SemiBold`. The attributes of an instance
+/// are derived from its `wdth`, `wght`, `ital` and `slnt` coordinates,
+/// falling back to those of the font for axes that are not present.
+#[derive(Clone, Debug)]
+pub struct NamedInstance {
+    name: Arc<str>,
+    postscript_name: Option<Arc<str>>,
+    coords: Arc<[(Tag, f32)]>,
+    width: FontWidth,
+    style: FontStyle,
+    weight: FontWeight,
+}
+
+impl NamedInstance {
+    /// Returns the subfamily name of the instance, for example
+    /// `Condensed SemiBold`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the PostScript name of the instance, if present.
+    pub fn postscript_name(&self) -> Option<&str> {
+        self.postscript_name.as_deref()
+    }
+
+    /// Returns the user space coordinates of the instance for each axis.
+    pub fn coords(&self) -> &[(Tag, f32)] {
+        &self.coords
+    }
+
+    /// Returns the visual width of the instance.
+    pub fn width(&self) -> FontWidth {
+        self.width
+    }
+
+    /// Returns the visual style of the instance.
+    pub fn style(&self) -> FontStyle {
+        self.style
+    }
+
+    /// Returns the visual weight of the instance.
+    pub fn weight(&self) -> FontWeight {
+        self.weight
+    }
+
+    /// Returns `true` if the given name matches the full name (the family
+    /// name followed by the subfamily name) or the PostScript name of the
+    /// instance.
+    pub(crate) fn matches_name(&self, family_name: &str, name: &str) -> bool {
+        if self
+            .postscript_name()
+            .is_some_and(|ps_name| ps_name.eq_ignore_ascii_case(name))
+        {
+            return true;
+        }
+        name.len() == family_name.len() + 1 + self.name.len()
+            && name.is_char_boundary(family_name.len())
+            && name[..family_name.len()].eq_ignore_ascii_case(family_name)
+            && name[family_name.len()..].starts_with(' ')
+            && name[family_name.len() + 1..].eq_ignore_ascii_case(&self.name)
+    }
+}
+
 /// Suggestions for synthesizing a set of font attributes for a given
 /// font.
 ///
@@ -335,10 +433,11 @@ pub struct AxisInfo {
 /// [`QueryFont::synthesis`]: crate::QueryFont::synthesis
 #[derive(Copy, Clone, Default, PartialEq)]
 pub struct Synthesis {
-    vars: [(Tag, f32); 3],
+    vars: [(Tag, f32); 4],
     len: u8,
     embolden: bool,
     skew: i8,
+    instance: Option<u16>,
 }
 
 impl Synthesis {
@@ -350,6 +449,11 @@ impl Synthesis {
     /// Returns the variation settings that should be applied to match the
     /// requested attributes.
     ///
+    /// For a [named instance](Self::named_instance), these include the
+    /// coordinates of the instance for the `wdth`, `wght`, `ital` and `slnt`
+    /// axes, so applying them selects the instance even when its other
+    /// coordinates are not applied.
+    ///
     /// When using `parley`, these can be used to create `FontVariation`
     /// settings.
     pub fn variation_settings(&self) -> &[(Tag, f32)] {
@@ -369,6 +473,15 @@ impl Synthesis {
             None
         }
     }
+
+    /// Returns the index of the [named instance] whose coordinates should be
+    /// applied before the [variation settings](Self::variation_settings), if
+    /// any.
+    ///
+    /// [named instance]: FontInfo::named_instances
+    pub fn named_instance(&self) -> Option<usize> {
+        self.instance.map(usize::from)
+    }
 }
 
 #[allow(clippy::missing_fields_in_debug)]
@@ -378,10 +491,136 @@ impl fmt::Debug for Synthesis {
             .field("vars", &self.variation_settings())
             .field("embolden", &self.embolden)
             .field("skew", &self.skew)
+            .field("instance", &self.instance)
             .finish()
     }
 }
 
+fn synthesize(
+    (font_width, font_style, font_weight): (FontWidth, FontStyle, FontWeight),
+    axes: u8,
+    instance: Option<(u16, &[(Tag, f32)])>,
+    width: FontWidth,
+    style: FontStyle,
+    weight: FontWeight,
+) -> Synthesis {
+    let mut synth = Synthesis {
+        instance: instance.map(|(index, _)| index),
+        ..Default::default()
+    };
+    let mut len = 0_usize;
+    if axes & WIDTH_AXIS != 0 && font_width != width {
+        synth.vars[len] = (Tag::new(b"wdth"), width.percentage());
+        len += 1;
+    }
+    if font_weight != weight {
+        if axes & WEIGHT_AXIS != 0 {
+            synth.vars[len] = (Tag::new(b"wght"), weight.value());
+            len += 1;
+        } else if weight.value() > font_weight.value() {
+            synth.embolden = true;
+        }
+    }
+    if font_style != style {
+        match style {
+            FontStyle::Normal => {}
+            FontStyle::Italic => {
+                if font_style == FontStyle::Normal {
+                    if axes & ITALIC_AXIS != 0 {
+                        synth.vars[len] = (Tag::new(b"ital"), 1.0);
+                        len += 1;
+                    } else if axes & SLANT_AXIS != 0 {
+                        synth.vars[len] = (Tag::new(b"slnt"), 14.0);
+                        len += 1;
+                    } else {
+                        synth.skew = 14;
+                    }
+                }
+            }
+            FontStyle::Oblique(angle) => {
+                if font_style == FontStyle::Normal {
+                    let degrees = angle.unwrap_or(14.0);
+                    if axes & SLANT_AXIS != 0 {
+                        synth.vars[len] = (Tag::new(b"slnt"), degrees);
+                        len += 1;
+                    } else if axes & ITALIC_AXIS != 0 && degrees > 0. {
+                        synth.vars[len] = (Tag::new(b"ital"), 1.0);
+                        len += 1;
+                    } else {
+                        synth.skew = degrees as i8;
+                    }
+                }
+            }
+        }
+    }
+    // The coordinates of a named instance are kept for the axes that weren't
+    // adjusted above.
+    let coords = instance.map_or(&[][..], |(_, coords)| coords);
+    for &(tag, value) in coords {
+        if matches!(&tag.to_be_bytes(), b"wdth" | b"wght" | b"ital" | b"slnt")
+            && !synth.vars[..len].iter().any(|(set, _)| *set == tag)
+        {
+            synth.vars[len] = (tag, value);
+            len += 1;
+        }
+    }
+    synth.len = len as u8;
+    synth
+}
+
+fn read_named_instances(
+    font: &FontRef<'_>,
+    (width, style, weight): (FontWidth, FontStyle, FontWeight),
+) -> Arc<[NamedInstance]> {
+    use read_fonts::types::NameId;
+
+    let (Ok(fvar), Ok(name_table)) = (font.fvar(), font.name()) else {
+        return Arc::default();
+    };
+    let (Ok(axes), Ok(instances)) = (fvar.axes(), fvar.instances()) else {
+        return Arc::default();
+    };
+    let name_string = |id: NameId| -> Option<Arc<str>> {
+        let name = super::scan::english_or_first(&name_table, id)?;
+        Some(name.chars().collect::<String>().into())
+    };
+    instances
+        .iter()
+        .filter_map(|instance| {
+            let instance = instance.ok()?;
+            let name = name_string(instance.subfamily_name_id)?;
+            let postscript_name = instance.post_script_name_id.and_then(&name_string);
+            let coords: Arc<[(Tag, f32)]> = axes
+                .iter()
+                .zip(instance.coordinates)
+                .map(|(axis, coord)| (axis.axis_tag(), coord.get().to_f32()))
+                .collect();
+            let mut instance = NamedInstance {
+                name,
+                postscript_name,
+                coords,
+                width,
+                style,
+                weight,
+            };
+            for (tag, value) in instance.coords.iter() {
+                match &tag.to_be_bytes() {
+                    b"wdth" => instance.width = FontWidth::from_percentage(*value),
+                    b"wght" => instance.weight = FontWeight::new(*value),
+                    b"ital" if *value >= 0.5 => instance.style = FontStyle::Italic,
+                    b"slnt" if *value != 0.0 && instance.style != FontStyle::Italic => {
+                        // Positive slant angles lean to the left, which is the
+                        // opposite of CSS oblique angles.
+                        instance.style = FontStyle::Oblique(Some(-*value));
+                    }
+                    _ => {}
+                }
+            }
+            Some(instance)
+        })
+        .collect()
+}
+
 fn read_attributes(font: &FontRef<'_>) -> (FontWidth, FontStyle, FontWeight) {
     use read_fonts::{
         TableProvider,
@@ -478,3 +717,89 @@ pub struct FontInfoOverride<'a> {
     /// the font will be ignored.
     pub axes: Option<&'a [(Tag, f32)]>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Blob, Collection, CollectionOptions, FontStyle, FontWeight, FontWidth};
+    use alloc::{sync::Arc, vec::Vec};
+    use read_fonts::types::Tag;
+
+    const ARIMO: &[u8] =
+        include_bytes!("../../parley_dev/assets/fonts/arimo_fonts/Arimo-VariableFont_wght.ttf");
+
+    #[test]
+    fn named_instances() {
+        let mut collection = Collection::new(CollectionOptions {
+            shared: false,
+            system_fonts: false,
+        });
+        collection.register_fonts(Blob::new(Arc::new(ARIMO)), None);
+        let family = collection.family_by_name("Arimo").unwrap();
+        let font = &family.fonts()[0];
+
+        let instances: Vec<_> = font
+            .named_instances()
+            .iter()
+            .map(|instance| {
+                (
+                    instance.name(),
+                    instance.postscript_name(),
+                    instance.weight(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            instances,
+            [
+                ("Regular", Some("ArimoRoman-Regular"), FontWeight::NORMAL),
+                ("Bold", Some("ArimoRoman-Bold"), FontWeight::BOLD),
+            ]
+        );
+
+        // Instances are matched as virtual faces.
+        let normal = (FontWidth::NORMAL, FontStyle::Normal);
+        let face = |weight| family.match_face_index(normal.0, normal.1, weight, true);
+        assert_eq!(face(FontWeight::NORMAL), Some((0, None)));
+        assert_eq!(face(FontWeight::BOLD), Some((0, Some(1))));
+        // Ties prefer the real font to the virtual face of the same weight.
+        assert_eq!(face(FontWeight::new(450.)), Some((0, None)));
+        // The variation settings select the instance on their own.
+        let synthesis = font
+            .named_instance_synthesis(1, normal.0, normal.1, FontWeight::BOLD)
+            .unwrap();
+        assert_eq!(synthesis.variation_settings(), [(Tag::new(b"wght"), 700.0)]);
+
+        // Instances can be selected by full name or PostScript name.
+        let id = collection.family_id("Arimo Bold").unwrap();
+        assert_eq!(collection.family_id("arimoroman-bold"), Some(id));
+        let bold = collection.family(id).unwrap();
+        let bold_font = &bold.fonts()[0];
+        assert_eq!(bold_font.named_instance().unwrap().name(), "Bold");
+        assert_eq!(bold_font.weight(), FontWeight::BOLD);
+        let synthesis = bold_font.synthesis(normal.0, normal.1, FontWeight::NORMAL);
+        assert_eq!(synthesis.named_instance(), Some(1));
+        assert_eq!(synthesis.variation_settings(), [(Tag::new(b"wght"), 700.0)]);
+        assert!(collection.family_id("Arimo Black").is_none());
+    }
+
+    #[test]
+    fn named_instances_on_fresh_shared_collection() {
+        let mut collection = Collection::new(CollectionOptions {
+            shared: true,
+            system_fonts: false,
+        });
+        collection.register_fonts(Blob::new(Arc::new(ARIMO)), None);
+        let mut other = collection.clone();
+
+        // Instances resolve without first looking up their family.
+        let id = collection.family_id("ArimoRoman-Bold").unwrap();
+        assert_eq!(collection.family_id("Arimo Bold"), Some(id));
+
+        // The virtual family is shared, and survives later changes.
+        assert_eq!(other.family_id("arimo bold"), Some(id));
+        other.register_fonts(Blob::new(Arc::new(ARIMO)), None);
+        assert_eq!(collection.family_id("Arimo Bold"), Some(id));
+        let bold = collection.family(id).unwrap();
+        assert_eq!(bold.fonts()[0].named_instance().unwrap().name(), "Bold");
+    }
+}
@@ -33,4 +33,12 @@ impl GenericFamilyMap {
     pub fn append(&mut self, generic: GenericFamily, families: impl Iterator<Item = FamilyId>) {
         self.map[generic as usize].extend(families);
     }
+
+    /// Removes the given family identifier from all generic families.
+    #[cfg(feature = "std")]
+    pub fn remove_family(&mut self, id: FamilyId) {
+        for families in &mut self.map {
+            families.retain(|family| *family != id);
+        }
+    }
 }
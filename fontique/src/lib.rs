@@ -41,6 +41,8 @@ mod attributes;
 mod backend;
 mod charmap;
 mod collection;
+#[cfg(feature = "config")]
+mod config;
 mod fallback;
 mod family;
 mod family_name;
@@ -51,6 +53,8 @@ mod matching;
 mod scan;
 mod script;
 mod source;
+#[cfg(feature = "std")]
+mod watch;
 
 mod source_cache;
 
@@ -62,15 +66,25 @@ pub use text_primitives::Script;
 pub use attributes::Attributes;
 pub use charmap::{Charmap, CharmapIndex};
 pub use collection::{Collection, CollectionOptions, Query, QueryFamily, QueryFont, QueryStatus};
+#[cfg(feature = "config")]
+pub use config::{CollectionConfig, ConfigError, ConfigMode};
 pub use fallback::FallbackKey;
 pub use family::{FamilyId, FamilyInfo};
-pub use font::{AxisInfo, FontInfo, FontInfoOverride, Synthesis};
+pub use font::{AxisInfo, FontInfo, FontInfoOverride, NamedInstance, Synthesis};
 pub use impl_fontconfig::FromFontconfig;
 pub use source::{SourceId, SourceInfo, SourceKind};
 pub use text_primitives::{FontStyle, FontWeight, FontWidth, GenericFamily};
+#[cfg(feature = "std")]
+pub use watch::{FontWatcher, WatchChanges};
 
 #[cfg(all(feature = "system", target_vendor = "apple"))]
 use objc2 as _;
+#[cfg(all(
+    feature = "system",
+    not(feature = "config"),
+    not(target_os = "android")
+))]
+use roxmltree as _;
 pub use source_cache::{SourceCache, SourceCacheOptions};
 
 #[cfg(not(target_has_atomic = "64"))]
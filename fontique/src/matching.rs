@@ -16,31 +16,80 @@ pub fn match_font(
     weight: FontWeight,
     synthesize_style: bool,
 ) -> Option<usize> {
-    const OBLIQUE_THRESHOLD: f32 = DEFAULT_OBLIQUE_ANGLE;
     match set.len() {
         0 => return None,
         1 => return Some(0),
         _ => {}
     }
+    match_face(set, width, style, weight, synthesize_style).map(|(index, _)| index)
+}
+
+/// Matches against the fonts in the set along with the named instances of
+/// any variable fonts, which are treated as virtual faces.
+///
+/// Returns the index of the font and, if a virtual face was selected, the
+/// index of the named instance.
+pub fn match_face(
+    fonts: &[FontInfo],
+    width: FontWidth,
+    style: FontStyle,
+    weight: FontWeight,
+    synthesize_style: bool,
+) -> Option<(usize, Option<usize>)> {
+    const OBLIQUE_THRESHOLD: f32 = DEFAULT_OBLIQUE_ANGLE;
     #[derive(Copy, Clone)]
     struct Candidate {
         index: usize,
+        instance: Option<usize>,
         width: i32,
         style: FontStyle,
         weight: f32,
         has_slnt: bool,
     }
-    let mut set: SmallVec<[Candidate; 16]> = set
+    impl Candidate {
+        fn face(&self) -> (usize, Option<usize>) {
+            (self.index, self.instance)
+        }
+
+        fn is_virtual(&self) -> bool {
+            self.instance.is_some()
+        }
+    }
+    let mut set: SmallVec<[Candidate; 16]> = fonts
         .iter()
         .enumerate()
         .map(|(i, font)| Candidate {
             index: i,
+            instance: None,
             width: (font.width().ratio() * 100.0) as i32,
             style: font.style(),
             weight: font.weight().value(),
             has_slnt: font.has_slant_axis(),
         })
         .collect();
+    // Virtual faces follow all of the real fonts. When a real font and a
+    // virtual face match equally well, the real font is preferred.
+    // Fonts that already represent a named instance are not expanded.
+    for (i, font) in fonts.iter().enumerate() {
+        if font.named_instance().is_some() {
+            continue;
+        }
+        for (j, instance) in font.named_instances().iter().enumerate() {
+            set.push(Candidate {
+                index: i,
+                instance: Some(j),
+                width: (instance.width().ratio() * 100.0) as i32,
+                style: instance.style(),
+                weight: instance.weight().value(),
+                has_slnt: font.has_slant_axis(),
+            });
+        }
+    }
+    match set.len() {
+        0 => return None,
+        1 => return Some(set[0].face()),
+        _ => {}
+    }
     let width = (width.ratio() * 100.0) as i32;
     let weight = weight.value();
     // font-width is tried first:
@@ -373,8 +422,12 @@ pub fn match_font(
     }
     set.retain(|f| f.style == use_style);
     // font-weight is matched next:
-    if let Some(f) = set.iter().find(|f| f.weight == weight) {
-        return Some(f.index);
+    if let Some(f) = set
+        .iter()
+        .filter(|f| f.weight == weight)
+        .min_by_key(|f| f.is_virtual())
+    {
+        return Some(f.face());
     } else {
         // If the desired weight is inclusively between 400 and 500...
         if (400.0..=500.0).contains(&weight) {
@@ -384,65 +437,84 @@ pub fn match_font(
                 .iter()
                 .enumerate()
                 .filter(|f| f.1.weight >= weight && f.1.weight <= 500.0)
-                .min_by(|x, y| x.1.weight.partial_cmp(&y.1.weight).unwrap_or(Less))
+                .min_by(|x, y| {
+                    x.1.weight
+                        .partial_cmp(&y.1.weight)
+                        .unwrap_or(Less)
+                        .then(x.1.is_virtual().cmp(&y.1.is_virtual()))
+                })
             {
-                return Some(found.1.index);
+                return Some(found.1.face());
             }
             // followed by weights less than the target weight in descending order
             if let Some(found) = set
                 .iter()
                 .enumerate()
                 .filter(|f| f.1.weight < weight)
-                .max_by(|x, y| x.1.weight.partial_cmp(&y.1.weight).unwrap_or(Less))
+                .max_by(|x, y| {
+                    x.1.weight
+                        .partial_cmp(&y.1.weight)
+                        .unwrap_or(Less)
+                        .then(y.1.is_virtual().cmp(&x.1.is_virtual()))
+                })
             {
-                return Some(found.1.index);
+                return Some(found.1.face());
             }
             // followed by weights greater than 500, until a match is found.
             if let Some(found) = set
                 .iter()
                 .enumerate()
                 .filter(|f| f.1.weight > 500.0)
-                .min_by(|x, y| x.1.weight.partial_cmp(&y.1.weight).unwrap_or(Less))
+                .min_by(|x, y| {
+                    x.1.weight
+                        .partial_cmp(&y.1.weight)
+                        .unwrap_or(Less)
+                        .then(x.1.is_virtual().cmp(&y.1.is_virtual()))
+                })
             {
-                return Some(found.1.index);
+                return Some(found.1.face());
             }
         }
         // If the desired weight is less than 400...
         else if weight < 400.0 {
             // weights less than or equal to the target weight are checked in descending
-            if let Some(found) = set
-                .iter()
-                .filter(|f| f.weight <= weight)
-                .max_by(|x, y| x.weight.partial_cmp(&y.weight).unwrap_or(Less))
-            {
-                return Some(found.index);
+            if let Some(found) = set.iter().filter(|f| f.weight <= weight).max_by(|x, y| {
+                x.weight
+                    .partial_cmp(&y.weight)
+                    .unwrap_or(Less)
+                    .then(y.is_virtual().cmp(&x.is_virtual()))
+            }) {
+                return Some(found.face());
             }
             // followed by weights greater than the target weight in ascending order
-            if let Some(found) = set
-                .iter()
-                .filter(|f| f.weight > weight)
-                .min_by(|x, y| x.weight.partial_cmp(&y.weight).unwrap_or(Less))
-            {
-                return Some(found.index);
+            if let Some(found) = set.iter().filter(|f| f.weight > weight).min_by(|x, y| {
+                x.weight
+                    .partial_cmp(&y.weight)
+                    .unwrap_or(Less)
+                    .then(x.is_virtual().cmp(&y.is_virtual()))
+            }) {
+                return Some(found.face());
             }
         }
         // If the desired weight is greater than 500...
         else {
             // weights greater than or equal to the target weight are checked in ascending
-            if let Some(found) = set
-                .iter()
-                .filter(|f| f.weight >= weight)
-                .min_by(|x, y| x.weight.partial_cmp(&y.weight).unwrap_or(Less))
-            {
-                return Some(found.index);
+            if let Some(found) = set.iter().filter(|f| f.weight >= weight).min_by(|x, y| {
+                x.weight
+                    .partial_cmp(&y.weight)
+                    .unwrap_or(Less)
+                    .then(x.is_virtual().cmp(&y.is_virtual()))
+            }) {
+                return Some(found.face());
             }
             // followed by weights less than the target weight in descending order
-            if let Some(found) = set
-                .iter()
-                .filter(|f| f.weight < weight)
-                .max_by(|x, y| x.weight.partial_cmp(&y.weight).unwrap_or(Less))
-            {
-                return Some(found.index);
+            if let Some(found) = set.iter().filter(|f| f.weight < weight).max_by(|x, y| {
+                x.weight
+                    .partial_cmp(&y.weight)
+                    .unwrap_or(Less)
+                    .then(y.is_virtual().cmp(&x.is_virtual()))
+            }) {
+                return Some(found.face());
             }
         }
     }
@@ -0,0 +1,212 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Polling based watching of font directories.
+
+use super::Collection;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+use std::{
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// File extensions that are considered to be font files.
+const FONT_EXTENSIONS: &[&str] = &["ttf", "otf", "ttc", "otc"];
+
+/// Watches the [scan paths](Collection::register_scan_path) of a
+/// [`Collection`] for font files being added, removed or modified and applies
+/// those changes to the collection.
+///
+/// This uses polling rather than platform specific notification APIs. Call
+/// [`FontWatcher::poll`] periodically (for example, when the application
+/// regains focus or on a timer) to pick up changes. The first poll after a
+/// scan path is registered loads all of the fonts found under that path.
+///
+/// Every poll that detects changes increments the
+/// [generation](Collection::generation) of the collection, which can be
+/// used to determine whether previously computed layouts are stale.
+#[derive(Clone, Default, Debug)]
+pub struct FontWatcher {
+    files: HashMap<PathBuf, FileStamp>,
+}
+
+impl FontWatcher {
+    /// Creates a new watcher.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scans the registered scan paths of the given collection and applies
+    /// any changes to it.
+    ///
+    /// Returns a summary of the files that changed since the last poll.
+    pub fn poll(&mut self, collection: &mut Collection) -> WatchChanges {
+        let mut current = HashMap::default();
+        for (path, max_depth) in collection.scan_paths() {
+            stamp_path(&path, max_depth, 0, &mut current);
+        }
+        let mut changes = WatchChanges::default();
+        for (path, stamp) in &current {
+            match self.files.get(path) {
+                None => changes.added.push(path.clone()),
+                Some(old) if old != stamp => changes.modified.push(path.clone()),
+                _ => {}
+            }
+        }
+        for path in self.files.keys() {
+            if !current.contains_key(path) {
+                changes.removed.push(path.clone());
+            }
+        }
+        if !changes.is_empty() {
+            // Modified files are removed and registered again in a single
+            // update so that their families are retained.
+            let removed: Vec<PathBuf> = changes
+                .removed
+                .iter()
+                .chain(&changes.modified)
+                .cloned()
+                .collect();
+            let added: Vec<PathBuf> = changes
+                .added
+                .iter()
+                .chain(&changes.modified)
+                .cloned()
+                .collect();
+            collection.update_font_paths(&removed, &added);
+        }
+        self.files = current;
+        changes
+    }
+}
+
+/// Changes detected by a call to [`FontWatcher::poll`].
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct WatchChanges {
+    /// Font files that were added.
+    pub added: Vec<PathBuf>,
+    /// Font files that were removed.
+    pub removed: Vec<PathBuf>,
+    /// Font files that were modified.
+    pub modified: Vec<PathBuf>,
+}
+
+impl WatchChanges {
+    /// Returns `true` if no changes were detected.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Metadata used to detect changes to a file.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct FileStamp {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+fn stamp_path(path: &Path, max_depth: u32, depth: u32, files: &mut HashMap<PathBuf, FileStamp>) {
+    let Ok(metadata) = path.metadata() else {
+        return;
+    };
+    if metadata.is_dir() {
+        if depth > max_depth {
+            return;
+        }
+        let Ok(entries) = std::fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            stamp_path(&entry.path(), max_depth, depth + 1, files);
+        }
+    } else if is_font_file(path) {
+        files.insert(
+            path.to_path_buf(),
+            FileStamp {
+                len: metadata.len(),
+                modified: metadata.modified().ok(),
+            },
+        );
+    }
+}
+
+fn is_font_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            FONT_EXTENSIONS
+                .iter()
+                .any(|font_ext| ext.eq_ignore_ascii_case(font_ext))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FontWatcher;
+    use crate::{Collection, CollectionOptions};
+    use std::path::{Path, PathBuf};
+
+    fn test_font(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../parley_dev/assets/fonts")
+            .join(name)
+    }
+
+    #[test]
+    fn watcher_tracks_font_files() {
+        let dir = std::env::temp_dir().join(std::format!(
+            "fontique-watch-{}-{}",
+            std::process::id(),
+            line!()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut collection = Collection::new(CollectionOptions {
+            shared: false,
+            system_fonts: false,
+        });
+        let mut watcher = FontWatcher::new();
+        collection.register_scan_path(&dir, 4);
+        assert!(watcher.poll(&mut collection).is_empty());
+
+        // Adding a font registers its family.
+        let font_path = dir.join("font.ttf");
+        std::fs::copy(test_font("roboto_fonts/Roboto-Regular.ttf"), &font_path).unwrap();
+        let generation = collection.generation();
+        let changes = watcher.poll(&mut collection);
+        assert_eq!(changes.added, std::slice::from_ref(&font_path));
+        assert!(collection.generation() > generation);
+        assert!(collection.family_by_name("Roboto").is_some());
+        assert!(watcher.poll(&mut collection).is_empty());
+
+        // Replacing the contents swaps out the family.
+        std::fs::copy(
+            test_font("arimo_fonts/Arimo-VariableFont_wght.ttf"),
+            &font_path,
+        )
+        .unwrap();
+        let changes = watcher.poll(&mut collection);
+        assert_eq!(changes.modified, std::slice::from_ref(&font_path));
+        assert!(collection.family_by_name("Roboto").is_none());
+        assert!(collection.family_by_name("Arimo").is_some());
+
+        // Removing the file removes the family.
+        std::fs::remove_file(&font_path).unwrap();
+        let changes = watcher.poll(&mut collection);
+        assert_eq!(changes.removed, std::slice::from_ref(&font_path));
+        assert!(collection.family_by_name("Arimo").is_none());
+
+        // Unregistering the scan path removes the fonts found under it.
+        std::fs::copy(test_font("roboto_fonts/Roboto-Regular.ttf"), &font_path).unwrap();
+        watcher.poll(&mut collection);
+        assert!(collection.family_by_name("Roboto").is_some());
+        assert!(collection.unregister_scan_path(&dir));
+        let changes = watcher.poll(&mut collection);
+        assert_eq!(changes.removed, std::slice::from_ref(&font_path));
+        assert!(collection.family_by_name("Roboto").is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
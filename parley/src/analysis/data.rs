@@ -0,0 +1,74 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unicode data loaded at runtime.
+
+use alloc::sync::Arc;
+use core::fmt;
+
+use icu_provider::DataError;
+use icu_provider_blob::BlobDataProvider;
+use parley_data::CompositeProps;
+
+/// Unicode data used for text analysis, loaded at runtime.
+///
+/// This allows the data to be fetched separately from the application, for
+/// example on the web, instead of being baked into the binary. The data is
+/// produced by running `parley_data_gen blob <output-file>` and must come from
+/// the same version of Parley.
+///
+/// Use [`LayoutContext::try_with_unicode_data`] to create a layout context
+/// from the data. Cloning the data is cheap.
+///
+/// [`LayoutContext::try_with_unicode_data`]: crate::LayoutContext::try_with_unicode_data
+#[derive(Clone)]
+pub struct UnicodeData {
+    pub(crate) provider: Arc<BlobDataProvider>,
+    pub(crate) composite: CompositeProps,
+}
+
+impl UnicodeData {
+    /// Loads the data from a blob.
+    ///
+    /// The contents of the blob are copied, so it can be dropped after this
+    /// returns.
+    pub fn try_from_blob(blob: &[u8]) -> Result<Self, UnicodeDataError> {
+        let (composite, icu4x_data) =
+            parley_data::blob::split(blob).ok_or(UnicodeDataError::Format)?;
+        let composite =
+            CompositeProps::try_from_postcard(composite.into()).ok_or(UnicodeDataError::Format)?;
+        let provider = BlobDataProvider::try_new_from_blob(icu4x_data.into())
+            .map_err(UnicodeDataError::Data)?;
+        Ok(Self {
+            provider: Arc::new(provider),
+            composite,
+        })
+    }
+}
+
+impl fmt::Debug for UnicodeData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnicodeData").finish_non_exhaustive()
+    }
+}
+
+/// Error returned when loading [`UnicodeData`] fails.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum UnicodeDataError {
+    /// The blob is not in the expected format.
+    Format,
+    /// The blob does not contain the required data.
+    Data(DataError),
+}
+
+impl fmt::Display for UnicodeDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format => f.write_str("invalid Unicode data blob"),
+            Self::Data(err) => write!(f, "failed to load Unicode data: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for UnicodeDataError {}
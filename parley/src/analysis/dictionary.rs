@@ -0,0 +1,160 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! User supplied word segmentation dictionaries.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+use core::ops::Range;
+
+use parley_data::dictionary::DictionaryData;
+
+/// Determines how a [`SegmentationDictionary`] interacts with the built-in
+/// word segmentation.
+///
+/// In both modes, the word boundaries found by the dictionary are also line
+/// break opportunities. The line break opportunities found by the built-in
+/// segmentation are always kept, so that the [`WordBreak`] and [`LineBreak`]
+/// styles still apply.
+///
+/// [`WordBreak`]: crate::WordBreak
+/// [`LineBreak`]: crate::LineBreak
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub enum DictionaryMode {
+    /// Replace the built-in word boundaries within runs of the script with
+    /// those found by the dictionary.
+    #[default]
+    Override,
+    /// Add the word boundaries found by the dictionary to the built-in word
+    /// boundaries.
+    Augment,
+}
+
+/// A list of words used to segment text in scripts that do not separate words
+/// with spaces, such as Thai, Lao, Khmer and Burmese.
+///
+/// Text is segmented by repeatedly matching the longest dictionary word.
+/// Text that does not match any word is kept together until the next match.
+///
+/// Dictionaries are registered for a script with
+/// [`LayoutContext::set_segmentation_dictionary`].
+///
+/// [`LayoutContext::set_segmentation_dictionary`]: crate::LayoutContext::set_segmentation_dictionary
+#[derive(Clone, Default, Debug)]
+pub struct SegmentationDictionary {
+    /// The words in sorted order, each terminated by a `\n`.
+    text: Box<str>,
+    /// The byte ranges of the words in `text`.
+    words: Vec<Range<usize>>,
+    max_len: usize,
+}
+
+impl SegmentationDictionary {
+    /// Creates a dictionary from a list of words.
+    pub fn from_words<I>(words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut words: Vec<I::Item> = words
+            .into_iter()
+            .filter(|word| !word.as_ref().is_empty())
+            .collect();
+        words.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        words.dedup_by(|a, b| a.as_ref() == b.as_ref());
+        let mut text =
+            String::with_capacity(words.iter().map(|word| word.as_ref().len() + 1).sum());
+        for word in &words {
+            text.push_str(word.as_ref());
+            text.push('\n');
+        }
+        Self::from_sorted(text.into())
+    }
+
+    /// Creates a dictionary from data in the compact format produced by
+    /// `parley_data_gen dictionary`.
+    ///
+    /// The words are copied into a single buffer, without allocating for
+    /// every word.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, DictionaryError> {
+        let data = DictionaryData::new(data).ok_or(DictionaryError)?;
+        Ok(Self::from_sorted(data.as_str().into()))
+    }
+
+    /// Creates a dictionary from unique, sorted words that are each
+    /// terminated by a `\n`.
+    fn from_sorted(text: Box<str>) -> Self {
+        let mut words = Vec::new();
+        let mut start = 0;
+        for word in text.split_terminator('\n') {
+            if !word.is_empty() {
+                words.push(start..start + word.len());
+            }
+            start += word.len() + 1;
+        }
+        let max_len = words.iter().map(ExactSizeIterator::len).max().unwrap_or(0);
+        Self {
+            text,
+            words,
+            max_len,
+        }
+    }
+
+    /// Returns the number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns `true` if the dictionary contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns `true` if the dictionary contains the given word.
+    pub fn contains(&self, word: &str) -> bool {
+        self.words
+            .binary_search_by(|probe| self.text[probe.clone()].cmp(word))
+            .is_ok()
+    }
+
+    /// Returns the length in bytes of the longest word that is a prefix of
+    /// `text`.
+    fn longest_prefix(&self, text: &str) -> Option<usize> {
+        let limit = text.len().min(self.max_len);
+        (1..=limit)
+            .rev()
+            .filter(|len| text.is_char_boundary(*len))
+            .find(|len| self.contains(&text[..*len]))
+    }
+
+    /// Appends the byte offsets of the word boundaries strictly inside
+    /// `text`, relative to `offset`.
+    pub(crate) fn segment(&self, text: &str, offset: usize, boundaries: &mut Vec<usize>) {
+        let mut pos = 0;
+        while pos < text.len() {
+            if let Some(len) = self.longest_prefix(&text[pos..]) {
+                if pos != 0 {
+                    boundaries.push(offset + pos);
+                }
+                pos += len;
+                if pos != text.len() {
+                    boundaries.push(offset + pos);
+                }
+            } else {
+                pos += text[pos..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+}
+
+/// Error returned when dictionary data is not in the expected format.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DictionaryError;
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid segmentation dictionary data")
+    }
+}
+
+impl core::error::Error for DictionaryError {}
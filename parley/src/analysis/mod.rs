@@ -2,31 +2,115 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub(crate) mod cluster;
+#[cfg(feature = "runtime_data")]
+pub(crate) mod data;
+pub(crate) mod dictionary;
+#[cfg(feature = "baked_data")]
 mod provider;
 
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 use core::marker::PhantomData;
+use core::ops::Range;
 
+use crate::analysis::dictionary::{DictionaryMode, SegmentationDictionary};
+#[cfg(feature = "baked_data")]
 use crate::analysis::provider::PROVIDER;
 use crate::resolve::{RangedStyle, ResolvedStyle};
-use crate::{Brush, LayoutContext, WordBreak};
+use crate::{Brush, LayoutContext, LineBreak, WordBreak};
 
 use icu_normalizer::properties::{
     CanonicalComposition, CanonicalCompositionBorrowed, CanonicalDecomposition,
     CanonicalDecompositionBorrowed,
 };
-use icu_properties::props::{BidiMirroringGlyph, GeneralCategory, GraphemeClusterBreak, Script};
+use icu_properties::props::{
+    BidiMirroringGlyph, GeneralCategory, GraphemeClusterBreak, JoiningType, Script,
+};
 use icu_properties::{
     CodePointMapData, CodePointMapDataBorrowed, PropertyNamesShort, PropertyNamesShortBorrowed,
 };
-use icu_segmenter::options::{LineBreakOptions, LineBreakWordOption, WordBreakOptions};
+use icu_provider::DataError;
+use icu_segmenter::options::{
+    LineBreakOptions, LineBreakStrictness, LineBreakWordOption, WordBreakOptions,
+};
 use icu_segmenter::{
     GraphemeClusterSegmenter, GraphemeClusterSegmenterBorrowed, LineSegmenter,
     LineSegmenterBorrowed, WordSegmenter, WordSegmenterBorrowed,
 };
 use parley_data::CompositeProps;
 
+/// Where the Unicode data used for analysis comes from.
+#[derive(Clone)]
+pub(crate) enum DataSource {
+    /// Data baked into the binary.
+    #[cfg(feature = "baked_data")]
+    Baked,
+    /// Data loaded at runtime.
+    #[cfg(feature = "runtime_data")]
+    Runtime(data::UnicodeData),
+    /// No data, which keeps the analysis code type checked when neither
+    /// data feature is enabled.
+    #[cfg(not(any(feature = "baked_data", feature = "runtime_data")))]
+    #[expect(
+        dead_code,
+        reason = "A `LayoutContext` cannot be created without the `baked_data` or `runtime_data` feature."
+    )]
+    Unavailable,
+}
+
+impl DataSource {
+    /// Returns the lookup for the composite character properties.
+    fn composite(&self) -> CompositeProps {
+        match *self {
+            #[cfg(feature = "baked_data")]
+            Self::Baked => CompositeProps::baked(),
+            #[cfg(feature = "runtime_data")]
+            Self::Runtime(ref data) => data.composite.clone(),
+            #[cfg(not(any(feature = "baked_data", feature = "runtime_data")))]
+            Self::Unavailable => unreachable!("no Unicode data is available"),
+        }
+    }
+}
+
+/// Evaluates `$body` with `$provider` bound to a data provider for `$source`.
+macro_rules! with_provider {
+    ($source:expr, |$provider:ident| $body:expr) => {
+        match *$source {
+            #[cfg(feature = "baked_data")]
+            DataSource::Baked => {
+                let $provider = &PROVIDER;
+                $body
+            }
+            #[cfg(feature = "runtime_data")]
+            DataSource::Runtime(ref data) => {
+                use icu_provider::buf::AsDeserializingBufferProvider as _;
+                let $provider = &(*data.provider).as_deserializing();
+                $body
+            }
+            #[cfg(not(any(feature = "baked_data", feature = "runtime_data")))]
+            DataSource::Unavailable => {
+                let $provider = &NoData;
+                $body
+            }
+        }
+    };
+}
+
+/// Data provider that fails every request.
+#[cfg(not(any(feature = "baked_data", feature = "runtime_data")))]
+struct NoData;
+
+#[cfg(not(any(feature = "baked_data", feature = "runtime_data")))]
+impl<M: icu_provider::DataMarker> icu_provider::DataProvider<M> for NoData {
+    fn load(
+        &self,
+        req: icu_provider::DataRequest<'_>,
+    ) -> Result<icu_provider::DataResponse<M>, DataError> {
+        Err(icu_provider::DataErrorKind::MarkerNotFound.with_req(M::INFO, req))
+    }
+}
+
 pub(crate) struct AnalysisDataSources {
+    source: DataSource,
     grapheme_segmenter: GraphemeClusterSegmenter,
     word_segmenter: WordSegmenter,
     line_segmenters: LineSegmenters,
@@ -34,57 +118,103 @@ pub(crate) struct AnalysisDataSources {
     decomposing_normalizer: CanonicalDecomposition,
     script_short_name: PropertyNamesShort<Script>,
     brackets: CodePointMapData<BidiMirroringGlyph>,
+    pub(crate) dictionaries: Vec<ScriptDictionary>,
 
     composite: CompositeProps,
 }
 
+/// A segmentation dictionary registered for a script.
+#[derive(Clone)]
+pub(crate) struct ScriptDictionary {
+    pub(crate) script: fontique::Script,
+    pub(crate) mode: DictionaryMode,
+    pub(crate) dictionary: Arc<SegmentationDictionary>,
+}
+
 #[derive(Default)]
 struct LineSegmenters {
-    normal: Option<LineSegmenter>,
-    keep_all: Option<LineSegmenter>,
-    break_all: Option<LineSegmenter>,
+    segmenters: Vec<(LineBreakConfig, LineSegmenter)>,
 }
 
-impl LineSegmenters {
-    fn get(&mut self, word_break_strength: WordBreak) -> LineSegmenterBorrowed<'_> {
-        let segmenter = match word_break_strength {
-            WordBreak::Normal => &mut self.normal,
-            WordBreak::KeepAll => &mut self.keep_all,
-            WordBreak::BreakAll => &mut self.break_all,
-        };
+/// The styles that determine line boundaries.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct LineBreakConfig {
+    word_break: WordBreak,
+    line_break: LineBreak,
+}
 
-        segmenter
-            .get_or_insert_with(|| {
+impl LineBreakConfig {
+    fn new<B: Brush>(style: &ResolvedStyle<B>) -> Self {
+        Self {
+            word_break: style.word_break,
+            line_break: style.line_break,
+        }
+    }
+}
+
+impl LineSegmenters {
+    fn get(&mut self, source: &DataSource, config: LineBreakConfig) -> LineSegmenterBorrowed<'_> {
+        let index = match self.segmenters.iter().position(|(c, _)| *c == config) {
+            Some(index) => index,
+            None => {
                 let mut line_break_opts = LineBreakOptions::default();
-                let word_break_strength_icu = match word_break_strength {
+                let word_break_strength_icu = match config.word_break {
                     WordBreak::Normal => LineBreakWordOption::Normal,
                     WordBreak::BreakAll => LineBreakWordOption::BreakAll,
                     WordBreak::KeepAll => LineBreakWordOption::KeepAll,
                 };
                 line_break_opts.word_option = Some(word_break_strength_icu);
-                LineSegmenter::try_new_auto_unstable(&PROVIDER, line_break_opts)
-                    .expect("Failed to create LineSegmenter")
-            })
-            .as_borrowed()
+                line_break_opts.strictness = match config.line_break {
+                    LineBreak::Auto => None,
+                    LineBreak::Loose => Some(LineBreakStrictness::Loose),
+                    LineBreak::Normal => Some(LineBreakStrictness::Normal),
+                    LineBreak::Strict => Some(LineBreakStrictness::Strict),
+                    LineBreak::Anywhere => Some(LineBreakStrictness::Anywhere),
+                };
+                let segmenter = with_provider!(source, |provider| {
+                    LineSegmenter::try_new_auto_unstable(provider, line_break_opts)
+                })
+                .expect("Failed to create LineSegmenter");
+                self.segmenters.push((config, segmenter));
+                self.segmenters.len() - 1
+            }
+        };
+        self.segmenters[index].1.as_borrowed()
     }
 }
 
 impl AnalysisDataSources {
+    #[cfg(feature = "baked_data")]
     pub(crate) fn new() -> Self {
-        Self {
-            grapheme_segmenter: GraphemeClusterSegmenter::try_new_unstable(&PROVIDER).unwrap(),
+        Self::try_new(DataSource::Baked).unwrap()
+    }
+
+    pub(crate) fn try_new(source: DataSource) -> Result<Self, DataError> {
+        let composite = source.composite();
+        with_provider!(&source, |provider| Ok(Self {
+            grapheme_segmenter: GraphemeClusterSegmenter::try_new_unstable(provider)?,
             word_segmenter: WordSegmenter::try_new_lstm_unstable(
-                &PROVIDER,
+                provider,
                 WordBreakOptions::default(),
-            )
-            .unwrap(),
+            )?,
             line_segmenters: LineSegmenters::default(),
-            composing_normalizer: CanonicalComposition::try_new_unstable(&PROVIDER).unwrap(),
-            decomposing_normalizer: CanonicalDecomposition::try_new_unstable(&PROVIDER).unwrap(),
-            script_short_name: PropertyNamesShort::<Script>::try_new_unstable(&PROVIDER).unwrap(),
-            brackets: CodePointMapData::<BidiMirroringGlyph>::try_new_unstable(&PROVIDER).unwrap(),
-            composite: CompositeProps,
-        }
+            composing_normalizer: CanonicalComposition::try_new_unstable(provider)?,
+            decomposing_normalizer: CanonicalDecomposition::try_new_unstable(provider)?,
+            script_short_name: PropertyNamesShort::<Script>::try_new_unstable(provider)?,
+            brackets: CodePointMapData::<BidiMirroringGlyph>::try_new_unstable(provider)?,
+            dictionaries: Vec::new(),
+            composite,
+            source: source.clone(),
+        }))
+    }
+
+    /// Creates new data sources from the same data, keeping the registered
+    /// dictionaries.
+    pub(crate) fn duplicate(&self) -> Self {
+        let mut sources = Self::try_new(self.source.clone())
+            .expect("Data sources were previously created from the same data");
+        sources.dictionaries.clone_from(&self.dictionaries);
+        sources
     }
 
     #[inline(always)]
@@ -121,6 +251,99 @@ impl AnalysisDataSources {
     pub(crate) fn brackets(&self) -> CodePointMapDataBorrowed<'_, BidiMirroringGlyph> {
         self.brackets.as_borrowed()
     }
+
+    fn dictionary_index(&self, script: Script) -> Option<usize> {
+        let script = crate::convert::script_to_fontique(script, self);
+        self.dictionaries
+            .iter()
+            .position(|entry| entry.script == script)
+    }
+
+    /// Applies the registered dictionaries to the runs of text in their
+    /// scripts, updating the word and line boundary positions.
+    fn apply_dictionaries(
+        &self,
+        text: &str,
+        word_boundary_positions: &mut Vec<usize>,
+        line_boundary_positions: &mut Vec<usize>,
+    ) {
+        let composite = self.composite();
+        let mut cached: Option<(Script, Option<usize>)> = None;
+        let mut run_start = 0;
+        let mut run_dictionary = None;
+        let mut scratch = Vec::new();
+        for (pos, ch) in text.char_indices() {
+            let script = composite.properties(ch as u32).script();
+            let dictionary = match cached {
+                Some((cached_script, index)) if cached_script == script => index,
+                _ => {
+                    let index = self.dictionary_index(script);
+                    cached = Some((script, index));
+                    index
+                }
+            };
+            if dictionary != run_dictionary {
+                if let Some(index) = run_dictionary {
+                    self.segment_run(
+                        &self.dictionaries[index],
+                        text,
+                        run_start..pos,
+                        word_boundary_positions,
+                        line_boundary_positions,
+                        &mut scratch,
+                    );
+                }
+                run_start = pos;
+                run_dictionary = dictionary;
+            }
+        }
+        if let Some(index) = run_dictionary {
+            self.segment_run(
+                &self.dictionaries[index],
+                text,
+                run_start..text.len(),
+                word_boundary_positions,
+                line_boundary_positions,
+                &mut scratch,
+            );
+        }
+        word_boundary_positions.sort_unstable();
+        word_boundary_positions.dedup();
+        line_boundary_positions.sort_unstable();
+        line_boundary_positions.dedup();
+    }
+
+    fn segment_run(
+        &self,
+        entry: &ScriptDictionary,
+        text: &str,
+        range: Range<usize>,
+        word_boundary_positions: &mut Vec<usize>,
+        line_boundary_positions: &mut Vec<usize>,
+        scratch: &mut Vec<usize>,
+    ) {
+        let run = &text[range.clone()];
+        scratch.clear();
+        entry.dictionary.segment(run, range.start, scratch);
+        // Never break inside of a grapheme cluster.
+        let mut graphemes = self
+            .grapheme_segmenter()
+            .segment_str(run)
+            .map(|pos| pos + range.start)
+            .peekable();
+        scratch.dedup();
+        scratch.retain(|pos| {
+            while graphemes.next_if(|grapheme| grapheme < pos).is_some() {}
+            graphemes.peek() == Some(pos)
+        });
+
+        // The built-in line boundaries are kept, as they reflect the line breaking styles.
+        if entry.mode == DictionaryMode::Override {
+            word_boundary_positions.retain(|pos| *pos <= range.start || range.end <= *pos);
+        }
+        word_boundary_positions.extend_from_slice(scratch);
+        line_boundary_positions.extend_from_slice(scratch);
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -135,6 +358,8 @@ pub(crate) struct CharInfo {
     pub bidi_class: icu_properties::props::BidiClass,
     /// Whether or not the character is a bracket, plus mirror data if so.
     pub bracket: BidiMirroringGlyph,
+    /// How this character connects to its neighbours in cursive scripts.
+    pub joining_type: JoiningType,
 
     flags: u8,
 }
@@ -168,6 +393,7 @@ impl CharInfo {
         grapheme_cluster_break: GraphemeClusterBreak,
         bidi_class: icu_properties::props::BidiClass,
         bracket: BidiMirroringGlyph,
+        joining_type: JoiningType,
         is_variation_selector: bool,
         is_region_indicator: bool,
         is_control: bool,
@@ -181,6 +407,7 @@ impl CharInfo {
             grapheme_cluster_break,
             bidi_class,
             bracket,
+            joining_type,
             flags: (is_variation_selector as u8) << Self::VARIATION_SELECTOR_SHIFT
                 | (is_region_indicator as u8) << Self::REGION_INDICATOR_SHIFT
                 | (is_control as u8) << Self::CONTROL_SHIFT
@@ -227,6 +454,24 @@ impl CharInfo {
     pub(crate) fn force_normalize(self) -> bool {
         self.flags & Self::FORCE_NORMALIZE_MASK != 0
     }
+
+    /// Returns `true` if the character connects to the following character in cursive scripts.
+    #[inline(always)]
+    pub(crate) fn joins_to_following(self) -> bool {
+        matches!(
+            self.joining_type,
+            JoiningType::DualJoining | JoiningType::LeftJoining | JoiningType::JoinCausing
+        )
+    }
+
+    /// Returns `true` if the character connects to the preceding character in cursive scripts.
+    #[inline(always)]
+    pub(crate) fn joins_to_preceding(self) -> bool {
+        matches!(
+            self.joining_type,
+            JoiningType::DualJoining | JoiningType::RightJoining | JoiningType::JoinCausing
+        )
+    }
 }
 
 /// Boundary type of a character or cluster.
@@ -250,7 +495,7 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
         char_indices: core::str::CharIndices<'a>,
         current_char: (usize, char),
         building_range_start: usize,
-        previous_word_break_style: WordBreak,
+        previous_word_break_style: LineBreakConfig,
         done: bool,
         _phantom: PhantomData<B>,
     }
@@ -269,7 +514,7 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
                 char_indices,
                 current_char: current_char_len,
                 building_range_start: first_style.range.start,
-                previous_word_break_style: first_style.style.word_break,
+                previous_word_break_style: LineBreakConfig::new(&first_style.style),
                 done: false,
                 _phantom: PhantomData,
             }
@@ -280,7 +525,7 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
     where
         I: Iterator<Item = &'a RangedStyle<B>>,
     {
-        type Item = (&'a str, WordBreak, bool);
+        type Item = (&'a str, LineBreakConfig, bool);
 
         fn next(&mut self) -> Option<Self::Item> {
             if self.done {
@@ -300,7 +545,7 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
                     self.current_char = self.char_indices.next().unwrap();
                 }
 
-                let current_word_break_style = style.style.word_break;
+                let current_word_break_style = LineBreakConfig::new(&style.style);
                 if self.previous_word_break_style == current_word_break_style {
                     continue;
                 }
@@ -338,11 +583,11 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
     let mut line_segmenters = core::mem::take(&mut lcx.analysis_data_sources.line_segmenters);
 
     // Collect boundary byte positions compactly
-    let mut wb_iter = lcx
+    let mut word_boundary_positions: Vec<usize> = lcx
         .analysis_data_sources
         .word_segmenter()
         .segment_str(text)
-        .peekable();
+        .collect();
 
     // Line boundaries (word break naming refers to the line boundary determination config).
     //
@@ -356,13 +601,13 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
         WordBreakSegmentIter::new(text, rest.iter(), first_style);
     let mut global_offset = 0;
     let mut line_boundary_positions: Vec<usize> = Vec::new();
-    for (substring_index, (substring, word_break_strength, last)) in
+    for (substring_index, (substring, line_break_config, last)) in
         contiguous_word_break_substrings.enumerate()
     {
         // Fast path for text with a single word-break option.
         if substring_index == 0 && last {
             let mut lb_iter = line_segmenters
-                .get(word_break_strength)
+                .get(&lcx.analysis_data_sources.source, line_break_config)
                 .segment_str(substring);
 
             let _first = lb_iter.next();
@@ -384,7 +629,7 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
         }
 
         let line_boundaries_iter = line_segmenters
-            .get(word_break_strength)
+            .get(&lcx.analysis_data_sources.source, line_break_config)
             .segment_str(substring);
 
         let mut substring_chars = substring.chars();
@@ -416,7 +661,17 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
         }
     }
 
+    // User supplied dictionaries refine the boundaries for their scripts.
+    if !lcx.analysis_data_sources.dictionaries.is_empty() {
+        lcx.analysis_data_sources.apply_dictionaries(
+            text,
+            &mut word_boundary_positions,
+            &mut line_boundary_positions,
+        );
+    }
+
     // Merge boundaries - line takes precedence over word
+    let mut wb_iter = word_boundary_positions.iter().copied().peekable();
     let mut lb_iter = line_boundary_positions.iter().peekable();
     let boundary_iter = text.char_indices().map(|(byte_pos, ch)| {
         // advance any stale word boundary positions
@@ -504,6 +759,7 @@ pub(crate) fn analyze_text<B: Brush>(lcx: &mut LayoutContext<B>, mut text: &str)
                     grapheme_cluster_break,
                     bidi_class,
                     bracket,
+                    properties.joining_type(),
                     is_variation_selector,
                     is_region_indicator,
                     is_control(general_category),
@@ -10,8 +10,12 @@ use super::style::{Brush, StyleProperty, TextStyle, WhiteSpaceCollapse};
 use super::layout::Layout;
 
 use alloc::string::String;
+use core::fmt::Debug;
+use core::mem::Discriminant;
 use core::ops::RangeBounds;
 
+use attributed_text::{AttributedText, KeyedAttribute, TextChunks, TextStorage};
+
 use crate::inline_box::InlineBox;
 use crate::resolve::tree::ItemKind;
 
@@ -69,6 +73,140 @@ impl<B: Brush> RangedBuilder<'_, B> {
         self.build_into(&mut layout, text);
         layout
     }
+
+    /// Builds the layout from text given as a sequence of chunks, such as the chunks of a rope.
+    ///
+    /// Text analysis and shaping work on contiguous text, so unless there is a single chunk, the
+    /// chunks are copied into a buffer owned by the layout context. The buffer is reused across
+    /// layouts, which avoids allocating for every layout, but the text is still copied.
+    ///
+    /// The builder should be created with [`LayoutContext::ranged_builder_for_len`] and the
+    /// total length of the chunks.
+    ///
+    /// [`LayoutContext::ranged_builder_for_len`]: crate::LayoutContext::ranged_builder_for_len
+    pub fn build_from_chunks_into<'t>(
+        self,
+        layout: &mut Layout<B>,
+        chunks: impl IntoIterator<Item = &'t str>,
+    ) {
+        let mut chunks = chunks.into_iter();
+        let first = chunks.next().unwrap_or_default();
+        let Some(second) = chunks.next() else {
+            self.build_into(layout, first);
+            return;
+        };
+
+        let mut text = core::mem::take(&mut self.lcx.text_buffer);
+        text.clear();
+        text.push_str(first);
+        text.push_str(second);
+        chunks.for_each(|chunk| text.push_str(chunk));
+
+        self.lcx.ranged_style_builder.finish(&mut self.lcx.styles);
+        build_into_layout(layout, self.scale, self.quantize, &text, self.lcx, self.fcx);
+        self.lcx.text_buffer = text;
+    }
+
+    /// Builds the layout from text given as a sequence of chunks, such as the chunks of a rope.
+    ///
+    /// See [`build_from_chunks_into`](Self::build_from_chunks_into).
+    pub fn build_from_chunks<'t>(self, chunks: impl IntoIterator<Item = &'t str>) -> Layout<B> {
+        let mut layout = Layout::default();
+        self.build_from_chunks_into(&mut layout, chunks);
+        layout
+    }
+}
+
+/// An attribute of an [`AttributedText`] that can be used to build a layout.
+///
+/// See [`LayoutContext::attributed_builder`].
+pub trait LayoutAttribute<B: Brush> {
+    /// Calls `push` with each style property set by the attribute.
+    ///
+    /// Properties pushed later take precedence over earlier ones.
+    fn style_properties(&self, push: &mut dyn FnMut(StyleProperty<'_, B>));
+
+    /// Returns the inline box represented by the attribute, if any.
+    ///
+    /// The box is placed at the start of the range the attribute is applied to, replacing its
+    /// [`index`](InlineBox::index). Such attributes are usually applied to an empty range.
+    fn inline_box(&self) -> Option<InlineBox> {
+        None
+    }
+}
+
+impl<B: Brush> LayoutAttribute<B> for StyleProperty<'_, B> {
+    fn style_properties(&self, push: &mut dyn FnMut(StyleProperty<'_, B>)) {
+        push(self.clone());
+    }
+}
+
+/// Style properties of the same kind override each other, matching how they are applied to a
+/// layout.
+impl<B: Brush> KeyedAttribute for StyleProperty<'_, B> {
+    type Key = Discriminant<Self>;
+
+    fn key(&self) -> Self::Key {
+        core::mem::discriminant(self)
+    }
+}
+
+impl<B: Brush> LayoutAttribute<B> for InlineBox {
+    fn style_properties(&self, _: &mut dyn FnMut(StyleProperty<'_, B>)) {}
+
+    fn inline_box(&self) -> Option<InlineBox> {
+        Some(self.clone())
+    }
+}
+
+/// Builder for constructing a text layout from an [`AttributedText`].
+///
+/// Attributes applied later to the text take precedence over attributes applied earlier, and
+/// all of them take precedence over the properties set with
+/// [`push_default`](Self::push_default).
+#[must_use]
+pub struct AttributedBuilder<'a, B: Brush, T: Debug + TextStorage, A: Debug> {
+    pub(crate) builder: RangedBuilder<'a, B>,
+    pub(crate) text: &'a AttributedText<T, A>,
+}
+
+impl<B, T, A> AttributedBuilder<'_, B, T, A>
+where
+    B: Brush,
+    T: Debug + TextChunks,
+    A: Debug + LayoutAttribute<B>,
+{
+    /// Change a property of the style that applies to the whole text.
+    pub fn push_default<'a>(&mut self, property: impl Into<StyleProperty<'a, B>>) {
+        self.builder.push_default(property);
+    }
+
+    /// Builds the layout into an existing [`Layout`], reusing its allocations.
+    ///
+    /// The attributes are applied in the order yielded by
+    /// [`AttributedText::attributes_iter`], which is the order they were applied to the text, so
+    /// where spans overlap, the properties of the later span take precedence. Inline boxes are
+    /// placed at the start of the range of their attribute.
+    pub fn build_into(mut self, layout: &mut Layout<B>) {
+        for (range, attribute) in self.text.attributes_iter() {
+            attribute.style_properties(&mut |property| self.builder.push(property, range.clone()));
+            if let Some(mut inline_box) = attribute.inline_box() {
+                inline_box.index = range.start;
+                self.builder.push_inline_box(inline_box);
+            }
+        }
+        self.builder
+            .build_from_chunks_into(layout, self.text.text().chunks());
+    }
+
+    /// Builds a new layout.
+    ///
+    /// See [`build_into`](Self::build_into).
+    pub fn build(self) -> Layout<B> {
+        let mut layout = Layout::default();
+        self.build_into(&mut layout);
+        layout
+    }
 }
 
 /// Builder for constructing a text layout with a tree of attributes.
@@ -162,6 +300,7 @@ fn build_into_layout<B: Brush>(
     layout.data.quantize = quantize;
     layout.data.base_level = lcx.bidi.base_level();
     layout.data.text_len = text.len();
+    layout.data.font_generation = fcx.collection.generation();
 
     let mut char_index = 0;
     for (i, style) in lcx.styles.iter().enumerate() {
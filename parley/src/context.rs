@@ -3,15 +3,23 @@
 
 //! Context for layout.
 
-use alloc::{vec, vec::Vec};
+use alloc::{string::String, sync::Arc, vec, vec::Vec};
+use core::fmt::Debug;
+
+use attributed_text::{AttributedText, TextChunks};
 
 use super::FontContext;
-use super::builder::RangedBuilder;
+use super::builder::{AttributedBuilder, LayoutAttribute, RangedBuilder};
 use super::resolve::tree::TreeStyleBuilder;
 use super::resolve::{RangedStyle, RangedStyleBuilder, ResolveContext, ResolvedStyle};
 use super::style::{Brush, TextStyle};
 
-use crate::analysis::{AnalysisDataSources, CharInfo};
+#[cfg(feature = "runtime_data")]
+use crate::analysis::DataSource;
+#[cfg(feature = "runtime_data")]
+use crate::analysis::data::{UnicodeData, UnicodeDataError};
+use crate::analysis::dictionary::{DictionaryMode, SegmentationDictionary};
+use crate::analysis::{AnalysisDataSources, CharInfo, ScriptDictionary};
 use crate::bidi::BidiResolver;
 use crate::builder::TreeBuilder;
 use crate::inline_box::InlineBox;
@@ -36,10 +44,30 @@ pub struct LayoutContext<B: Brush = [u8; 4]> {
 
     // Unicode analysis data sources (provided by icu)
     pub(crate) analysis_data_sources: AnalysisDataSources,
+
+    // Reusable buffer for gathering text that is given in chunks
+    pub(crate) text_buffer: String,
 }
 
 impl<B: Brush> LayoutContext<B> {
+    /// Creates a new layout context using the Unicode data baked into the binary.
+    #[cfg(feature = "baked_data")]
     pub fn new() -> Self {
+        Self::with_analysis_data_sources(AnalysisDataSources::new())
+    }
+
+    /// Creates a new layout context using Unicode data loaded at runtime.
+    ///
+    /// Returns an error if the data is missing anything required for text
+    /// analysis.
+    #[cfg(feature = "runtime_data")]
+    pub fn try_with_unicode_data(data: &UnicodeData) -> Result<Self, UnicodeDataError> {
+        let analysis_data_sources = AnalysisDataSources::try_new(DataSource::Runtime(data.clone()))
+            .map_err(UnicodeDataError::Data)?;
+        Ok(Self::with_analysis_data_sources(analysis_data_sources))
+    }
+
+    fn with_analysis_data_sources(analysis_data_sources: AnalysisDataSources) -> Self {
         Self {
             rcx: ResolveContext::default(),
             styles: vec![],
@@ -48,8 +76,9 @@ impl<B: Brush> LayoutContext<B> {
             ranged_style_builder: RangedStyleBuilder::default(),
             tree_style_builder: TreeStyleBuilder::default(),
             info: vec![],
-            analysis_data_sources: AnalysisDataSources::new(),
+            analysis_data_sources,
             scx: ShapeContext::default(),
+            text_buffer: String::new(),
         }
     }
 
@@ -88,12 +117,25 @@ impl<B: Brush> LayoutContext<B> {
         text: &'a str,
         scale: f32,
         quantize: bool,
+    ) -> RangedBuilder<'a, B> {
+        self.ranged_builder_for_len(fcx, text.len(), scale, quantize)
+    }
+
+    /// Create a ranged style layout builder for text of `len` bytes that is given when building
+    /// the layout, for example in chunks with [`RangedBuilder::build_from_chunks`].
+    ///
+    /// See [`ranged_builder`](Self::ranged_builder) for the meaning of `quantize`.
+    pub fn ranged_builder_for_len<'a>(
+        &'a mut self,
+        fcx: &'a mut FontContext,
+        len: usize,
+        scale: f32,
+        quantize: bool,
     ) -> RangedBuilder<'a, B> {
         self.begin();
 
         let resolved_root_style = self.resolve_style_set(fcx, scale, &TextStyle::default());
-        self.ranged_style_builder
-            .begin(resolved_root_style, text.len());
+        self.ranged_style_builder.begin(resolved_root_style, len);
 
         fcx.source_cache.prune(128, false);
 
@@ -105,6 +147,32 @@ impl<B: Brush> LayoutContext<B> {
         }
     }
 
+    /// Create a layout builder for text with attributes.
+    ///
+    /// The attributes are converted to style properties and inline boxes with
+    /// [`LayoutAttribute`]. Where attributes conflict, the one applied to the text last wins.
+    /// Text in contiguous storage is laid out in place, without being copied. Text in chunked
+    /// storage, such as a rope, is copied into a buffer owned by this context, as layout needs
+    /// contiguous text. See [`RangedBuilder::build_from_chunks`].
+    ///
+    /// See [`ranged_builder`](Self::ranged_builder) for the meaning of `quantize`.
+    pub fn attributed_builder<'a, T, A>(
+        &'a mut self,
+        fcx: &'a mut FontContext,
+        text: &'a AttributedText<T, A>,
+        scale: f32,
+        quantize: bool,
+    ) -> AttributedBuilder<'a, B, T, A>
+    where
+        T: Debug + TextChunks,
+        A: Debug + LayoutAttribute<B>,
+    {
+        AttributedBuilder {
+            builder: self.ranged_builder_for_len(fcx, text.len(), scale, quantize),
+            text,
+        }
+    }
+
     /// Create a tree style layout builder.
     ///
     /// Set `quantize` as `true` to have the layout coordinates aligned to pixel boundaries.
@@ -146,6 +214,45 @@ impl<B: Brush> LayoutContext<B> {
         }
     }
 
+    /// Registers a word segmentation dictionary for the given script.
+    ///
+    /// The dictionary is used to find word boundaries in runs of text in that
+    /// script, either replacing or augmenting the built-in word boundaries
+    /// depending on `mode`. The word boundaries are also line break
+    /// opportunities. This replaces any dictionary previously registered for
+    /// the script.
+    pub fn set_segmentation_dictionary(
+        &mut self,
+        script: fontique::Script,
+        dictionary: Arc<SegmentationDictionary>,
+        mode: DictionaryMode,
+    ) {
+        let dictionaries = &mut self.analysis_data_sources.dictionaries;
+        let entry = ScriptDictionary {
+            script,
+            mode,
+            dictionary,
+        };
+        if let Some(existing) = dictionaries.iter_mut().find(|entry| entry.script == script) {
+            *existing = entry;
+        } else {
+            dictionaries.push(entry);
+        }
+    }
+
+    /// Removes the word segmentation dictionary registered for the given
+    /// script, returning it if present.
+    pub fn remove_segmentation_dictionary(
+        &mut self,
+        script: fontique::Script,
+    ) -> Option<Arc<SegmentationDictionary>> {
+        let dictionaries = &mut self.analysis_data_sources.dictionaries;
+        let index = dictionaries
+            .iter()
+            .position(|entry| entry.script == script)?;
+        Some(dictionaries.remove(index).dictionary)
+    }
+
     fn begin(&mut self) {
         self.rcx.clear();
         self.styles.clear();
@@ -155,6 +262,7 @@ impl<B: Brush> LayoutContext<B> {
     }
 }
 
+#[cfg(feature = "baked_data")]
 impl<B: Brush> Default for LayoutContext<B> {
     fn default() -> Self {
         Self::new()
@@ -163,7 +271,8 @@ impl<B: Brush> Default for LayoutContext<B> {
 
 impl<B: Brush> Clone for LayoutContext<B> {
     fn clone(&self) -> Self {
-        // None of the internal state is visible so just return a new instance.
-        Self::new()
+        // Apart from the Unicode data and segmentation dictionaries, none of
+        // the internal state is visible so just return a new instance.
+        Self::with_analysis_data_sources(self.analysis_data_sources.duplicate())
     }
 }
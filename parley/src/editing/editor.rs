@@ -3,7 +3,7 @@
 
 //! A simple plain text editor and related types.
 
-use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
 use core::{
     cmp::PartialEq,
     default::Default,
@@ -12,8 +12,8 @@ use core::{
     ops::Range,
 };
 
-use crate::editing::{Cursor, Selection};
-use crate::layout::{Affinity, Alignment, AlignmentOptions, Layout};
+use crate::editing::{Cursor, Selection, SelectionFragment};
+use crate::layout::{Affinity, Alignment, AlignmentOptions, Layout, TextIndex};
 use crate::style::Brush;
 use crate::{BoundingBox, FontContext, LayoutContext, StyleProperty, StyleSet};
 
@@ -83,6 +83,28 @@ impl<'source> IntoIterator for SplitString<'source> {
     }
 }
 
+/// The text around the selection of a [`PlainEditor`], as returned by
+/// [`PlainEditor::surrounding_text`].
+///
+/// Platform IMEs use this to provide context-aware suggestions and to delete text around the
+/// caret. The offsets of the selection are relative to the start of [`text`](Self::text), and
+/// are given in both UTF-8 bytes and UTF-16 code units, as platforms differ in which they use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SurroundingText<'source> {
+    /// The text around the selection, including the selected text.
+    pub text: &'source str,
+    /// The byte offset of the start of `text` in the text of the editor.
+    pub offset: usize,
+    /// The byte offset of the selection anchor in `text`.
+    pub anchor: usize,
+    /// The byte offset of the selection focus, which is the caret, in `text`.
+    pub focus: usize,
+    /// The offset of the selection anchor in `text`, in UTF-16 code units.
+    pub anchor_utf16: usize,
+    /// The offset of the selection focus in `text`, in UTF-16 code units.
+    pub focus_utf16: usize,
+}
+
 /// Basic plain text editor with a single style applied to the entire text.
 ///
 /// Internally, this is a wrapper around a string buffer and its corresponding [`Layout`],
@@ -95,13 +117,21 @@ where
 {
     layout: Layout<T>,
     buffer: String,
+    /// The index of the offsets in `buffer`, which is updated with each edit.
+    text_index: TextIndex,
     default_style: StyleSet<T>,
     #[cfg(feature = "accesskit")]
     layout_access: LayoutAccessibility,
-    selection: Selection,
+    /// The selections, sorted and non-overlapping. There is always at least one.
+    selections: Vec<Selection>,
+    /// The index of the primary selection in `selections`.
+    primary: usize,
     /// Byte offsets of IME composing preedit text in the text buffer.
     /// `None` if the IME is not currently composing.
     compose: Option<Range<usize>>,
+    /// The text that the IME preedit text replaced, if composing started with
+    /// [`reconvert`](PlainEditorDriver::reconvert). It is restored if composing is cleared.
+    reconversion: Option<String>,
     /// Whether the cursor should be shown. The IME can request to hide the cursor.
     show_cursor: bool,
     width: Option<f32>,
@@ -131,11 +161,14 @@ where
         Self {
             default_style: StyleSet::new(font_size),
             buffer: String::default(),
+            text_index: TextIndex::default(),
             layout: Layout::default(),
             #[cfg(feature = "accesskit")]
             layout_access: LayoutAccessibility::default(),
-            selection: Selection::default(),
+            selections: vec![Selection::default()],
+            primary: 0,
             compose: None,
+            reconversion: None,
             show_cursor: true,
             width: None,
             font_size,
@@ -155,6 +188,11 @@ where
 ///
 /// This can perform operations which require the editor's layout to
 /// be up-to-date by refreshing it as necessary.
+///
+/// Edits and relative movements apply to every selection of the editor. Methods which select a
+/// given position or range, such as [`move_to_point`](Self::move_to_point), replace all
+/// selections with one, and methods which extend a selection to a given position only change the
+/// primary selection.
 pub struct PlainEditorDriver<'a, T>
 where
     T: Brush + Clone + Debug + PartialEq + Default,
@@ -169,130 +207,98 @@ where
     T: Brush + Clone + Debug + PartialEq + Default,
 {
     // --- MARK: Forced relayout ---
-    /// Insert at cursor, or replace selection.
+    /// Insert at each cursor, or replace each selection.
     pub fn insert_or_replace_selection(&mut self, s: &str) {
         self.editor
             .replace_selection(self.font_cx, self.layout_cx, s);
     }
 
-    /// Delete the selection.
+    /// Delete each selection.
     pub fn delete_selection(&mut self) {
         self.insert_or_replace_selection("");
     }
 
-    /// Delete the specified numbers of bytes before the selection.
-    /// The selection is moved to the left by that number of bytes
+    /// Delete the specified numbers of bytes before each selection.
+    /// The selections are moved to the left by that number of bytes
     /// but otherwise unchanged.
     ///
-    /// The deleted range is clamped to the start of the buffer.
-    /// No-op if the start of the range is not a char boundary.
+    /// The deleted ranges are clamped to the start of the buffer.
+    /// No-op for a selection if the start of its range is not a char boundary.
     pub fn delete_bytes_before_selection(&mut self, len: NonZeroUsize) {
-        let old_selection = self.editor.selection;
-        let selection_range = old_selection.text_range();
-        let range = selection_range.start.saturating_sub(len.get())..selection_range.start;
-        if range.is_empty() || !self.editor.buffer.is_char_boundary(range.start) {
-            return;
-        }
-        self.editor.buffer.replace_range(range.clone(), "");
         self.editor
-            .update_compose_for_replaced_range(range.clone(), 0);
-        self.update_layout();
-        let old_anchor = old_selection.anchor();
-        let old_focus = old_selection.focus();
-        // When doing the equivalent of a backspace on a collapsed selection,
-        // always use downstream affinity, as `backdelete` does.
-        let (anchor_affinity, focus_affinity) = if old_selection.is_collapsed() {
-            (Affinity::Downstream, Affinity::Downstream)
-        } else {
-            (old_anchor.affinity(), old_focus.affinity())
-        };
-        self.editor.set_selection(Selection::new(
-            Cursor::from_byte_index(
-                &self.editor.layout,
-                old_anchor.index() - range.len(),
-                anchor_affinity,
-            ),
-            Cursor::from_byte_index(
-                &self.editor.layout,
-                old_focus.index() - range.len(),
-                focus_affinity,
-            ),
-        ));
+            .edit_selections(self.font_cx, self.layout_cx, "", |editor, selection| {
+                let start = selection.text_range().start;
+                let range = start.saturating_sub(len.get())..start;
+                if range.is_empty() || !editor.buffer.is_char_boundary(range.start) {
+                    return None;
+                }
+                // When doing the equivalent of a backspace on a collapsed selection,
+                // always use downstream affinity, as `backdelete` does.
+                Some((
+                    range,
+                    selection.is_collapsed().then_some(Affinity::Downstream),
+                ))
+            });
     }
 
-    /// Delete the specified numbers of bytes after the selection.
-    /// The selection is unchanged.
+    /// Delete the specified numbers of bytes after each selection.
+    /// The selections are unchanged.
     ///
-    /// The deleted range is clamped to the end of the buffer.
-    /// No-op if the end of the range is not a char boundary.
+    /// The deleted ranges are clamped to the end of the buffer.
+    /// No-op for a selection if the end of its range is not a char boundary.
     pub fn delete_bytes_after_selection(&mut self, len: NonZeroUsize) {
-        let selection_range = self.editor.selection.text_range();
-        let range = selection_range.end
-            ..selection_range
-                .end
-                .saturating_add(len.get())
-                .min(self.editor.buffer.len());
-        if range.is_empty() || !self.editor.buffer.is_char_boundary(range.end) {
-            return;
-        }
-        self.editor.buffer.replace_range(range.clone(), "");
-        self.editor.update_compose_for_replaced_range(range, 0);
-        self.update_layout();
+        self.editor
+            .edit_selections(self.font_cx, self.layout_cx, "", |editor, selection| {
+                let end = selection.text_range().end;
+                let range = end..end.saturating_add(len.get()).min(editor.buffer.len());
+                if range.is_empty() || !editor.buffer.is_char_boundary(range.end) {
+                    return None;
+                }
+                Some((range, None))
+            });
     }
 
-    /// Delete the selection or the next cluster (typical ‘delete’ behavior).
+    /// Delete each selection or the next cluster (typical ‘delete’ behavior).
     pub fn delete(&mut self) {
-        if self.editor.selection.is_collapsed() {
-            // Upstream cluster range
-            if let Some(range) = self
-                .editor
-                .selection
-                .focus()
-                .logical_clusters(&self.editor.layout)[1]
-                .as_ref()
-                .map(|cluster| cluster.text_range())
-                .and_then(|range| (!range.is_empty()).then_some(range))
-            {
-                self.editor.buffer.replace_range(range.clone(), "");
-                self.editor.update_compose_for_replaced_range(range, 0);
-                self.update_layout();
-            }
-        } else {
-            self.delete_selection();
-        }
+        self.editor
+            .edit_selections(self.font_cx, self.layout_cx, "", |editor, selection| {
+                if !selection.is_collapsed() {
+                    return Some((selection.text_range(), Some(Affinity::Upstream)));
+                }
+                // Upstream cluster range
+                selection.focus().logical_clusters(&editor.layout)[1]
+                    .as_ref()
+                    .map(|cluster| cluster.text_range())
+                    .and_then(|range| (!range.is_empty()).then_some((range, None)))
+            });
     }
 
-    /// Delete the selection or up to the next word boundary (typical ‘ctrl + delete’ behavior).
+    /// Delete each selection or up to the next word boundary (typical ‘ctrl + delete’ behavior).
     pub fn delete_word(&mut self) {
-        if self.editor.selection.is_collapsed() {
-            let focus = self.editor.selection.focus();
-            let start = focus.index();
-            let end = focus.next_logical_word(&self.editor.layout).index();
-            if self.editor.buffer.get(start..end).is_some() {
-                self.editor.buffer.replace_range(start..end, "");
-                self.editor.update_compose_for_replaced_range(start..end, 0);
-                self.update_layout();
-                self.editor.set_selection(
-                    Cursor::from_byte_index(&self.editor.layout, start, Affinity::Downstream)
-                        .into(),
-                );
-            }
-        } else {
-            self.delete_selection();
-        }
+        self.editor
+            .edit_selections(self.font_cx, self.layout_cx, "", |editor, selection| {
+                if !selection.is_collapsed() {
+                    return Some((selection.text_range(), Some(Affinity::Upstream)));
+                }
+                let focus = selection.focus();
+                let start = focus.index();
+                let end = focus.next_logical_word(&editor.layout).index();
+                editor
+                    .buffer
+                    .get(start..end)
+                    .map(|_| (start..end, Some(Affinity::Downstream)))
+            });
     }
 
-    /// Delete the selection or the previous cluster (typical ‘backspace’ behavior).
+    /// Delete each selection or the previous cluster (typical ‘backspace’ behavior).
     pub fn backdelete(&mut self) {
-        if self.editor.selection.is_collapsed() {
-            // Upstream cluster
-            if let Some(cluster) = self
-                .editor
-                .selection
-                .focus()
-                .logical_clusters(&self.editor.layout)[0]
-                .clone()
-            {
+        self.editor
+            .edit_selections(self.font_cx, self.layout_cx, "", |editor, selection| {
+                if !selection.is_collapsed() {
+                    return Some((selection.text_range(), Some(Affinity::Upstream)));
+                }
+                // Upstream cluster
+                let cluster = selection.focus().logical_clusters(&editor.layout)[0].clone()?;
                 let range = cluster.text_range();
                 let end = range.end;
                 let start = if cluster.is_hard_line_break() || cluster.is_emoji() {
@@ -300,47 +306,31 @@ where
                     range.start
                 } else {
                     // Otherwise, delete the previous character
-                    let Some((start, _)) = self
-                        .editor
+                    let (start, _) = editor
                         .buffer
                         .get(..end)
-                        .and_then(|str| str.char_indices().next_back())
-                    else {
-                        return;
-                    };
+                        .and_then(|str| str.char_indices().next_back())?;
                     start
                 };
-                self.editor.buffer.replace_range(start..end, "");
-                self.editor.update_compose_for_replaced_range(start..end, 0);
-                self.update_layout();
-                self.editor.set_selection(
-                    Cursor::from_byte_index(&self.editor.layout, start, Affinity::Downstream)
-                        .into(),
-                );
-            }
-        } else {
-            self.delete_selection();
-        }
+                Some((start..end, Some(Affinity::Downstream)))
+            });
     }
 
-    /// Delete the selection or back to the previous word boundary (typical ‘ctrl + backspace’ behavior).
+    /// Delete each selection or back to the previous word boundary (typical ‘ctrl + backspace’ behavior).
     pub fn backdelete_word(&mut self) {
-        if self.editor.selection.is_collapsed() {
-            let focus = self.editor.selection.focus();
-            let end = focus.index();
-            let start = focus.previous_logical_word(&self.editor.layout).index();
-            if self.editor.buffer.get(start..end).is_some() {
-                self.editor.buffer.replace_range(start..end, "");
-                self.editor.update_compose_for_replaced_range(start..end, 0);
-                self.update_layout();
-                self.editor.set_selection(
-                    Cursor::from_byte_index(&self.editor.layout, start, Affinity::Downstream)
-                        .into(),
-                );
-            }
-        } else {
-            self.delete_selection();
-        }
+        self.editor
+            .edit_selections(self.font_cx, self.layout_cx, "", |editor, selection| {
+                if !selection.is_collapsed() {
+                    return Some((selection.text_range(), Some(Affinity::Upstream)));
+                }
+                let focus = selection.focus();
+                let end = focus.index();
+                let start = focus.previous_logical_word(&editor.layout).index();
+                editor
+                    .buffer
+                    .get(start..end)
+                    .map(|_| (start..end, Some(Affinity::Downstream)))
+            });
     }
 
     // --- MARK: IME ---
@@ -352,7 +342,8 @@ where
     /// The selection and preedit region can be manipulated independently while composing
     /// is active.
     ///
-    /// The preedit text replaces the current selection if this call starts composing.
+    /// The preedit text replaces the primary selection if this call starts composing, and the
+    /// other selections are removed.
     ///
     /// The selection is updated based on `cursor`, which contains the byte offsets relative to the
     /// start of the preedit text. If `cursor` is `None`, the selection and caret are hidden.
@@ -360,22 +351,14 @@ where
         debug_assert!(!text.is_empty());
         debug_assert!(cursor.map(|cursor| cursor.1 <= text.len()).unwrap_or(true));
 
-        let start = if let Some(preedit_range) = &self.editor.compose {
+        let start = if let Some(preedit_range) = self.editor.compose.clone() {
             self.editor
-                .buffer
-                .replace_range(preedit_range.clone(), text);
+                .replace_buffer_range(preedit_range.clone(), text);
             preedit_range.start
         } else {
-            if self.editor.selection.is_collapsed() {
-                self.editor
-                    .buffer
-                    .insert_str(self.editor.selection.text_range().start, text);
-            } else {
-                self.editor
-                    .buffer
-                    .replace_range(self.editor.selection.text_range(), text);
-            }
-            self.editor.selection.text_range().start
+            let range = self.editor.raw_selection().text_range();
+            self.editor.replace_buffer_range(range.clone(), text);
+            range.start
         };
         self.editor.compose = Some(start..start + text.len());
         self.editor.show_cursor = cursor.is_some();
@@ -402,18 +385,50 @@ where
         }
     }
 
+    /// Start IME reconversion of the text in a range of byte indices.
+    ///
+    /// This starts composing with the existing text in `start..end` as the preedit text, without
+    /// deleting it, and selects it. The IME can then offer candidates for the text, and replace
+    /// it by calling [`set_compose`](Self::set_compose). If composing is reset by calling
+    /// [`clear_compose`](Self::clear_compose), the original text is restored.
+    ///
+    /// No-op if the editor is already composing, if the range is empty or out of bounds, or if
+    /// either index is not a char boundary.
+    pub fn reconvert(&mut self, start: usize, end: usize) {
+        let Some(text) = self.editor.buffer.get(start..end) else {
+            return;
+        };
+        if self.editor.is_composing() || text.is_empty() {
+            return;
+        }
+        self.editor.reconversion = Some(text.to_owned());
+        self.editor.compose = Some(start..end);
+        self.update_layout();
+        self.editor.set_selection(Selection::new(
+            self.editor.cursor_at(start),
+            self.editor.cursor_at(end),
+        ));
+    }
+
     /// Stop IME composing.
     ///
     /// This removes the IME preedit text, shows the cursor if it was hidden,
     /// and moves the cursor to the start of the former preedit region.
+    ///
+    /// If composing started with [`reconvert`](Self::reconvert), the original text is restored
+    /// instead, and selected.
     pub fn clear_compose(&mut self) {
         if let Some(preedit_range) = self.editor.compose.take() {
-            self.editor.buffer.replace_range(preedit_range.clone(), "");
+            let original = self.editor.reconversion.take().unwrap_or_default();
+            self.editor
+                .replace_buffer_range(preedit_range.clone(), &original);
             self.editor.show_cursor = true;
             self.update_layout();
 
-            self.editor
-                .set_selection(self.editor.cursor_at(preedit_range.start).into());
+            self.editor.set_selection(Selection::new(
+                self.editor.cursor_at(preedit_range.start),
+                self.editor.cursor_at(preedit_range.start + original.len()),
+            ));
         }
     }
 
@@ -422,6 +437,7 @@ where
     /// This doesn't change the selection, but shows the cursor if
     /// it was hidden.
     pub fn finish_compose(&mut self) {
+        self.editor.reconversion = None;
         if self.editor.compose.take().is_some() {
             self.editor.show_cursor = true;
             self.update_layout();
@@ -450,112 +466,85 @@ where
     /// Move the cursor to the start of the buffer.
     pub fn move_to_text_start(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(self.editor.selection.move_lines(
-            &self.editor.layout,
-            isize::MIN,
-            false,
-        ));
+        self.editor
+            .map_selections(|selection, layout| selection.move_lines(layout, isize::MIN, false));
     }
 
     /// Move the cursor to just after the previous hard line break (such as `\n`).
     pub fn move_to_hard_line_start(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .hard_line_start(&self.editor.layout, false),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.hard_line_start(layout, false));
     }
 
     /// Move the cursor to the start of the physical line.
     pub fn move_to_line_start(&mut self) {
         self.refresh_layout();
         self.editor
-            .set_selection(self.editor.selection.line_start(&self.editor.layout, false));
+            .map_selections(|selection, layout| selection.line_start(layout, false));
     }
 
     /// Move the cursor to the end of the buffer.
     pub fn move_to_text_end(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(self.editor.selection.move_lines(
-            &self.editor.layout,
-            isize::MAX,
-            false,
-        ));
+        self.editor
+            .map_selections(|selection, layout| selection.move_lines(layout, isize::MAX, false));
     }
 
     /// Move the cursor to just before the next hard line break (such as `\n`).
     pub fn move_to_hard_line_end(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .hard_line_end(&self.editor.layout, false),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.hard_line_end(layout, false));
     }
 
     /// Move the cursor to the end of the physical line.
     pub fn move_to_line_end(&mut self) {
         self.refresh_layout();
         self.editor
-            .set_selection(self.editor.selection.line_end(&self.editor.layout, false));
+            .map_selections(|selection, layout| selection.line_end(layout, false));
     }
 
     /// Move up to the closest physical cluster boundary on the previous line, preserving the horizontal position for repeated movements.
     pub fn move_up(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .previous_line(&self.editor.layout, false),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.previous_line(layout, false));
     }
 
     /// Move down to the closest physical cluster boundary on the next line, preserving the horizontal position for repeated movements.
     pub fn move_down(&mut self) {
         self.refresh_layout();
         self.editor
-            .set_selection(self.editor.selection.next_line(&self.editor.layout, false));
+            .map_selections(|selection, layout| selection.next_line(layout, false));
     }
 
     /// Move to the next cluster left in visual order.
     pub fn move_left(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .previous_visual(&self.editor.layout, false),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.previous_visual(layout, false));
     }
 
     /// Move to the next cluster right in visual order.
     pub fn move_right(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .next_visual(&self.editor.layout, false),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.next_visual(layout, false));
     }
 
     /// Move to the next word boundary left.
     pub fn move_word_left(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .previous_visual_word(&self.editor.layout, false),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.previous_visual_word(layout, false));
     }
 
     /// Move to the next word boundary right.
     pub fn move_word_right(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .next_visual_word(&self.editor.layout, false),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.next_visual_word(layout, false));
     }
 
     /// Select the whole buffer.
@@ -569,115 +558,92 @@ where
 
     /// Collapse selection into caret.
     pub fn collapse_selection(&mut self) {
-        self.editor.set_selection(self.editor.selection.collapse());
+        self.editor
+            .map_selections(|selection, _| selection.collapse());
     }
 
     /// Move the selection focus point to the start of the buffer.
     pub fn select_to_text_start(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(self.editor.selection.move_lines(
-            &self.editor.layout,
-            isize::MIN,
-            true,
-        ));
+        self.editor
+            .map_selections(|selection, layout| selection.move_lines(layout, isize::MIN, true));
     }
 
     /// Move the selection focus point to just after the previous hard line break (such as `\n`).
     pub fn select_to_hard_line_start(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .hard_line_start(&self.editor.layout, true),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.hard_line_start(layout, true));
     }
 
     /// Move the selection focus point to the start of the physical line.
     pub fn select_to_line_start(&mut self) {
         self.refresh_layout();
         self.editor
-            .set_selection(self.editor.selection.line_start(&self.editor.layout, true));
+            .map_selections(|selection, layout| selection.line_start(layout, true));
     }
 
     /// Move the selection focus point to the end of the buffer.
     pub fn select_to_text_end(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(self.editor.selection.move_lines(
-            &self.editor.layout,
-            isize::MAX,
-            true,
-        ));
+        self.editor
+            .map_selections(|selection, layout| selection.move_lines(layout, isize::MAX, true));
     }
 
     /// Move the selection focus point to just before the next hard line break (such as `\n`).
     pub fn select_to_hard_line_end(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .hard_line_end(&self.editor.layout, true),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.hard_line_end(layout, true));
     }
 
     /// Move the selection focus point to the end of the physical line.
     pub fn select_to_line_end(&mut self) {
         self.refresh_layout();
         self.editor
-            .set_selection(self.editor.selection.line_end(&self.editor.layout, true));
+            .map_selections(|selection, layout| selection.line_end(layout, true));
     }
 
     /// Move the selection focus point up to the nearest cluster boundary on the previous line, preserving the horizontal position for repeated movements.
     pub fn select_up(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .previous_line(&self.editor.layout, true),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.previous_line(layout, true));
     }
 
     /// Move the selection focus point down to the nearest cluster boundary on the next line, preserving the horizontal position for repeated movements.
     pub fn select_down(&mut self) {
         self.refresh_layout();
         self.editor
-            .set_selection(self.editor.selection.next_line(&self.editor.layout, true));
+            .map_selections(|selection, layout| selection.next_line(layout, true));
     }
 
     /// Move the selection focus point to the next cluster left in visual order.
     pub fn select_left(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .previous_visual(&self.editor.layout, true),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.previous_visual(layout, true));
     }
 
     /// Move the selection focus point to the next cluster right in visual order.
     pub fn select_right(&mut self) {
         self.refresh_layout();
         self.editor
-            .set_selection(self.editor.selection.next_visual(&self.editor.layout, true));
+            .map_selections(|selection, layout| selection.next_visual(layout, true));
     }
 
     /// Move the selection focus point to the next word boundary left.
     pub fn select_word_left(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .previous_visual_word(&self.editor.layout, true),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.previous_visual_word(layout, true));
     }
 
     /// Move the selection focus point to the next word boundary right.
     pub fn select_word_right(&mut self) {
         self.refresh_layout();
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .next_visual_word(&self.editor.layout, true),
-        );
+        self.editor
+            .map_selections(|selection, layout| selection.next_visual_word(layout, true));
     }
 
     /// Select the word at the point.
@@ -707,39 +673,43 @@ where
         self.editor.set_selection(hard_line);
     }
 
-    /// Move the selection focus point to the cluster boundary closest to point.
+    /// Move the focus point of the primary selection to the cluster boundary closest to point.
     ///
     /// If the initial selection was created from a word or line, then the new
     /// selection will be extended at the same granularity.
     pub fn extend_selection_to_point(&mut self, x: f32, y: f32) {
         self.refresh_layout();
         // FIXME: This is usually the wrong way to handle selection extension for mouse moves, but not a regression.
-        self.editor.set_selection(
-            self.editor
-                .selection
-                .extend_to_point(&self.editor.layout, x, y),
-        );
+        self.editor
+            .set_primary_selection(self.editor.raw_selection().extend_to_point(
+                &self.editor.layout,
+                x,
+                y,
+            ));
     }
 
-    /// Move the selection focus point to the cluster boundary closest to point.
+    /// Move the focus point of the primary selection to the cluster boundary closest to point.
     pub fn shift_click_extension(&mut self, x: f32, y: f32) {
         self.refresh_layout();
         self.editor
-            .set_selection(
-                self.editor
-                    .selection
-                    .shift_click_extension(&self.editor.layout, x, y),
-            );
+            .set_primary_selection(self.editor.raw_selection().shift_click_extension(
+                &self.editor.layout,
+                x,
+                y,
+            ));
     }
 
-    /// Move the selection focus point to a byte index.
+    /// Move the focus point of the primary selection to a byte index.
     ///
     /// No-op if index is not a char boundary.
     pub fn extend_selection_to_byte(&mut self, index: usize) {
         if self.editor.buffer.is_char_boundary(index) {
             self.refresh_layout();
-            self.editor
-                .set_selection(self.editor.selection.extend(self.editor.cursor_at(index)));
+            self.editor.set_primary_selection(
+                self.editor
+                    .raw_selection()
+                    .extend(self.editor.cursor_at(index)),
+            );
         }
     }
 
@@ -769,6 +739,105 @@ where
         }
     }
 
+    // --- MARK: Multiple Selections ---
+    /// Add a caret at the cluster boundary nearest this point in the layout, as the primary
+    /// selection.
+    pub fn add_cursor_at_point(&mut self, x: f32, y: f32) {
+        self.refresh_layout();
+        self.editor
+            .add_selection(Selection::from_point(&self.editor.layout, x, y));
+    }
+
+    /// Add a selection of a range of byte indices, as the primary selection.
+    ///
+    /// No-op if either index is not a char boundary.
+    pub fn add_selection_byte_range(&mut self, start: usize, end: usize) {
+        if self.editor.buffer.is_char_boundary(start) && self.editor.buffer.is_char_boundary(end) {
+            self.refresh_layout();
+            self.editor.add_selection(Selection::new(
+                self.editor.cursor_at(start),
+                self.editor.cursor_at(end),
+            ));
+        }
+    }
+
+    /// Add a caret on the previous line, at the horizontal position of the primary selection's
+    /// focus, as the primary selection.
+    ///
+    /// Repeating this creates a column of carets.
+    pub fn add_cursor_above(&mut self) {
+        self.refresh_layout();
+        self.editor.add_selection(
+            self.editor
+                .raw_selection()
+                .previous_line(&self.editor.layout, false),
+        );
+    }
+
+    /// Add a caret on the next line, at the horizontal position of the primary selection's
+    /// focus, as the primary selection.
+    ///
+    /// Repeating this creates a column of carets.
+    pub fn add_cursor_below(&mut self) {
+        self.refresh_layout();
+        self.editor.add_selection(
+            self.editor
+                .raw_selection()
+                .next_line(&self.editor.layout, false),
+        );
+    }
+
+    /// Add a selection of the next occurrence of the text of the primary selection, as the primary
+    /// selection (typical ‘ctrl + d’ behavior).
+    ///
+    /// The search wraps around to the start of the buffer, and skips occurrences which are already
+    /// selected. If the primary selection is collapsed, the word at the caret is selected instead.
+    pub fn select_next_occurrence(&mut self) {
+        self.refresh_layout();
+        let primary = *self.editor.raw_selection();
+        if primary.is_collapsed() {
+            let caret = primary.focus().geometry(&self.editor.layout, 0.);
+            let word = Selection::word_from_point(
+                &self.editor.layout,
+                caret.x0 as f32,
+                ((caret.y0 + caret.y1) / 2.) as f32,
+            );
+            self.editor.set_primary_selection(word);
+            return;
+        }
+        let range = primary.text_range();
+        let buffer = &self.editor.buffer;
+        let needle = &buffer[range.clone()];
+        let is_selected = |start: usize| {
+            self.editor
+                .selections
+                .iter()
+                .any(|selection| selection.text_range() == (start..start + needle.len()))
+        };
+        let next = buffer[range.end..]
+            .match_indices(needle)
+            .map(|(start, _)| range.end + start)
+            .chain(
+                buffer[..range.start]
+                    .match_indices(needle)
+                    .map(|(start, _)| start),
+            )
+            .find(|start| !is_selected(*start));
+        if let Some(start) = next {
+            let end = start + needle.len();
+            self.editor.add_selection(Selection::new(
+                self.editor.cursor_at(start),
+                self.editor.cursor_at(end),
+            ));
+        }
+    }
+
+    /// Remove all selections except the primary selection.
+    pub fn clear_secondary_selections(&mut self) {
+        let primary = *self.editor.raw_selection();
+        self.editor.set_selections(vec![primary], 0);
+    }
+
     // --- MARK: Rendering ---
     #[cfg(feature = "accesskit")]
     /// Perform an accessibility update.
@@ -823,12 +892,29 @@ where
         }
     }
 
-    /// Borrow the current selection. The indices returned by functions
+    /// Borrow the primary selection. The indices returned by functions
     /// such as [`Selection::text_range`] refer to the raw text buffer,
     /// including the IME preedit region, which can be accessed via
     /// [`PlainEditor::raw_text`].
+    ///
+    /// The primary selection is the one used for IME, accessibility and the
+    /// methods which work on a single selection, such as [`selected_text`](Self::selected_text).
     pub fn raw_selection(&self) -> &Selection {
-        &self.selection
+        &self.selections[self.primary]
+    }
+
+    /// Borrow all selections, sorted by position and non-overlapping. Like
+    /// [`raw_selection`](Self::raw_selection), their indices refer to the raw text buffer.
+    ///
+    /// There is always at least one selection. Use [`primary_selection_index`](Self::primary_selection_index)
+    /// to find the primary selection.
+    pub fn raw_selections(&self) -> &[Selection] {
+        &self.selections
+    }
+
+    /// Get the index of the primary selection in [`raw_selections`](Self::raw_selections).
+    pub fn primary_selection_index(&self) -> usize {
+        self.primary
     }
 
     /// Borrow the current IME preedit range, if any. These indices refer
@@ -837,42 +923,108 @@ where
         &self.compose
     }
 
-    /// If the current selection is not collapsed, returns the text content of
+    /// If the primary selection is not collapsed, returns the text content of
     /// that selection.
     pub fn selected_text(&self) -> Option<&str> {
         if self.is_composing() {
             return None;
         }
-        if !self.selection.is_collapsed() {
-            self.buffer.get(self.selection.text_range())
+        if !self.raw_selection().is_collapsed() {
+            self.buffer.get(self.raw_selection().text_range())
         } else {
             None
         }
     }
 
-    /// Get rectangles, and their corresponding line indices, representing the selected portions of
+    /// If the primary selection is not collapsed, returns the styled fragments of that
+    /// selection, in logical order.
+    ///
+    /// This is useful for producing rich clipboard contents. See [`Selection::fragments`].
+    pub fn selected_fragments(&self) -> Option<Vec<SelectionFragment<'_, T>>> {
+        if self.is_composing() || self.raw_selection().is_collapsed() {
+            return None;
+        }
+        Some(self.raw_selection().fragments(&self.layout, &self.buffer))
+    }
+
+    /// Returns the text around the primary selection, for platform IMEs.
+    ///
+    /// The text extends up to `max_before` bytes before the start of the selection and up to
+    /// `max_after` bytes after its end, shortened to char boundaries. It always includes the
+    /// whole selection.
+    ///
+    /// Returns `None` while composing, as the IME preedit text isn't part of the surrounding
     /// text.
+    pub fn surrounding_text(
+        &self,
+        max_before: usize,
+        max_after: usize,
+    ) -> Option<SurroundingText<'_>> {
+        if self.is_composing() {
+            return None;
+        }
+        let range = self.raw_selection().text_range();
+        let mut start = range.start.saturating_sub(max_before);
+        while !self.buffer.is_char_boundary(start) {
+            start += 1;
+        }
+        let mut end = range.end.saturating_add(max_after).min(self.buffer.len());
+        while !self.buffer.is_char_boundary(end) {
+            end -= 1;
+        }
+        let text = &self.buffer[start..end];
+        let anchor = self.raw_selection().anchor().index() - start;
+        let focus = self.raw_selection().focus().index() - start;
+        let utf16_len = |text: &str| text.chars().map(char::len_utf16).sum();
+        Some(SurroundingText {
+            text,
+            offset: start,
+            anchor,
+            focus,
+            anchor_utf16: utf16_len(&text[..anchor]),
+            focus_utf16: utf16_len(&text[..focus]),
+        })
+    }
+
+    /// Get rectangles, and their corresponding line indices, representing the selected portions of
+    /// text, for all selections.
     pub fn selection_geometry(&self) -> Vec<(BoundingBox, usize)> {
-        // We do not check `self.show_cursor` here, as the IME handling code collapses the
-        // selection to a caret in that case.
-        self.selection.geometry(&self.layout)
+        let mut rects = Vec::new();
+        self.selection_geometry_with(|rect, line_idx| rects.push((rect, line_idx)));
+        rects
     }
 
     /// Invoke a callback with each rectangle representing the selected portions of text, and the
-    /// indices of the lines to which they belong.
-    pub fn selection_geometry_with(&self, f: impl FnMut(BoundingBox, usize)) {
+    /// indices of the lines to which they belong, for all selections.
+    pub fn selection_geometry_with(&self, mut f: impl FnMut(BoundingBox, usize)) {
         // We do not check `self.show_cursor` here, as the IME handling code collapses the
         // selection to a caret in that case.
-        self.selection.geometry_with(&self.layout, f);
+        for selection in &self.selections {
+            selection.geometry_with(&self.layout, &mut f);
+        }
     }
 
-    /// Get a rectangle representing the current caret cursor position.
+    /// Get a rectangle representing the caret cursor position of the primary selection.
     ///
     /// There is not always a caret. For example, the IME may have indicated the caret should be
     /// hidden.
     pub fn cursor_geometry(&self, size: f32) -> Option<BoundingBox> {
         self.show_cursor
-            .then(|| self.selection.focus().geometry(&self.layout, size))
+            .then(|| self.raw_selection().focus().geometry(&self.layout, size))
+    }
+
+    /// Get rectangles representing the caret cursor positions of all selections, in the order of
+    /// [`raw_selections`](Self::raw_selections).
+    ///
+    /// This is empty if the caret should be hidden, as for [`cursor_geometry`](Self::cursor_geometry).
+    pub fn all_cursor_geometry(&self, size: f32) -> Vec<BoundingBox> {
+        if !self.show_cursor {
+            return Vec::new();
+        }
+        self.selections
+            .iter()
+            .map(|selection| selection.focus().geometry(&self.layout, size))
+            .collect()
     }
 
     /// Get a rectangle bounding the text the user is currently editing.
@@ -900,15 +1052,15 @@ where
             )
         } else {
             // Bound the selected parts of the focused line only.
-            let focus = self.selection.focus().geometry(&self.layout, 0.);
+            let focus = self.raw_selection().focus().geometry(&self.layout, 0.);
             let mut area = focus;
-            self.selection.geometry_with(&self.layout, |rect, _| {
+            self.raw_selection().geometry_with(&self.layout, |rect, _| {
                 if rect.y0 == focus.y0 {
                     area = area.union(rect);
                 }
             });
 
-            (area, self.selection.focus())
+            (area, self.raw_selection().focus())
         };
 
         // Ensure some context is captured even for tiny or collapsed selections by including a
@@ -955,6 +1107,15 @@ where
         &self.buffer
     }
 
+    /// Get the index for converting between byte, UTF-16 and char offsets in the raw text
+    /// buffer, which includes any preedit text.
+    ///
+    /// The index is kept up to date as the text is edited. Its conversions take the text
+    /// returned by [`raw_text`](Self::raw_text).
+    pub fn text_index(&self) -> &TextIndex {
+        &self.text_index
+    }
+
     /// Get the current `Generation` of the layout, to decide whether to draw.
     ///
     /// You should store the generation the editor was at when you last drew it, and then redraw
@@ -967,8 +1128,10 @@ where
     pub fn set_text(&mut self, is: &str) {
         self.buffer.clear();
         self.buffer.push_str(is);
+        self.text_index.rebuild(&self.buffer);
         self.layout_dirty = true;
         self.compose = None;
+        self.reconversion = None;
     }
 
     /// Set the width of the layout.
@@ -1101,6 +1264,13 @@ where
         }
     }
 
+    /// Replace the text in `range` of the buffer, updating the text index.
+    fn replace_buffer_range(&mut self, range: Range<usize>, text: &str) {
+        self.buffer.replace_range(range.clone(), text);
+        self.text_index
+            .replace_range(&self.buffer, range, text.len());
+    }
+
     fn update_compose_for_replaced_range(&mut self, old_range: Range<usize>, new_len: usize) {
         if new_len == old_range.len() {
             return;
@@ -1124,6 +1294,7 @@ where
         if new_len < old_range.len() {
             if compose.start >= (old_range.start + new_len) {
                 self.compose = None;
+                self.reconversion = None;
                 return;
             }
             compose.end = compose.end.min(old_range.start + new_len);
@@ -1136,32 +1307,146 @@ where
         layout_cx: &mut LayoutContext<T>,
         s: &str,
     ) {
-        let range = self.selection.text_range();
-        let start = range.start;
-        if self.selection.is_collapsed() {
-            self.buffer.insert_str(start, s);
-        } else {
-            self.buffer.replace_range(range.clone(), s);
-        }
-        self.update_compose_for_replaced_range(range, s.len());
-
-        self.update_layout(font_cx, layout_cx);
-        let new_index = start.saturating_add(s.len());
         let affinity = if s.ends_with("\n") {
             Affinity::Downstream
         } else {
             Affinity::Upstream
         };
-        self.set_selection(Cursor::from_byte_index(&self.layout, new_index, affinity).into());
+        self.edit_selections(font_cx, layout_cx, s, |_, selection| {
+            Some((selection.text_range(), Some(affinity)))
+        });
     }
 
-    /// Update the selection, and nudge the `Generation` if something other than `h_pos` changed.
-    fn set_selection(&mut self, new_sel: Selection) {
-        if new_sel.focus() != self.selection.focus() || new_sel.anchor() != self.selection.anchor()
-        {
+    /// Replace a range of the text with `text` for each selection, and update the selections.
+    ///
+    /// For each selection, `edit` returns the range to replace, and the affinity of a caret to
+    /// place after the replacement, or `None` to keep the selection where it is. It returns `None`
+    /// instead of a range if nothing should be replaced for that selection. Ranges that overlap
+    /// the range of a previous selection are clamped to its end.
+    fn edit_selections(
+        &mut self,
+        font_cx: &mut FontContext,
+        layout_cx: &mut LayoutContext<T>,
+        text: &str,
+        mut edit: impl FnMut(&Self, &Selection) -> Option<(Range<usize>, Option<Affinity>)>,
+    ) {
+        let mut edits: Vec<_> = self
+            .selections
+            .iter()
+            .map(|selection| edit(self, selection))
+            .collect();
+        if edits.iter().all(Option::is_none) {
+            return;
+        }
+        // The old and new ranges of each replacement, in order.
+        let mut replaced = Vec::new();
+        let (mut prev_end, mut added, mut removed) = (0, 0, 0);
+        for (range, _) in edits.iter_mut().flatten() {
+            range.start = range.start.max(prev_end);
+            range.end = range.end.max(range.start);
+            prev_end = range.end;
+            let start = range.start + added - removed;
+            replaced.push((range.clone(), start..start + text.len()));
+            added += text.len();
+            removed += range.len();
+        }
+        // Replace from the end, so that the ranges still to be replaced stay valid.
+        for (range, _) in replaced.iter().rev() {
+            self.replace_buffer_range(range.clone(), text);
+            self.update_compose_for_replaced_range(range.clone(), text.len());
+        }
+
+        self.update_layout(font_cx, layout_cx);
+        let mut new_ranges = replaced.iter().map(|(_, new)| new);
+        let mut selections = Vec::with_capacity(self.selections.len());
+        for (selection, edit) in self.selections.iter().zip(&edits) {
+            let new_range = edit.as_ref().and_then(|_| new_ranges.next());
+            selections.push(match (edit, new_range) {
+                (Some((_, Some(affinity))), Some(new_range)) => {
+                    Cursor::from_byte_index(&self.layout, new_range.end, *affinity).into()
+                }
+                _ => {
+                    let map_cursor = |cursor: Cursor| {
+                        let index = map_index(&replaced, cursor.index());
+                        Cursor::from_byte_index(&self.layout, index, cursor.affinity())
+                    };
+                    Selection::new(
+                        map_cursor(selection.anchor()),
+                        map_cursor(selection.focus()),
+                    )
+                }
+            });
+        }
+        self.set_selections(selections, self.primary);
+    }
+
+    /// Apply `f` to each selection, merging the selections which then overlap.
+    fn map_selections(&mut self, mut f: impl FnMut(&Selection, &Layout<T>) -> Selection) {
+        let selections = self
+            .selections
+            .iter()
+            .map(|selection| f(selection, &self.layout))
+            .collect();
+        self.set_selections(selections, self.primary);
+    }
+
+    /// Replace the primary selection, keeping the others.
+    fn set_primary_selection(&mut self, new_sel: Selection) {
+        let mut selections = self.selections.clone();
+        selections[self.primary] = new_sel;
+        self.set_selections(selections, self.primary);
+    }
+
+    /// Add a selection, which becomes the primary selection.
+    fn add_selection(&mut self, new_sel: Selection) {
+        let mut selections = self.selections.clone();
+        selections.push(new_sel);
+        let primary = selections.len() - 1;
+        self.set_selections(selections, primary);
+    }
+
+    /// Replace the selections, sorting them and merging those which overlap, and nudge the
+    /// `Generation` if something other than `h_pos` changed.
+    ///
+    /// Carets are merged with selections that start at the same index, but not with those which
+    /// end there.
+    fn set_selections(&mut self, selections: Vec<Selection>, primary: usize) {
+        let mut sorted: Vec<_> = selections
+            .into_iter()
+            .enumerate()
+            .map(|(index, selection)| (selection, index == primary))
+            .collect();
+        sorted.sort_by_key(|(selection, _)| {
+            let range = selection.text_range();
+            (range.start, range.end)
+        });
+        let mut merged: Vec<(Selection, bool)> = Vec::with_capacity(sorted.len());
+        for (selection, is_primary) in sorted {
+            if let Some((last, last_is_primary)) = merged.last_mut() {
+                let (last_range, range) = (last.text_range(), selection.text_range());
+                if range.start < last_range.end || range.start == last_range.start {
+                    *last = merge_selections(*last, selection, is_primary);
+                    *last_is_primary |= is_primary;
+                    continue;
+                }
+            }
+            merged.push((selection, is_primary));
+        }
+        let primary = merged
+            .iter()
+            .position(|(_, is_primary)| *is_primary)
+            .unwrap_or_default();
+        let selections: Vec<_> = merged.into_iter().map(|(selection, _)| selection).collect();
+        if selections != self.selections || primary != self.primary {
             self.generation.nudge();
         }
+        self.selections = selections;
+        self.primary = primary;
+    }
 
+    /// Replace all selections with `new_sel`, and nudge the `Generation` if something other than
+    /// `h_pos` changed.
+    fn set_selection(&mut self, new_sel: Selection) {
         // This debug code is quite useful when diagnosing selection problems.
         #[cfg(feature = "std")]
         #[allow(clippy::print_stderr)] // reason = "unreachable debug code"
@@ -1194,8 +1479,9 @@ where
             );
             eprintln!(" | visual: {dbg:?}");
         }
-        self.selection = new_sel;
+        self.set_selections(vec![new_sel], 0);
     }
+
     /// Update the layout.
     fn update_layout(&mut self, font_cx: &mut FontContext, layout_cx: &mut LayoutContext<T>) {
         let mut builder =
@@ -1210,7 +1496,7 @@ where
         self.layout.break_all_lines(self.width);
         self.layout
             .align(self.width, self.alignment, AlignmentOptions::default());
-        self.selection = self.selection.refresh(&self.layout);
+        self.map_selections(|selection, layout| selection.refresh(layout));
         self.layout_dirty = false;
         self.generation.nudge();
     }
@@ -1242,7 +1528,7 @@ where
         );
         if self.show_cursor {
             if let Some(selection) = self
-                .selection
+                .raw_selection()
                 .to_access_selection(&self.layout, &self.layout_access)
             {
                 node.set_text_selection(selection);
@@ -1253,3 +1539,55 @@ where
         node.add_action(accesskit::Action::SetTextSelection);
     }
 }
+
+/// Maps a byte index in the text before a series of replacements to the text after them.
+///
+/// `replaced` holds the old and new ranges of the replacements, in order. Indices within a
+/// replaced range are clamped to the new range.
+fn map_index(replaced: &[(Range<usize>, Range<usize>)], index: usize) -> usize {
+    let mut mapped = index;
+    for (old, new) in replaced {
+        if old.end <= index {
+            mapped = mapped - old.len() + new.len();
+        } else if old.start < index {
+            return new.start + (index - old.start).min(new.len());
+        } else {
+            break;
+        }
+    }
+    mapped
+}
+
+/// Merges two overlapping selections into one covering both.
+///
+/// The result has the direction of `b` if `prefer_b` is `true`, or otherwise of `a`.
+fn merge_selections(a: Selection, b: Selection, prefer_b: bool) -> Selection {
+    let (preferred, other) = if prefer_b { (b, a) } else { (a, b) };
+    let (range, other_range) = (preferred.text_range(), other.text_range());
+    if range.start <= other_range.start && other_range.end <= range.end {
+        return preferred;
+    }
+    let ordered = |selection: Selection| {
+        if selection.anchor().index() <= selection.focus().index() {
+            [selection.anchor(), selection.focus()]
+        } else {
+            [selection.focus(), selection.anchor()]
+        }
+    };
+    let ([start, end], [other_start, other_end]) = (ordered(preferred), ordered(other));
+    let start = if other_start.index() < start.index() {
+        other_start
+    } else {
+        start
+    };
+    let end = if other_end.index() > end.index() {
+        other_end
+    } else {
+        end
+    };
+    if preferred.anchor().index() <= preferred.focus().index() {
+        Selection::new(start, end)
+    } else {
+        Selection::new(end, start)
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::FontData;
+use crate::InlineBox;
+use crate::layout::Style;
+use crate::style::Brush;
+
+use alloc::string::{String, ToString};
+use core::ops::Range;
+use fontique::Synthesis;
+
+/// A piece of the content of a [`Selection`](crate::Selection), as returned by
+/// [`Selection::fragments`](crate::Selection::fragments).
+///
+/// Fragments are useful for producing rich clipboard contents, such as HTML or RTF.
+#[derive(Clone, Debug)]
+pub enum SelectionFragment<'a, B: Brush> {
+    /// A run of text with a single style and font.
+    Text(TextFragment<'a, B>),
+    /// An inline box, which is a placeholder for content outside of the text.
+    InlineBox(&'a InlineBox),
+}
+
+/// A run of selected text with a single style and font.
+#[derive(Clone, Debug)]
+pub struct TextFragment<'a, B: Brush> {
+    /// The selected text.
+    pub text: &'a str,
+    /// The range of the selected text in the source text of the layout.
+    pub text_range: Range<usize>,
+    /// The resolved style of the text.
+    pub style: &'a Style<B>,
+    /// The font that the text was shaped with.
+    pub font: &'a FontData,
+    /// The font size.
+    pub font_size: f32,
+    /// The synthesis suggestions for the font.
+    pub synthesis: Synthesis,
+    /// Whether the text has right-to-left directionality.
+    pub is_rtl: bool,
+}
+
+impl<B: Brush> TextFragment<'_, B> {
+    /// Returns the family name of the font, read from its naming table.
+    ///
+    /// This prefers the typographic family name, which doesn't include style qualifiers
+    /// such as "Bold", if the font has one.
+    pub fn family_name(&self) -> Option<String> {
+        use skrifa::MetadataProvider as _;
+        use skrifa::string::StringId;
+        let font_ref =
+            skrifa::FontRef::from_index(self.font.data.as_ref(), self.font.index).ok()?;
+        [StringId::TYPOGRAPHIC_FAMILY_NAME, StringId::FAMILY_NAME]
+            .into_iter()
+            .find_map(|id| font_ref.localized_strings(id).english_or_first())
+            .map(|name| name.to_string())
+    }
+}
@@ -3,8 +3,10 @@
 
 mod cursor;
 mod editor;
+mod fragment;
 mod selection;
 
 pub use self::cursor::*;
 pub use self::editor::*;
+pub use self::fragment::*;
 pub use self::selection::*;
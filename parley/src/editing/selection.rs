@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::BoundingBox;
-use crate::editing::Cursor;
+use crate::editing::{Cursor, SelectionFragment, TextFragment};
 #[cfg(feature = "accesskit")]
 use crate::layout::LayoutAccessibility;
 use crate::layout::{Affinity, BreakReason, Cluster, Layout, LineItem};
@@ -608,6 +608,82 @@ impl Selection {
         }
     }
 
+    /// Returns the styled fragments of the selected content of the given layout, in logical
+    /// order.
+    ///
+    /// Each [text fragment](TextFragment) is a maximal run of selected text within a line that
+    /// has a single style and font, and selected inline boxes are included as placeholders.
+    /// Fragments are in the order of the source `text` rather than the visual order of
+    /// bidirectional text, so concatenating their text gives the selected text. Use
+    /// [`TextFragment::is_rtl`] to recover the direction of each fragment.
+    ///
+    /// `text` must be the source text of the layout.
+    pub fn fragments<'a, B: Brush>(
+        &self,
+        layout: &'a Layout<B>,
+        text: &'a str,
+    ) -> Vec<SelectionFragment<'a, B>> {
+        let mut fragments = Vec::new();
+        if self.is_collapsed() {
+            return fragments;
+        }
+        let range = self.text_range();
+        for line in layout.lines() {
+            let line_range = line.text_range();
+            if line_range.start >= range.end || line_range.end <= range.start {
+                continue;
+            }
+            for run in line.runs() {
+                let mut current: Option<TextFragment<'a, B>> = None;
+                for cluster in (0..run.len()).filter_map(|index| run.get(index)) {
+                    let cluster_range = cluster.text_range();
+                    let start = cluster_range.start.max(range.start);
+                    let end = cluster_range.end.min(range.end);
+                    if start >= end {
+                        continue;
+                    }
+                    let style = &layout.styles()[usize::from(cluster.data.style_index)];
+                    if let Some(fragment) = current.as_mut().filter(|fragment| {
+                        core::ptr::eq(fragment.style, style) && fragment.text_range.end == start
+                    }) {
+                        fragment.text_range.end = end;
+                        continue;
+                    }
+                    let fragment = TextFragment {
+                        text: "",
+                        text_range: start..end,
+                        style,
+                        font: &layout.data.fonts[run.data.font_index],
+                        font_size: run.font_size(),
+                        synthesis: run.synthesis(),
+                        is_rtl: run.is_rtl(),
+                    };
+                    fragments.extend(current.replace(fragment).map(SelectionFragment::Text));
+                }
+                fragments.extend(current.map(SelectionFragment::Text));
+            }
+        }
+        fragments.extend(
+            layout
+                .inline_boxes()
+                .iter()
+                .filter(|inline_box| range.contains(&inline_box.index))
+                .map(SelectionFragment::InlineBox),
+        );
+        // Runs are in visual order within each line, so restore the logical order. An inline box
+        // comes before any text at its index.
+        fragments.sort_by_key(|fragment| match fragment {
+            SelectionFragment::Text(fragment) => (fragment.text_range.start, 1),
+            SelectionFragment::InlineBox(inline_box) => (inline_box.index, 0),
+        });
+        for fragment in &mut fragments {
+            if let SelectionFragment::Text(fragment) = fragment {
+                fragment.text = text.get(fragment.text_range.clone()).unwrap_or_default();
+            }
+        }
+        fragments
+    }
+
     pub(crate) fn maybe_extend(&self, focus: Cursor, extend: bool) -> Self {
         if extend {
             Self::new(self.anchor, focus)
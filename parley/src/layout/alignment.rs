@@ -7,6 +7,7 @@ use super::{
 };
 use crate::data::LayoutData;
 use crate::style::Brush;
+use icu_properties::props::Script;
 
 /// Alignment of a layout.
 #[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
@@ -33,6 +34,27 @@ pub enum Alignment {
     Justify,
 }
 
+/// Determines where space is added when a line is justified with [`Alignment::Justify`].
+///
+/// Corresponds to the CSS [`text-justify`] property. Unlike in CSS, the default is
+/// [`TextJustify::InterWord`], which only stretches spaces.
+///
+/// [`text-justify`]: https://drafts.csswg.org/css-text-3/#text-justify-property
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum TextJustify {
+    /// Space is added between words, between the characters of scripts that do not separate
+    /// words with spaces (such as Chinese, Japanese and Thai), and by inserting kashidas in
+    /// Arabic text.
+    Auto,
+    /// Space is only added between words.
+    #[default]
+    InterWord,
+    /// Space is added between words and between all characters. Cursive scripts are
+    /// elongated with kashidas instead of being split apart.
+    InterCharacter,
+}
+
 /// Additional options to fine tune alignment
 #[derive(Debug, Clone, Copy)]
 pub struct AlignmentOptions {
@@ -40,16 +62,15 @@ pub struct AlignmentOptions {
     /// wider than the alignment width. If it is set to `false`, all overflowing lines will be
     /// [`Alignment::Start`] aligned.
     pub align_when_overflowing: bool,
+    /// Determines where space is added with [`Alignment::Justify`].
+    pub text_justify: TextJustify,
 }
 
-#[expect(
-    clippy::derivable_impls,
-    reason = "Make default values explicit rather than relying on the implicit default value of bool"
-)]
 impl Default for AlignmentOptions {
     fn default() -> Self {
         Self {
             align_when_overflowing: false,
+            text_justify: TextJustify::InterWord,
         }
     }
 }
@@ -66,6 +87,7 @@ pub(crate) fn align<B: Brush>(
 ) {
     layout.alignment_width = alignment_width.unwrap_or(layout.width);
     layout.is_aligned_justified = alignment == Alignment::Justify;
+    layout.text_justify = options.text_justify;
 
     align_impl::<_, false>(layout, alignment, options);
 }
@@ -76,7 +98,11 @@ pub(crate) fn align<B: Brush>(
 /// layout.
 pub(crate) fn unjustify<B: Brush>(layout: &mut LayoutData<B>) {
     if layout.is_aligned_justified {
-        align_impl::<_, true>(layout, Alignment::Justify, AlignmentOptions::default());
+        let options = AlignmentOptions {
+            text_justify: layout.text_justify,
+            ..AlignmentOptions::default()
+        };
+        align_impl::<_, true>(layout, Alignment::Justify, options);
         layout.is_aligned_justified = false;
     }
 }
@@ -100,19 +126,30 @@ fn align_impl<B: Brush, const UNDO_JUSTIFICATION: bool>(
 
     // Apply alignment to line items
     for line in &mut layout.lines {
-        line.metrics.offset = 0.;
+        // Lines broken within a span are aligned within it.
+        let (start, alignment_width) = line.span.map_or((0., layout.alignment_width), |span| {
+            (span.offset, span.width)
+        });
+        // Lines beside an initial letter are aligned beside it.
+        let (start, alignment_width) = (start + line.indent, alignment_width - line.indent);
+        line.metrics.offset = start;
 
         if is_rtl {
             // In RTL text, trailing whitespace is on the left. As we hang that whitespace, offset
             // the line to the left.
-            line.metrics.offset = -line.metrics.trailing_whitespace;
+            line.metrics.offset -= line.metrics.trailing_whitespace;
         }
 
         // Compute free space.
-        let free_space =
-            layout.alignment_width - line.metrics.advance + line.metrics.trailing_whitespace;
+        let free_space = alignment_width - line.metrics.advance + line.metrics.trailing_whitespace;
 
-        if !options.align_when_overflowing && free_space <= 0.0 {
+        // Lines broken by `LineBreakStrategy::Optimal` may overflow by the amount their spaces
+        // can shrink when justified.
+        let is_shrinking = alignment == Alignment::Justify
+            && free_space < 0.0
+            && -free_space <= line.max_shrink * (1. + 1e-5) + 1e-3;
+
+        if !options.align_when_overflowing && free_space <= 0.0 && !is_shrinking {
             if is_rtl {
                 // In RTL text, right-align on overflow.
                 line.metrics.offset += free_space;
@@ -131,57 +168,180 @@ fn align_impl<B: Brush, const UNDO_JUSTIFICATION: bool>(
                 line.metrics.offset += free_space * 0.5;
             }
             (Alignment::Justify, _) => {
-                // Justified alignment doesn't have any effect if free_space is negative or zero
-                if free_space <= 0.0 {
+                // Justified alignment doesn't have any effect if free_space is negative or zero,
+                // unless the spaces of the line can shrink
+                if free_space <= 0.0 && !is_shrinking {
                     continue;
                 }
 
                 // Justified alignment doesn't apply to the last line of a paragraph
-                // (`BreakReason::None`), (`BreakReason::Explicit`) or if there are no whitespace
-                // gaps to adjust. In that case, start-align, i.e., left-align for LTR text and
+                // (`BreakReason::None`), (`BreakReason::Explicit`) or if there are no gaps to
+                // adjust. In that case, start-align, i.e., left-align for LTR text and
                 // right-align for RTL text.
-                if matches!(line.break_reason, BreakReason::None | BreakReason::Explicit)
-                    || line.num_spaces == 0
+                let is_last_line =
+                    matches!(line.break_reason, BreakReason::None | BreakReason::Explicit);
+                let num_spaces = line.num_spaces;
+                // Count the opportunities other than spaces, excluding the ones at or after the
+                // last non-whitespace cluster of the line.
+                // Only spaces shrink.
+                let mut num_others = 0;
+                if !is_last_line && !is_shrinking && options.text_justify != TextJustify::InterWord
                 {
+                    let mut count = 0;
+                    for_each_line_cluster(
+                        &layout.line_items[line.item_range.clone()],
+                        &mut layout.clusters,
+                        is_rtl,
+                        |_, cluster| {
+                            if !cluster.info.is_whitespace() {
+                                num_others = count;
+                            }
+                            if matches!(
+                                opportunity(cluster, options.text_justify),
+                                Opportunity::Character | Opportunity::Kashida
+                            ) {
+                                count += 1;
+                            }
+                        },
+                    );
+                }
+                if is_last_line || num_spaces + num_others == 0 {
                     if is_rtl {
                         line.metrics.offset += free_space;
                     }
                     continue;
                 }
 
-                let adjustment =
-                    free_space / line.num_spaces as f32 * if UNDO_JUSTIFICATION { -1. } else { 1. };
-                let mut applied = 0;
-                // Iterate over text runs in the line and clusters in the text run
-                //   - Iterate forwards for even bidi levels (which represent LTR runs)
-                //   - Iterate backwards for odd bidi levels (which represent RTL runs)
-                let line_items: &mut dyn Iterator<Item = &LineItemData> = if is_rtl {
-                    &mut layout.line_items[line.item_range.clone()].iter().rev()
-                } else {
-                    &mut layout.line_items[line.item_range.clone()].iter()
-                };
-                line_items
-                    .filter(|item| item.is_text_run())
-                    .for_each(|line_item| {
-                        let clusters = &mut layout.clusters[line_item.cluster_range.clone()];
-                        let line_item_is_rtl = line_item.bidi_level & 1 != 0;
-                        let clusters: &mut dyn Iterator<Item = &mut ClusterData> =
-                            if line_item_is_rtl {
-                                &mut clusters.iter_mut().rev()
-                            } else {
-                                &mut clusters.iter_mut()
-                            };
-                        clusters.for_each(|cluster| {
-                            if applied == line.num_spaces {
-                                return;
+                let adjustment = free_space / (num_spaces + num_others) as f32
+                    * if UNDO_JUSTIFICATION { -1. } else { 1. };
+                line.kashida_advance = if UNDO_JUSTIFICATION { 0. } else { adjustment };
+                let mut applied_spaces = 0;
+                let mut applied_others = 0;
+                let runs = &layout.runs;
+                let glyphs = &mut layout.glyphs;
+                for_each_line_cluster(
+                    &layout.line_items[line.item_range.clone()],
+                    &mut layout.clusters,
+                    is_rtl,
+                    |line_item, cluster| {
+                        match opportunity(cluster, options.text_justify) {
+                            Opportunity::Space if applied_spaces < num_spaces => {
+                                applied_spaces += 1;
+                            }
+                            Opportunity::Character if applied_others < num_others => {
+                                applied_others += 1;
                             }
-                            if cluster.info.whitespace().is_space_or_nbsp() {
+                            Opportunity::Kashida if applied_others < num_others => {
+                                // The glyphs are left untouched: the extra advance is filled
+                                // with tatweel glyphs when iterating the cluster's glyphs.
+                                applied_others += 1;
                                 cluster.advance += adjustment;
-                                applied += 1;
+                                cluster.set_kashida_applied(!UNDO_JUSTIFICATION);
+                                return;
+                            }
+                            _ => return,
+                        }
+                        cluster.advance += adjustment;
+                        if cluster.glyph_len != 0xFF {
+                            let start =
+                                runs[line_item.index].glyph_start + cluster.glyph_offset as usize;
+                            let end = start + cluster.glyph_len as usize;
+                            if let Some(last) = glyphs[start..end].last_mut() {
+                                last.advance += adjustment;
                             }
-                        });
-                    });
+                        }
+                    },
+                );
             }
         }
     }
 }
+
+/// Calls `f` for each cluster of the text runs in `line_items`.
+///
+/// Runs are visited in reverse for RTL text, and clusters are visited in reverse for RTL runs,
+/// so that clusters are visited roughly in logical order.
+fn for_each_line_cluster(
+    line_items: &[LineItemData],
+    clusters: &mut [ClusterData],
+    is_rtl: bool,
+    mut f: impl FnMut(&LineItemData, &mut ClusterData),
+) {
+    let line_items: &mut dyn Iterator<Item = &LineItemData> = if is_rtl {
+        &mut line_items.iter().rev()
+    } else {
+        &mut line_items.iter()
+    };
+    line_items
+        .filter(|item| item.is_text_run())
+        .for_each(|line_item| {
+            let clusters = &mut clusters[line_item.cluster_range.clone()];
+            let clusters: &mut dyn Iterator<Item = &mut ClusterData> =
+                if line_item.bidi_level & 1 != 0 {
+                    &mut clusters.iter_mut().rev()
+                } else {
+                    &mut clusters.iter_mut()
+                };
+            clusters.for_each(|cluster| f(line_item, cluster));
+        });
+}
+
+/// The kind of justification opportunity following a cluster.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Opportunity {
+    None,
+    /// Space is added to a space character.
+    Space,
+    /// Space is added between the cluster and the following one.
+    Character,
+    /// The connection between the cluster and the following one is elongated.
+    Kashida,
+}
+
+fn opportunity(cluster: &ClusterData, text_justify: TextJustify) -> Opportunity {
+    if cluster.info.whitespace().is_space_or_nbsp() {
+        return Opportunity::Space;
+    }
+    if text_justify == TextJustify::InterWord {
+        return Opportunity::None;
+    }
+    if cluster.is_kashida_point() {
+        return Opportunity::Kashida;
+    }
+    if cluster.info.is_whitespace()
+        || cluster.is_ligature_start()
+        || cluster.is_ligature_component()
+    {
+        return Opportunity::None;
+    }
+    let is_character = match text_justify {
+        TextJustify::Auto => cluster.is_inter_character(),
+        // Splitting cursive connections apart is worse than not justifying at all.
+        _ => !cluster.joins_to_following(),
+    };
+    if is_character {
+        Opportunity::Character
+    } else {
+        Opportunity::None
+    }
+}
+
+/// Returns `true` if the script does not separate words with spaces, such that space can be
+/// added between its characters.
+pub(crate) fn is_inter_character_script(script: Script) -> bool {
+    matches!(
+        script,
+        Script::Han
+            | Script::Hiragana
+            | Script::Katakana
+            | Script::Bopomofo
+            | Script::Yi
+            | Script::Thai
+            | Script::Lao
+            | Script::Khmer
+            | Script::Myanmar
+            | Script::TaiLe
+            | Script::NewTaiLue
+            | Script::TaiTham
+    )
+}
@@ -12,6 +12,10 @@ use crate::layout::run::Run;
 use crate::style::Brush;
 use core::ops::Range;
 
+#[cfg(feature = "libm")]
+#[allow(unused_imports)]
+use core_maths::CoreFloat;
+
 /// Atomic unit of text.
 #[derive(Copy, Clone)]
 pub struct Cluster<'a, B: Brush> {
@@ -204,20 +208,60 @@ impl<'a, B: Brush> Cluster<'a, B> {
     }
 
     /// Returns an iterator over the glyphs in the cluster.
+    ///
+    /// If the cluster was elongated by justification, this includes the kashida glyphs that
+    /// connect it to the following cluster.
     pub fn glyphs(&self) -> impl Iterator<Item = Glyph> + 'a + Clone {
-        if self.data.glyph_len == 0xFF {
+        let kashida = self.kashida();
+        let glyphs = if self.data.glyph_len == 0xFF {
             GlyphIter::Single(Some(Glyph {
                 id: self.data.glyph_offset,
                 style_index: self.data.style_index,
                 x: 0.,
                 y: 0.,
-                advance: self.data.advance,
+                advance: self.data.advance - kashida.advance,
             }))
         } else {
             let start = self.run.data.glyph_start + self.data.glyph_offset as usize;
             GlyphIter::Slice(
                 self.run.layout.data.glyphs[start..start + self.data.glyph_len as usize].iter(),
             )
+        };
+        // The following cluster is on the left in RTL runs.
+        let (before, after) = if self.is_rtl() {
+            (kashida, KashidaIter::default())
+        } else {
+            (KashidaIter::default(), kashida)
+        };
+        before.chain(glyphs).chain(after)
+    }
+
+    /// Returns the kashida glyphs inserted after the cluster by justification.
+    fn kashida(&self) -> KashidaIter {
+        let Some((id, tatweel_advance)) = self
+            .run
+            .data
+            .tatweel
+            .filter(|_| self.data.is_kashida_applied())
+        else {
+            return KashidaIter::default();
+        };
+        let Some(line) = self.run.layout.data.lines.get(self.run.line_index as usize) else {
+            return KashidaIter::default();
+        };
+        let advance = line.kashida_advance;
+        // Tatweels overlap when the advance is not a multiple of their width.
+        let count = (advance / tatweel_advance).ceil().max(1.) as u32;
+        KashidaIter {
+            glyph: Glyph {
+                id,
+                style_index: self.data.style_index,
+                x: 0.,
+                y: 0.,
+                advance: advance / count as f32,
+            },
+            count,
+            advance,
         }
     }
 
@@ -433,7 +477,7 @@ impl<'a, B: Brush> Cluster<'a, B> {
     }
 
     /// Returns the text length of the cluster in bytes.
-    #[cfg(test)]
+    #[cfg(all(test, any(feature = "baked_data", feature = "runtime_data")))]
     pub(crate) fn text_len(&self) -> u8 {
         self.data.text_len
     }
@@ -516,6 +560,24 @@ enum GlyphIter<'a> {
     Slice(core::slice::Iter<'a, Glyph>),
 }
 
+/// Iterator over the tatweel glyphs that elongate a cluster.
+#[derive(Clone, Default)]
+struct KashidaIter {
+    glyph: Glyph,
+    count: u32,
+    /// Total advance of the glyphs.
+    advance: f32,
+}
+
+impl Iterator for KashidaIter {
+    type Item = Glyph;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.count = self.count.checked_sub(1)?;
+        Some(self.glyph)
+    }
+}
+
 impl Iterator for GlyphIter<'_> {
     type Item = Glyph;
 
@@ -530,7 +592,7 @@ impl Iterator for GlyphIter<'_> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "baked_data"))]
 mod tests {
     use crate::{
         Alignment, AlignmentOptions, Cluster, FontContext, Layout, LayoutContext,
@@ -2,16 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::inline_box::InlineBox;
-use crate::layout::{ContentWidths, Glyph, LineMetrics, RunMetrics, Style};
+use crate::layout::alignment::is_inter_character_script;
+use crate::layout::{ContentWidths, Glyph, LineMetrics, LineSpan, RunMetrics, Style};
 use crate::style::Brush;
 use crate::util::nearly_zero;
-use crate::{FontData, LineHeight, OverflowWrap, TextWrapMode};
+use crate::{FontData, InitialLetter, LineHeight, OverflowWrap, TextJustify, TextWrapMode};
 use core::ops::Range;
 
 use alloc::vec::Vec;
 
 use crate::analysis::cluster::Whitespace;
 use crate::analysis::{Boundary, CharInfo};
+use icu_properties::props::Script;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) struct ClusterData {
@@ -37,6 +39,17 @@ pub(crate) struct ClusterData {
 impl ClusterData {
     pub(crate) const LIGATURE_START: u16 = 1;
     pub(crate) const LIGATURE_COMPONENT: u16 = 2;
+    /// The connection to the following cluster can be elongated with kashidas.
+    pub(crate) const KASHIDA_POINT: u16 = 4;
+    /// Justification elongated the connection to the following cluster with kashidas.
+    pub(crate) const KASHIDA_APPLIED: u16 = 8;
+    /// The first character of the cluster connects cursively to the following character.
+    pub(crate) const JOINS_TO_FOLLOWING: u16 = 16;
+    /// The first character of the cluster connects cursively to the preceding character.
+    pub(crate) const JOINS_TO_PRECEDING: u16 = 32;
+    /// The cluster is in a script that doesn't separate words with spaces, so justification can
+    /// add space after it.
+    pub(crate) const INTER_CHARACTER: u16 = 64;
 
     #[inline(always)]
     pub(crate) fn is_ligature_start(self) -> bool {
@@ -48,6 +61,39 @@ impl ClusterData {
         self.flags & Self::LIGATURE_COMPONENT != 0
     }
 
+    #[inline(always)]
+    pub(crate) fn is_kashida_point(self) -> bool {
+        self.flags & Self::KASHIDA_POINT != 0
+    }
+
+    #[inline(always)]
+    pub(crate) fn joins_to_following(self) -> bool {
+        self.flags & Self::JOINS_TO_FOLLOWING != 0
+    }
+
+    #[inline(always)]
+    pub(crate) fn joins_to_preceding(self) -> bool {
+        self.flags & Self::JOINS_TO_PRECEDING != 0
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_inter_character(self) -> bool {
+        self.flags & Self::INTER_CHARACTER != 0
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_kashida_applied(self) -> bool {
+        self.flags & Self::KASHIDA_APPLIED != 0
+    }
+
+    pub(crate) fn set_kashida_applied(&mut self, applied: bool) {
+        if applied {
+            self.flags |= Self::KASHIDA_APPLIED;
+        } else {
+            self.flags &= !Self::KASHIDA_APPLIED;
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn text_range(self, run: &RunData) -> Range<usize> {
         let start = run.text_range.start + self.text_offset as usize;
@@ -95,7 +141,7 @@ impl ClusterInfo {
         self.source_char.is_whitespace()
     }
 
-    #[cfg(test)]
+    /// Returns the first character of the cluster.
     pub(crate) fn source_char(self) -> char {
         self.source_char
     }
@@ -139,6 +185,13 @@ pub(crate) struct RunData {
     pub(crate) letter_spacing: f32,
     /// Total advance of the run.
     pub(crate) advance: f32,
+    /// Identifier and advance of the tatweel glyph used for kashida justification, if the run
+    /// contains kashida points.
+    pub(crate) tatweel: Option<(u32, f32)>,
+    /// Cap height of the font.
+    pub(crate) cap_height: f32,
+    /// Initial letter setting of the run, which is the same for all of its clusters.
+    pub(crate) initial_letter: Option<InitialLetter>,
 }
 
 #[derive(Copy, Clone, Default, PartialEq, Debug)]
@@ -164,6 +217,17 @@ pub(crate) struct LineData {
     pub(crate) max_advance: f32,
     /// Number of justified clusters on the line.
     pub(crate) num_spaces: usize,
+    /// Advance added to each cluster elongated with kashidas.
+    pub(crate) kashida_advance: f32,
+    /// Amount the spaces of the line can shrink when justified.
+    pub(crate) max_shrink: f32,
+    /// Horizontal span the line was broken in, if it was supplied by the caller.
+    pub(crate) span: Option<LineSpan>,
+    /// Indentation of the line beside an initial letter, from the start of its span.
+    pub(crate) indent: f32,
+    /// Offset from the baseline of the line to the baseline of the initial letter starting on
+    /// the line.
+    pub(crate) initial_letter_drop: f32,
 }
 
 impl LineData {
@@ -263,6 +327,8 @@ pub(crate) struct LayoutData<B: Brush> {
     pub(crate) quantize: bool,
     pub(crate) base_level: u8,
     pub(crate) text_len: usize,
+    /// Generation of the font collection used to build the layout.
+    pub(crate) font_generation: u64,
     pub(crate) width: f32,
     pub(crate) full_width: f32,
     pub(crate) height: f32,
@@ -286,6 +352,9 @@ pub(crate) struct LayoutData<B: Brush> {
     // Output of alignment
     /// Whether the layout is aligned with [`crate::Alignment::Justify`].
     pub(crate) is_aligned_justified: bool,
+    /// The justification mode used when the layout is aligned with
+    /// [`crate::Alignment::Justify`].
+    pub(crate) text_justify: TextJustify,
     /// The width the layout was aligned to.
     pub(crate) alignment_width: f32,
 }
@@ -297,6 +366,7 @@ impl<B: Brush> Default for LayoutData<B> {
             quantize: true,
             base_level: 0,
             text_len: 0,
+            font_generation: 0,
             width: 0.,
             full_width: 0.,
             height: 0.,
@@ -311,6 +381,7 @@ impl<B: Brush> Default for LayoutData<B> {
             lines: Vec::new(),
             line_items: Vec::new(),
             is_aligned_justified: false,
+            text_justify: TextJustify::default(),
             alignment_width: 0.0,
         }
     }
@@ -322,6 +393,7 @@ impl<B: Brush> LayoutData<B> {
         self.quantize = true;
         self.base_level = 0;
         self.text_len = 0;
+        self.font_generation = 0;
         self.width = 0.;
         self.full_width = 0.;
         self.height = 0.;
@@ -357,6 +429,7 @@ impl<B: Brush> LayoutData<B> {
         synthesis: fontique::Synthesis,
         glyph_buffer: &harfrust::GlyphBuffer,
         bidi_level: u8,
+        script: Script,
         style_index: u16,
         word_spacing: f32,
         letter_spacing: f32,
@@ -385,6 +458,7 @@ impl<B: Brush> LayoutData<B> {
             skrifa::metrics::Metrics::new(&font_ref, skrifa::prelude::Size::new(font_size), coords)
         };
         let units_per_em = metrics.units_per_em as f32;
+        let cap_height = metrics.cap_height.unwrap_or(metrics.ascent);
 
         let metrics = {
             let (underline_offset, underline_size) = if let Some(underline) = metrics.underline {
@@ -439,6 +513,9 @@ impl<B: Brush> LayoutData<B> {
             word_spacing,
             letter_spacing,
             advance: 0.,
+            tatweel: None,
+            cap_height,
+            initial_letter: None,
         };
 
         // `HarfRust` returns glyphs in visual order, so we need to process them as such while
@@ -480,6 +557,21 @@ impl<B: Brush> LayoutData<B> {
         }
 
         run.cluster_range = cluster_range_start..self.clusters.len();
+        let clusters = &mut self.clusters[run.cluster_range.clone()];
+        if is_inter_character_script(script) {
+            for cluster in clusters.iter_mut() {
+                cluster.flags |= ClusterData::INTER_CHARACTER;
+            }
+        }
+        if clusters.iter().any(|cluster| cluster.joins_to_following()) {
+            run.tatweel = tatweel_glyph(&self.fonts[font_index], font_size, coords);
+        }
+        if run.tatweel.is_some() {
+            mark_kashida_points(clusters);
+        }
+        run.initial_letter = clusters
+            .first()
+            .and_then(|cluster| self.styles[cluster.style_index as usize].initial_letter);
         if !run.cluster_range.is_empty() {
             self.runs.push(run);
             self.items.push(LayoutItem {
@@ -491,6 +583,12 @@ impl<B: Brush> LayoutData<B> {
     }
 
     pub(crate) fn finish(&mut self) {
+        // Initial letters don't contribute to the height of lines.
+        for run in &mut self.runs {
+            if run.initial_letter.is_some() {
+                run.metrics.line_height = 0.;
+            }
+        }
         for run in &self.runs {
             let word = run.word_spacing;
             let letter = run.letter_spacing;
@@ -518,6 +616,23 @@ impl<B: Brush> LayoutData<B> {
         }
     }
 
+    /// Removes the runs pushed after there were `runs` runs, along with their items, clusters,
+    /// glyphs and coordinates, and the fonts added after there were `fonts` fonts.
+    ///
+    /// No inline boxes may have been pushed after the runs.
+    pub(crate) fn truncate_runs(&mut self, fonts: usize, runs: usize) {
+        let Some(run) = self.runs.get(runs) else {
+            return;
+        };
+        self.clusters.truncate(run.cluster_range.start);
+        self.glyphs.truncate(run.glyph_start);
+        self.coords.truncate(run.coords_range.start);
+        self.items
+            .truncate(self.items.len() - (self.runs.len() - runs));
+        self.runs.truncate(runs);
+        self.fonts.truncate(fonts);
+    }
+
     // TODO: this method does not handle mixed direction text at all.
     pub(crate) fn calculate_content_widths(&self) -> ContentWidths {
         fn whitespace_advance(cluster: Option<&ClusterData>) -> f32 {
@@ -857,6 +972,38 @@ fn process_clusters<I: Iterator<Item = (usize, char)>>(
     run_advance
 }
 
+/// Marks the clusters whose connection to the following cluster can be elongated with kashidas.
+///
+/// `clusters` must be in logical order.
+fn mark_kashida_points(clusters: &mut [ClusterData]) {
+    for index in 1..clusters.len() {
+        let next = clusters[index];
+        let cluster = &mut clusters[index - 1];
+        if !cluster.is_ligature_component()
+            && !next.is_ligature_component()
+            && cluster.joins_to_following()
+            && next.joins_to_preceding()
+        {
+            cluster.flags |= ClusterData::KASHIDA_POINT;
+        }
+    }
+}
+
+/// Returns the identifier and advance of the tatweel glyph in the given font.
+fn tatweel_glyph(
+    font: &FontData,
+    font_size: f32,
+    coords: &[harfrust::NormalizedCoord],
+) -> Option<(u32, f32)> {
+    use skrifa::MetadataProvider as _;
+    let font_ref = skrifa::FontRef::from_index(font.data.as_ref(), font.index).ok()?;
+    let glyph_id = font_ref.charmap().map('\u{0640}')?;
+    let advance = font_ref
+        .glyph_metrics(skrifa::prelude::Size::new(font_size), coords)
+        .advance_width(glyph_id)?;
+    (advance > 0.).then_some((glyph_id.to_u32(), advance))
+}
+
 #[derive(PartialEq)]
 enum Direction {
     Ltr,
@@ -917,7 +1064,17 @@ fn push_cluster(
 
     clusters.push(ClusterData {
         info: ClusterInfo::new(char_info.0.boundary, cluster_start_char.1),
-        flags: (&cluster_type).into(),
+        flags: u16::from(&cluster_type)
+            | if char_info.0.joins_to_following() {
+                ClusterData::JOINS_TO_FOLLOWING
+            } else {
+                0
+            }
+            | if char_info.0.joins_to_preceding() {
+                ClusterData::JOINS_TO_PRECEDING
+            } else {
+                0
+            },
         style_index: char_info.1,
         glyph_len: final_glyph_len,
         text_len: cluster_start_char.1.len_utf8() as u8,
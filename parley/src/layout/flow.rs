@@ -0,0 +1,132 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Flowing a layout across several containers.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::BoundingBox;
+
+/// A fixed-size box that lines are flowed into, such as a column, a page or a linked text frame.
+///
+/// See [`BreakLines::break_into_containers`](crate::BreakLines::break_into_containers).
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub struct FlowContainer {
+    /// Width of the container, which is the maximum advance of its lines.
+    pub width: f32,
+    /// Height of the container.
+    pub height: f32,
+}
+
+impl FlowContainer {
+    /// Creates a new container with the given size.
+    pub fn new(width: f32, height: f32) -> Self {
+        Self { width, height }
+    }
+}
+
+/// The lines that landed in a container.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct ContainerLines {
+    /// Range of the indices of the lines in the container.
+    pub lines: Range<usize>,
+    /// Offset of the top of the container in layout space, in the direction orthogonal to the
+    /// line direction.
+    pub y: f32,
+    /// Height of the lines in the container.
+    pub height: f32,
+}
+
+/// The result of flowing a layout across several containers, or of splitting it into pages.
+///
+/// The lines of the layout keep their positions in layout space, where the containers are
+/// stacked on top of each other. The methods of this type map between layout space and the
+/// local coordinates of each container, so that geometry such as
+/// [`Cursor::geometry`](crate::Cursor::geometry) and
+/// [`Selection::geometry`](crate::Selection::geometry) can be drawn in the right container, and
+/// points in a container can be hit tested with [`Cursor::from_point`](crate::Cursor::from_point).
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Flow {
+    pub(crate) containers: Vec<ContainerLines>,
+    pub(crate) overflow: Range<usize>,
+}
+
+impl Flow {
+    /// Returns the lines of each container, in the order the containers were given.
+    pub fn containers(&self) -> &[ContainerLines] {
+        &self.containers
+    }
+
+    /// Returns the range of the indices of the lines that didn't fit in any container.
+    ///
+    /// These lines follow the last container in layout space.
+    pub fn overflow(&self) -> Range<usize> {
+        self.overflow.clone()
+    }
+
+    /// Returns `true` if some lines didn't fit in any container.
+    pub fn has_overflow(&self) -> bool {
+        !self.overflow.is_empty()
+    }
+
+    /// Returns the index of the container that holds the line with the given index, or `None`
+    /// if the line is part of the overflow.
+    pub fn container_for_line(&self, line_index: usize) -> Option<usize> {
+        self.containers
+            .iter()
+            .position(|container| container.lines.contains(&line_index))
+    }
+
+    /// Returns the index of the container that holds the given offset in layout space, in the
+    /// direction orthogonal to the line direction, or `None` if the offset is part of the
+    /// overflow.
+    ///
+    /// Offsets above the first container belong to it.
+    pub fn container_for_offset(&self, y: f32) -> Option<usize> {
+        let index = self
+            .containers
+            .iter()
+            .rposition(|container| y >= container.y)
+            .unwrap_or(0);
+        let container = self.containers.get(index)?;
+        if self.has_overflow() && index == self.containers.len() - 1 {
+            // The overflow starts right after the last container.
+            (y < container.y + container.height).then_some(index)
+        } else {
+            Some(index)
+        }
+    }
+
+    /// Maps a point in layout space to the index of its container and the point in the local
+    /// coordinates of that container.
+    pub fn to_container_point(&self, x: f32, y: f32) -> Option<(usize, f32, f32)> {
+        let index = self.container_for_offset(y)?;
+        Some((index, x, y - self.containers[index].y))
+    }
+
+    /// Maps a point in the local coordinates of a container to layout space.
+    ///
+    /// The result can be used for hit testing, for example with
+    /// [`Cursor::from_point`](crate::Cursor::from_point).
+    pub fn from_container_point(&self, container: usize, x: f32, y: f32) -> Option<(f32, f32)> {
+        Some((x, self.containers.get(container)?.y + y))
+    }
+
+    /// Maps a rectangle in layout space to the index of its container and the rectangle in the
+    /// local coordinates of that container.
+    ///
+    /// The container is chosen by the vertical center of the rectangle, which works for the
+    /// rectangles of a single line, such as those returned by
+    /// [`Cursor::geometry`](crate::Cursor::geometry) and
+    /// [`Selection::geometry`](crate::Selection::geometry).
+    pub fn to_container_rect(&self, rect: BoundingBox) -> Option<(usize, BoundingBox)> {
+        let center = (rect.y0 + rect.y1) * 0.5;
+        let index = self.container_for_offset(center as f32)?;
+        let y = f64::from(self.containers[index].y);
+        Some((
+            index,
+            BoundingBox::new(rect.x0, rect.y0 - y, rect.x1, rect.y1 - y),
+        ))
+    }
+}
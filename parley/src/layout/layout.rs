@@ -7,10 +7,12 @@ use crate::layout::alignment::unjustify;
 use crate::layout::data::LayoutData;
 use crate::style::Brush;
 use core::cmp::Ordering;
+use core::ops::Range;
 
 use crate::layout::{
-    ContentWidths, Style, alignment::Alignment, alignment::AlignmentOptions, line::Line,
-    line_break::BreakLines,
+    ContentWidths, Style, alignment::Alignment, alignment::AlignmentOptions, flow::Flow,
+    flow::FlowContainer, line::Line, line_break::BreakLines, line_break::LineSpan,
+    optimal_break::LineBreakStrategy, pagination::PaginationOptions, pagination::paginate,
 };
 
 /// Text layout.
@@ -30,6 +32,18 @@ impl<B: Brush> Layout<B> {
         self.data.scale
     }
 
+    /// Returns the [generation] of the font collection at the time the
+    /// layout was built.
+    ///
+    /// If this differs from the current generation of the collection, then
+    /// fonts have been added or removed since and the layout should be
+    /// rebuilt to pick up the changes.
+    ///
+    /// [generation]: fontique::Collection::generation
+    pub fn font_generation(&self) -> u64 {
+        self.data.font_generation
+    }
+
     /// Returns the style collection for the layout.
     pub fn styles(&self) -> &[Style<B>] {
         &self.data.styles
@@ -120,6 +134,59 @@ impl<B: Brush> Layout<B> {
             .break_remaining(max_advance.unwrap_or(f32::MAX));
     }
 
+    /// Breaks all lines within the spans supplied by `shape`, to flow text around other
+    /// content.
+    ///
+    /// See [`BreakLines::break_remaining_in_shape`].
+    pub fn break_all_lines_in_shape(&mut self, shape: impl FnMut(Range<f32>) -> LineSpan) {
+        self.break_lines().break_remaining_in_shape(shape);
+    }
+
+    /// Breaks all lines into a sequence of containers, such as columns, pages or linked text
+    /// frames.
+    ///
+    /// See [`BreakLines::break_into_containers`].
+    pub fn break_into_containers(&mut self, containers: &[FlowContainer]) -> Flow {
+        self.break_lines().break_into_containers(containers)
+    }
+
+    /// Splits the lines of the layout into pages with the given heights, such as pages or
+    /// columns of the same width as the layout.
+    ///
+    /// Each page is filled with lines until the next line would exceed its height. The break is
+    /// then moved up to keep at least [`orphans`](PaginationOptions::orphans) lines of a
+    /// paragraph at the bottom of the page and [`widows`](PaginationOptions::widows) lines at
+    /// the top of the next page, and to avoid breaking between lines of text styled with
+    /// [`BreakInside::Avoid`]. When no break on the page satisfies these constraints, they are
+    /// ignored for that page. A page always receives at least one line if any remain.
+    ///
+    /// `page_heights` may be infinite, for example with [`core::iter::repeat`]. The lines that
+    /// don't fit in any page are reported as the [overflow](Flow::overflow).
+    ///
+    /// Lines must have been broken before paginating.
+    ///
+    /// [`BreakInside::Avoid`]: crate::BreakInside::Avoid
+    pub fn paginate(
+        &self,
+        page_heights: impl IntoIterator<Item = f32>,
+        options: PaginationOptions,
+    ) -> Flow {
+        paginate(self, page_heights, options)
+    }
+
+    /// Breaks all lines with the specified maximum advance, choosing the breaks with the given
+    /// strategy.
+    ///
+    /// [`Layout::break_all_lines`] is equivalent to using [`LineBreakStrategy::Greedy`].
+    pub fn break_all_lines_with_strategy(
+        &mut self,
+        max_advance: Option<f32>,
+        strategy: LineBreakStrategy,
+    ) {
+        self.break_lines()
+            .break_remaining_with_strategy(max_advance.unwrap_or(f32::MAX), strategy);
+    }
+
     /// Apply alignment to the layout relative to the specified container width or full layout
     /// width.
     ///
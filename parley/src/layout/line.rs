@@ -279,6 +279,11 @@ impl<'a, B: Brush> Iterator for GlyphRunIter<'a, B> {
                             advance += glyph.advance;
                         }
                         let style = run.layout.data.styles.get(style_index)?;
+                        // Initial letters sit on the baseline of the line they sink into.
+                        let mut baseline = self.line.data.metrics.baseline;
+                        if run.data.initial_letter.is_some() {
+                            baseline += self.line.data.initial_letter_drop;
+                        }
                         let glyph_start = self.glyph_start;
                         self.glyph_start += glyph_count;
                         let offset = self.offset;
@@ -289,7 +294,7 @@ impl<'a, B: Brush> Iterator for GlyphRunIter<'a, B> {
                             glyph_start,
                             glyph_count,
                             offset: offset + self.line.data.metrics.offset,
-                            baseline: self.line.data.metrics.baseline,
+                            baseline,
                             advance,
                         }));
                     }
@@ -3,7 +3,7 @@
 
 //! Greedy line breaking.
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 #[cfg(feature = "libm")]
 #[allow(unused_imports)]
@@ -12,15 +12,41 @@ use core_maths::CoreFloat;
 use crate::analysis::Boundary;
 use crate::analysis::cluster::Whitespace;
 use crate::data::ClusterData;
+use crate::layout::flow::{ContainerLines, Flow, FlowContainer};
+use crate::layout::optimal_break::{
+    BreakPoint, LineBreakStrategy, LineChoice, choose_lines, collect_break_points,
+};
 use crate::layout::{
     BreakReason, Layout, LayoutData, LayoutItem, LayoutItemKind, LineData, LineItemData,
     LineMetrics, Run,
 };
 use crate::style::Brush;
-use crate::{OverflowWrap, TextWrapMode};
+use crate::{OverflowWrap, TextWrapMode, TextWrapStyle};
 
 use core::ops::Range;
 
+/// Maximum number of times a line is broken again by [`BreakLines::break_remaining_in_shape`]
+/// when its height exceeds the height its span was queried for.
+const MAX_SHAPE_ATTEMPTS: usize = 4;
+
+/// The horizontal span available to a line.
+///
+/// See [`BreakLines::break_next_in`] and [`BreakLines::break_remaining_in_shape`].
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub struct LineSpan {
+    /// Offset of the start of the span from the left edge of the layout.
+    pub offset: f32,
+    /// Width of the span, which is the maximum advance of the line.
+    pub width: f32,
+}
+
+impl LineSpan {
+    /// Creates a new span with the given offset and width.
+    pub fn new(offset: f32, width: f32) -> Self {
+        Self { offset, width }
+    }
+}
+
 #[derive(Default)]
 struct LineLayout {
     lines: Vec<LineData>,
@@ -49,6 +75,15 @@ struct LineState {
     text_wrap_mode: TextWrapMode,
 }
 
+/// The lines beside an initial letter that remain to be indented.
+#[derive(Clone, Copy)]
+struct InitialLetterIndent {
+    /// The number of lines to indent.
+    lines: u32,
+    /// The advance of the initial letter.
+    advance: f32,
+}
+
 #[derive(Clone, Default)]
 struct PrevBoundaryState {
     item_idx: usize,
@@ -78,6 +113,9 @@ struct BreakerState {
     line: LineState,
     prev_boundary: Option<PrevBoundaryState>,
     emergency_boundary: Option<PrevBoundaryState>,
+
+    /// The indentation of the lines beside the initial letter of the current paragraph.
+    initial_letter: Option<InitialLetterIndent>,
 }
 
 impl BreakerState {
@@ -167,9 +205,29 @@ impl<'a, B: Brush> BreakLines<'a, B> {
         self.state.emergency_boundary = None;
 
         self.finish_line(self.lines.lines.len() - 1, line_height);
+        self.update_initial_letter();
         self.last_line_data()
     }
 
+    /// Updates the indentation of the lines beside an initial letter after a line is committed.
+    fn update_initial_letter(&mut self) {
+        let line = self.lines.lines.last().unwrap();
+        let ends_paragraph = matches!(line.break_reason, BreakReason::Explicit | BreakReason::None);
+        if let Some(indent) = &mut self.state.initial_letter {
+            indent.lines -= 1;
+            if indent.lines == 0 || ends_paragraph {
+                self.state.initial_letter = None;
+            }
+        } else if let Some((advance, sink)) = initial_letter(&self.layout.data, &self.lines, line) {
+            if sink > 1 && !ends_paragraph {
+                self.state.initial_letter = Some(InitialLetterIndent {
+                    lines: sink - 1,
+                    advance,
+                });
+            }
+        }
+    }
+
     fn last_line_data(&self) -> Option<(f32, f32)> {
         let line = self.lines.lines.last().unwrap();
         Some((line.metrics.advance, line.size()))
@@ -188,6 +246,20 @@ impl<'a, B: Brush> BreakLines<'a, B> {
     /// Computes the next line in the paragraph. Returns the advance and size
     /// (width and height for horizontal layouts) of the line.
     pub fn break_next(&mut self, max_advance: f32) -> Option<(f32, f32)> {
+        self.break_next_impl(max_advance, None)
+    }
+
+    /// Computes the next line in the paragraph within the given span, which is used to flow
+    /// text around other content. Returns the advance and size (width and height for
+    /// horizontal layouts) of the line.
+    ///
+    /// The [offset](LineMetrics::offset) of the line starts at the offset of the span, and the
+    /// line is aligned within the span.
+    pub fn break_next_in(&mut self, span: LineSpan) -> Option<(f32, f32)> {
+        self.break_next_impl(span.width, Some(span))
+    }
+
+    fn break_next_impl(&mut self, max_advance: f32, span: Option<LineSpan>) -> Option<(f32, f32)> {
         // Maintain iterator state
         if self.done {
             return None;
@@ -203,6 +275,13 @@ impl<'a, B: Brush> BreakLines<'a, B> {
                 max_advance
             };
 
+        // Lines beside an initial letter are indented by its advance.
+        let indent = self
+            .state
+            .initial_letter
+            .map_or(0., |initial_letter| initial_letter.advance);
+        let max_advance = max_advance - indent;
+
         // This macro simply calls the `commit_line` with the provided arguments and some parts of self.
         // It exists solely to cut down on the boilerplate for accessing the self variables while
         // keeping the borrow checker happy
@@ -213,6 +292,8 @@ impl<'a, B: Brush> BreakLines<'a, B> {
                     &mut self.lines,
                     &mut self.state.line,
                     max_advance,
+                    span,
+                    indent,
                     $break_reason,
                 )
             };
@@ -458,7 +539,91 @@ impl<'a, B: Brush> BreakLines<'a, B> {
 
     /// Breaks all remaining lines with the specified maximum advance. This
     /// consumes the line breaker.
-    pub fn break_remaining(mut self, max_advance: f32) {
+    ///
+    /// Unlike [`BreakLines::break_next`], this honors the [`TextWrapStyle`] of each paragraph,
+    /// which is taken from the style of its first cluster.
+    pub fn break_remaining(self, max_advance: f32) {
+        self.break_remaining_with_strategy(max_advance, LineBreakStrategy::Greedy);
+    }
+
+    /// Breaks all remaining lines within the spans supplied by `shape`, which is used to flow
+    /// text around other content, such as floated images. This consumes the line breaker.
+    ///
+    /// `shape` is called with the vertical range of each line, and returns the horizontal span
+    /// available to the line in that range. As the height of a line is only known once it is
+    /// broken, a line is broken again within the span of its actual vertical range when it is
+    /// taller than the range its span was queried for.
+    ///
+    /// Lines are broken greedily, regardless of their [`TextWrapStyle`].
+    pub fn break_remaining_in_shape(mut self, mut shape: impl FnMut(Range<f32>) -> LineSpan) {
+        let mut line_height = 0_f32;
+        while !self.done {
+            let y = self.state.committed_y as f32;
+            for attempt in 1..=MAX_SHAPE_ATTEMPTS {
+                let Some((_, height)) = self.break_next_in(shape(y..y + line_height)) else {
+                    break;
+                };
+                let fits = height <= line_height;
+                line_height = height;
+                if fits || attempt == MAX_SHAPE_ATTEMPTS {
+                    break;
+                }
+                self.revert();
+            }
+        }
+        self.finish();
+    }
+
+    /// Breaks all remaining lines into a sequence of containers, such as columns, pages or
+    /// linked text frames. This consumes the line breaker.
+    ///
+    /// Each container is filled with lines broken with its width until the next line would
+    /// exceed its height. A container always receives at least one line if any remain, even if
+    /// that line is taller than the container, so that the text makes progress. The lines that
+    /// don't fit in any container are broken with the width of the last container and are
+    /// reported as the [overflow](Flow::overflow).
+    ///
+    /// Lines are aligned within the width of their container, and are broken greedily,
+    /// regardless of their [`TextWrapStyle`].
+    pub fn break_into_containers(mut self, containers: &[FlowContainer]) -> Flow {
+        let mut flow = Flow::default();
+        for container in containers {
+            let top = self.state.committed_y;
+            let start = self.lines.lines.len();
+            while !self.done {
+                if self
+                    .break_next_in(LineSpan::new(0., container.width))
+                    .is_none()
+                {
+                    break;
+                }
+                let is_first = self.lines.lines.len() == start + 1;
+                if self.state.committed_y - top > f64::from(container.height) && !is_first {
+                    self.revert();
+                    break;
+                }
+            }
+            flow.containers.push(ContainerLines {
+                lines: start..self.lines.lines.len(),
+                y: top as f32,
+                height: (self.state.committed_y - top) as f32,
+            });
+        }
+
+        let overflow_start = self.lines.lines.len();
+        let width = containers
+            .last()
+            .map_or(f32::MAX, |container| container.width);
+        while !self.done && self.break_next_in(LineSpan::new(0., width)).is_some() {}
+        flow.overflow = overflow_start..self.lines.lines.len();
+        self.finish();
+        flow
+    }
+
+    /// Breaks all remaining lines with the specified maximum advance, choosing the breaks of
+    /// paragraphs with [`TextWrapStyle::Auto`] with the given strategy. This consumes the line
+    /// breaker.
+    pub fn break_remaining_with_strategy(mut self, max_advance: f32, strategy: LineBreakStrategy) {
         // println!("\nDEBUG ITEMS");
         // for item in &self.layout.items {
         //     match item.kind {
@@ -472,10 +637,157 @@ impl<'a, B: Brush> BreakLines<'a, B> {
 
         // println!("\nBREAK ALL");
 
-        while self.break_next(max_advance).is_some() {}
+        while !self.done {
+            let choice = match (self.paragraph_text_wrap_style(), strategy) {
+                (TextWrapStyle::Auto, LineBreakStrategy::Greedy) => None,
+                (TextWrapStyle::Auto, LineBreakStrategy::Optimal(options)) => {
+                    Some(LineChoice::Optimal(options))
+                }
+                (TextWrapStyle::Balance, _) => Some(LineChoice::Balance),
+                (TextWrapStyle::Pretty, _) => Some(LineChoice::Pretty),
+            };
+            let planned = choice.and_then(|choice| {
+                let points = collect_break_points(
+                    self.layout,
+                    self.state.item_idx,
+                    self.state.cluster_idx,
+                    self.state.line.text_wrap_mode,
+                )?;
+                let widths = self.paragraph_line_widths(max_advance);
+                let ends = choose_lines(&points, &widths, choice)?;
+                Some((points, widths, ends, choice.space_shrink()))
+            });
+            match planned {
+                Some((points, widths, ends, space_shrink)) => {
+                    self.break_planned_paragraph(&points, &ends, &widths, space_shrink);
+                }
+                None => self.break_paragraph(max_advance),
+            }
+        }
         self.finish();
     }
 
+    /// Returns the text wrap style of the paragraph starting at the current position.
+    fn paragraph_text_wrap_style(&self) -> TextWrapStyle {
+        self.layout
+            .data
+            .clusters
+            .get(self.state.cluster_idx)
+            .map(|cluster| self.layout.data.styles[cluster.style_index as usize].text_wrap_style)
+            .unwrap_or_default()
+    }
+
+    /// Returns the maximum advances of the first lines of the paragraph starting at the current
+    /// position, the last of which applies to all the following lines.
+    ///
+    /// The lines beside an initial letter are narrower by its advance.
+    fn paragraph_line_widths(&self, max_advance: f32) -> Vec<f32> {
+        let data = &self.layout.data;
+        let mut advance = 0.;
+        let mut sink = 0;
+        for item in &data.items[self.state.item_idx..] {
+            if item.kind != LayoutItemKind::TextRun {
+                break;
+            }
+            let run = &data.runs[item.index];
+            let Some(initial_letter) = run.initial_letter else {
+                break;
+            };
+            if self.state.cluster_idx > run.cluster_range.start {
+                break;
+            }
+            advance += data.clusters[run.cluster_range.clone()]
+                .iter()
+                .map(|cluster| cluster.advance)
+                .sum::<f32>();
+            sink = initial_letter.sink.max(sink);
+        }
+        let mut widths = vec![max_advance];
+        for _ in 1..sink {
+            widths.push(max_advance - advance);
+        }
+        if sink > 1 {
+            widths.push(max_advance);
+        }
+        widths
+    }
+
+    /// Returns `true` if the last computed line ends a paragraph.
+    fn is_paragraph_end(&self) -> bool {
+        self.done
+            || self
+                .lines
+                .lines
+                .last()
+                .is_some_and(|line| line.break_reason == BreakReason::Explicit)
+    }
+
+    /// Greedily breaks the lines of the paragraph starting at the current position.
+    fn break_paragraph(&mut self, max_advance: f32) {
+        while self.break_next(max_advance).is_some() && !self.is_paragraph_end() {}
+    }
+
+    /// Breaks the lines of the paragraph starting at the current position at the chosen break
+    /// points.
+    ///
+    /// Each line is broken greedily with a maximum advance equal to its chosen width, so that
+    /// the greedy breaker takes exactly the chosen break. If it doesn't, for example because of
+    /// an emergency break in an overfull line, the rest of the paragraph is broken greedily.
+    ///
+    /// `widths` are the maximum advances the lines were chosen with, as returned by
+    /// [`Self::paragraph_line_widths`]. `space_shrink` is the amount the spaces of a line can
+    /// shrink when justified, as a fraction of their advance. Lines may overflow by that amount.
+    fn break_planned_paragraph(
+        &mut self,
+        points: &[BreakPoint],
+        ends: &[usize],
+        widths: &[f32],
+        space_shrink: f64,
+    ) {
+        // The greedy breaker subtracts the indentation beside an initial letter itself.
+        let max_advance = widths[0];
+        let mut start = 0;
+        for (line_idx, &end) in ends.iter().enumerate() {
+            let is_last = line_idx == ends.len() - 1;
+            let line_width = widths[line_idx.min(widths.len() - 1)];
+            let indent = max_advance - line_width;
+            let content_width = (points[end].content_x - points[start].x) as f32;
+            let max_shrink = if is_last {
+                0.
+            } else {
+                ((points[end].content_spaces - points[start].spaces) * space_shrink) as f32
+            };
+            let line_max_advance = if is_last || content_width > line_width + max_shrink {
+                max_advance
+            } else {
+                // Include the trailing whitespace, so that it doesn't cause an earlier break, and
+                // allow for rounding errors, as the greedy breaker sums advances in `f32`.
+                let width = (points[end].x - points[start].x) as f32;
+                width + width * 1e-5 + 1e-3 + indent
+            };
+            if self.break_next(line_max_advance).is_none() {
+                return;
+            }
+            if let Some(line) = self.lines.lines.last_mut() {
+                line.max_advance = max_advance;
+                line.max_shrink = max_shrink;
+            }
+            if is_last || self.is_paragraph_end() {
+                break;
+            }
+            if (self.state.item_idx, self.state.cluster_idx)
+                != (points[end].item_idx, points[end].cluster_idx)
+            {
+                self.break_paragraph(max_advance);
+                return;
+            }
+            start = end;
+        }
+        if !self.is_paragraph_end() {
+            self.break_paragraph(max_advance);
+        }
+    }
+
     /// Consumes the line breaker and finalizes all line computations.
     pub fn finish(mut self) {
         if self.layout.data.text_len == 0 {
@@ -497,7 +809,7 @@ impl<'a, B: Brush> BreakLines<'a, B> {
         line.metrics.ascent = 0.;
         line.metrics.descent = 0.;
         line.metrics.leading = 0.;
-        line.metrics.offset = 0.;
+        line.metrics.offset = line.span.map_or(0., |span| span.offset) + line.indent;
         line.text_range.start = usize::MAX;
 
         line.metrics.line_height = line_height;
@@ -551,8 +863,11 @@ impl<'a, B: Brush> BreakLines<'a, B> {
                         continue;
                     }
 
-                    // Compute the run's vertical metrics
+                    // Compute the run's vertical metrics, which initial letters don't contribute to
                     let run = &self.layout.data.runs[line_item.index];
+                    if run.initial_letter.is_some() {
+                        continue;
+                    }
                     line.metrics.ascent = line.metrics.ascent.max(run.metrics.ascent);
                     line.metrics.descent = line.metrics.descent.max(run.metrics.descent);
 
@@ -681,6 +996,63 @@ impl<'a, B: Brush> BreakLines<'a, B> {
 
         self.state.committed_y += line.metrics.line_height as f64;
     }
+
+    /// Computes the offset from the baseline of each line that starts an initial letter to the
+    /// baseline of the line the initial letter sinks into.
+    ///
+    /// When the paragraph has fewer lines than the initial letter sinks into, the missing lines
+    /// are assumed to have the height of the first line.
+    fn position_initial_letters(&mut self) {
+        let lines = &self.lines.lines;
+        let drops: Vec<f32> = (0..lines.len())
+            .map(|index| {
+                let Some((_, sink)) = initial_letter(&self.layout.data, &self.lines, &lines[index])
+                else {
+                    return 0.;
+                };
+                let mut drop = 0.;
+                let mut line = index;
+                for _ in 1..sink {
+                    let ends_paragraph = matches!(
+                        lines[line].break_reason,
+                        BreakReason::Explicit | BreakReason::None
+                    );
+                    if line + 1 < lines.len() && !ends_paragraph {
+                        drop += lines[line + 1].metrics.baseline - lines[line].metrics.baseline;
+                        line += 1;
+                    } else {
+                        drop += lines[index].metrics.line_height;
+                    }
+                }
+                drop
+            })
+            .collect();
+        for (line, drop) in self.lines.lines.iter_mut().zip(drops) {
+            line.initial_letter_drop = drop;
+        }
+    }
+}
+
+/// Returns the advance and sink of the initial letter starting on the line, if any.
+fn initial_letter<B: Brush>(
+    layout: &LayoutData<B>,
+    lines: &LineLayout,
+    line: &LineData,
+) -> Option<(f32, u32)> {
+    let mut result = None;
+    for item in &lines.line_items[line.item_range.clone()] {
+        if item.kind != LayoutItemKind::TextRun {
+            continue;
+        }
+        let run = &layout.runs[item.index];
+        if let Some(initial_letter) = run.initial_letter {
+            if item.cluster_range.start == run.cluster_range.start {
+                let (advance, sink) = result.unwrap_or((0., 0));
+                result = Some((advance + item.advance, initial_letter.sink.max(sink)));
+            }
+        }
+    }
+    result
 }
 
 impl<B: Brush> Drop for BreakLines<'_, B> {
@@ -690,9 +1062,11 @@ impl<B: Brush> Drop for BreakLines<'_, B> {
         let mut width = 0_f32;
         let mut full_width = 0_f32;
         let mut height = 0_f64; // f32 causes test failures due to accumulated error
+        self.position_initial_letters();
         for line in &self.lines.lines {
-            width = width.max(line.metrics.advance - line.metrics.trailing_whitespace);
-            full_width = full_width.max(line.metrics.advance);
+            let offset = line.span.map_or(0., |span| span.offset) + line.indent;
+            width = width.max(offset + line.metrics.advance - line.metrics.trailing_whitespace);
+            full_width = full_width.max(offset + line.metrics.advance);
             height += line.metrics.line_height as f64;
         }
 
@@ -766,6 +1140,8 @@ fn try_commit_line<B: Brush>(
     lines: &mut LineLayout,
     state: &mut LineState,
     max_advance: f32,
+    span: Option<LineSpan>,
+    indent: f32,
     break_reason: BreakReason,
 ) -> bool {
     // Ensure that the cluster and item endpoints are within range
@@ -886,6 +1262,8 @@ fn try_commit_line<B: Brush>(
     lines.lines.push(LineData {
         item_range: start_item_idx..end_item_idx,
         max_advance,
+        span,
+        indent,
         break_reason,
         num_spaces,
         metrics: LineMetrics {
@@ -7,10 +7,14 @@
 mod accessibility;
 mod alignment;
 mod cluster;
+mod flow;
 mod glyph;
 mod line;
 mod line_break;
+mod optimal_break;
+mod pagination;
 mod run;
+mod text_index;
 
 // TODO - Add to allowed lint set?
 #[expect(
@@ -23,14 +27,18 @@ pub(crate) mod data;
 
 #[cfg(feature = "accesskit")]
 pub use accessibility::LayoutAccessibility;
-pub use alignment::{Alignment, AlignmentOptions};
+pub use alignment::{Alignment, AlignmentOptions, TextJustify};
 pub use cluster::{Affinity, Cluster, ClusterPath, ClusterSide};
 pub use data::BreakReason;
+pub use flow::{ContainerLines, Flow, FlowContainer};
 pub use glyph::Glyph;
 pub use layout::Layout;
 pub use line::{GlyphRun, Line, LineMetrics, PositionedInlineBox, PositionedLayoutItem};
-pub use line_break::BreakLines;
+pub use line_break::{BreakLines, LineSpan};
+pub use optimal_break::{LineBreakStrategy, OptimalBreakOptions};
+pub use pagination::PaginationOptions;
 pub use run::{Run, RunMetrics};
+pub use text_index::TextIndex;
 
 pub(crate) use data::{LayoutData, LayoutItem, LayoutItemKind, LineData, LineItemData};
 pub(crate) use line::LineItem;
@@ -42,7 +50,7 @@ pub use crate::editing::{Cursor, Selection};
 // TODO - Move the following to `style` module and submodules.
 
 use crate::style::Brush;
-use crate::{LineHeight, OverflowWrap, TextWrapMode};
+use crate::{BreakInside, InitialLetter, LineHeight, OverflowWrap, TextWrapMode, TextWrapStyle};
 
 #[allow(clippy::partial_pub_fields)]
 /// Style properties.
@@ -60,6 +68,12 @@ pub struct Style<B: Brush> {
     pub(crate) overflow_wrap: OverflowWrap,
     /// Per-cluster text-wrap-mode setting
     pub(crate) text_wrap_mode: TextWrapMode,
+    /// Per-cluster text-wrap-style setting
+    pub(crate) text_wrap_style: TextWrapStyle,
+    /// Per-cluster break-inside setting
+    pub(crate) break_inside: BreakInside,
+    /// Per-cluster initial-letter setting
+    pub(crate) initial_letter: Option<InitialLetter>,
 }
 
 /// Underline or strikethrough decoration.
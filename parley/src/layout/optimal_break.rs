@@ -0,0 +1,561 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Line breaking that considers a whole paragraph at once.
+//!
+//! The break opportunities of a paragraph are collected up front and the best set of breaks is
+//! chosen with dynamic programming. The lines are then committed by the greedy breaker with a
+//! maximum advance for each line that makes it take exactly the chosen breaks.
+
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "libm")]
+#[allow(unused_imports)]
+use core_maths::CoreFloat;
+
+use crate::TextWrapMode;
+use crate::analysis::Boundary;
+use crate::analysis::cluster::Whitespace;
+use crate::layout::{Layout, LayoutItemKind, Run};
+use crate::style::Brush;
+
+/// Paragraphs with more break opportunities than this are broken greedily, to bound the cost of
+/// choosing the breaks.
+const MAX_BREAK_POINTS: usize = 2000;
+
+/// Balanced paragraphs with more lines than this are broken greedily.
+///
+/// Balancing is intended for short blocks of text such as headings.
+const MAX_BALANCED_LINES: usize = 10;
+
+/// Cost of a line that is wider than the maximum advance. Such lines are only chosen when a
+/// single word doesn't fit.
+const OVERFULL_COST: f64 = 1e10;
+
+/// Cost of a line that is too loose or too tight for the tolerance of optimal line breaking.
+/// Such lines are only chosen when there is no other way to break the paragraph.
+const INFEASIBLE_COST: f64 = 1e8;
+
+/// The badness of a line that cannot be stretched or shrunk enough.
+const MAX_BADNESS: f64 = 10000.;
+
+/// The algorithm used to choose line breaks.
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub enum LineBreakStrategy {
+    /// Fill each line as much as possible before moving on to the next one.
+    ///
+    /// Paragraphs with a [`TextWrapStyle`] other than [`TextWrapStyle::Auto`] are broken
+    /// according to their style.
+    ///
+    /// [`TextWrapStyle`]: crate::TextWrapStyle
+    /// [`TextWrapStyle::Auto`]: crate::TextWrapStyle::Auto
+    #[default]
+    Greedy,
+    /// Choose the line breaks of each paragraph together, minimizing the total demerits of its
+    /// lines with the Knuth–Plass algorithm used by TeX.
+    ///
+    /// The demerits of a line grow with the amount its spaces have to be stretched or shrunk to
+    /// fill the line when it is justified, and with the penalties for breaking after hyphens.
+    /// Paragraphs with a [`TextWrapStyle`] other than [`TextWrapStyle::Auto`] are broken
+    /// according to their style.
+    ///
+    /// [`TextWrapStyle`]: crate::TextWrapStyle
+    /// [`TextWrapStyle::Auto`]: crate::TextWrapStyle::Auto
+    Optimal(OptimalBreakOptions),
+}
+
+/// Parameters of [`LineBreakStrategy::Optimal`].
+///
+/// The defaults follow the defaults of TeX, except for [`space_shrink`](Self::space_shrink).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct OptimalBreakOptions {
+    /// The amount a space can stretch, as a fraction of its advance.
+    pub space_stretch: f32,
+    /// The amount a space can shrink, as a fraction of its advance.
+    ///
+    /// Lines that rely on shrinking are wider than the maximum advance, and are only shrunk to
+    /// fit when aligned with [`Alignment::Justify`]. Defaults to `0.0`, so that lines never
+    /// overflow with other alignments.
+    ///
+    /// [`Alignment::Justify`]: crate::Alignment::Justify
+    pub space_shrink: f32,
+    /// The maximum badness of a line, where a badness of 100 means that the spaces are
+    /// stretched or shrunk by their full amount. Looser or tighter lines are only chosen when
+    /// there is no other way to break the paragraph.
+    pub tolerance: f32,
+    /// Penalty added to the badness of every line, which favors using fewer lines.
+    pub line_penalty: f32,
+    /// Penalty for breaking a line after a hyphen or a soft hyphen.
+    pub hyphen_penalty: f32,
+    /// Demerits added when two consecutive lines end with a hyphen.
+    pub consecutive_hyphen_demerits: f32,
+    /// The maximum number of consecutive lines ending with a hyphen, or `None` for no limit.
+    pub max_consecutive_hyphens: Option<u8>,
+}
+
+impl Default for OptimalBreakOptions {
+    fn default() -> Self {
+        Self {
+            space_stretch: 0.5,
+            space_shrink: 0.,
+            tolerance: 200.,
+            line_penalty: 10.,
+            hyphen_penalty: 50.,
+            consecutive_hyphen_demerits: 3000.,
+            max_consecutive_hyphens: None,
+        }
+    }
+}
+
+/// A position in a paragraph where a line can end.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct BreakPoint {
+    /// Index of the item at which the next line starts.
+    pub(crate) item_idx: usize,
+    /// Index of the cluster at which the next line starts.
+    pub(crate) cluster_idx: usize,
+    /// Advance from the start of the paragraph, including trailing whitespace.
+    pub(crate) x: f64,
+    /// Advance from the start of the paragraph, excluding trailing whitespace.
+    pub(crate) content_x: f64,
+    /// Advance of the spaces from the start of the paragraph, including trailing whitespace.
+    pub(crate) spaces: f64,
+    /// Advance of the spaces from the start of the paragraph, excluding trailing whitespace.
+    pub(crate) content_spaces: f64,
+    /// Whether the line ending at this point ends with a hyphen.
+    pub(crate) is_hyphen: bool,
+}
+
+/// How the lines of a paragraph are chosen.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) enum LineChoice {
+    /// Equalize the lengths of all lines, keeping the greedy number of lines.
+    Balance,
+    /// Minimize the raggedness of the lines and avoid a short last line.
+    Pretty,
+    /// Minimize the total demerits of the lines.
+    Optimal(OptimalBreakOptions),
+}
+
+impl LineChoice {
+    /// Returns the amount the spaces of a line can shrink, as a fraction of their advance.
+    pub(crate) fn space_shrink(self) -> f64 {
+        match self {
+            Self::Optimal(options) => f64::from(options.space_shrink.max(0.)),
+            Self::Balance | Self::Pretty => 0.,
+        }
+    }
+}
+
+/// Collects the break opportunities of the paragraph starting at the given iteration state of
+/// the greedy breaker, in the same way the greedy breaker finds them.
+///
+/// The first returned break point is the start of the paragraph and the last one is its end,
+/// either at an explicit line break or at the end of the text. Returns `None` if the paragraph
+/// has too many break opportunities.
+pub(crate) fn collect_break_points<B: Brush>(
+    layout: &Layout<B>,
+    item_idx: usize,
+    cluster_idx: usize,
+    mut text_wrap_mode: TextWrapMode,
+) -> Option<Vec<BreakPoint>> {
+    let data = &layout.data;
+    let mut points = vec![BreakPoint {
+        item_idx,
+        cluster_idx,
+        x: 0.,
+        content_x: 0.,
+        spaces: 0.,
+        content_spaces: 0.,
+        is_hyphen: false,
+    }];
+    let mut x = 0_f64;
+    let mut trailing_whitespace = 0_f64;
+    let mut spaces = 0_f64;
+    let mut trailing_spaces = 0_f64;
+    let mut is_hyphen = false;
+    let mut cluster_idx = cluster_idx;
+    for (item_idx, item) in data.items.iter().enumerate().skip(item_idx) {
+        if points.len() > MAX_BREAK_POINTS {
+            return None;
+        }
+        match item.kind {
+            LayoutItemKind::InlineBox => {
+                x += f64::from(data.inline_boxes[item.index].width);
+                trailing_whitespace = 0.;
+                trailing_spaces = 0.;
+                is_hyphen = false;
+                // The greedy breaker can always break after an inline box.
+                points.push(BreakPoint {
+                    item_idx: item_idx + 1,
+                    cluster_idx,
+                    x,
+                    content_x: x,
+                    spaces,
+                    content_spaces: spaces,
+                    is_hyphen,
+                });
+            }
+            LayoutItemKind::TextRun => {
+                let run_data = &data.runs[item.index];
+                let run = Run::new(layout, 0, 0, run_data, None);
+                let cluster_start = run_data.cluster_range.start;
+                cluster_idx = cluster_idx.max(cluster_start);
+                while cluster_idx < run_data.cluster_range.end {
+                    let cluster = run.get(cluster_idx - cluster_start).unwrap();
+                    let whitespace = cluster.info().whitespace();
+                    let style = &data.styles[cluster.data.style_index as usize];
+                    // The greedy breaker lags text_wrap_mode by one cluster.
+                    let prev_text_wrap_mode =
+                        core::mem::replace(&mut text_wrap_mode, style.text_wrap_mode);
+
+                    let point = BreakPoint {
+                        item_idx,
+                        cluster_idx,
+                        x,
+                        content_x: x - trailing_whitespace,
+                        spaces,
+                        content_spaces: spaces - trailing_spaces,
+                        is_hyphen,
+                    };
+                    if whitespace == Whitespace::Newline {
+                        points.push(point);
+                        return (points.len() <= MAX_BREAK_POINTS).then_some(points);
+                    }
+                    if cluster.info().boundary() == Boundary::Line
+                        && prev_text_wrap_mode == TextWrapMode::Wrap
+                        && !cluster.is_ligature_continuation()
+                        && x != 0.
+                    {
+                        points.push(point);
+                    }
+
+                    let advance = f64::from(cluster.advance());
+                    x += advance;
+                    if whitespace.is_space_or_nbsp() {
+                        spaces += advance;
+                    }
+                    if whitespace == Whitespace::None {
+                        trailing_whitespace = 0.;
+                        trailing_spaces = 0.;
+                        is_hyphen =
+                            matches!(cluster.info().source_char(), '-' | '\u{00AD}' | '\u{2010}');
+                    } else {
+                        trailing_whitespace += advance;
+                        if whitespace.is_space_or_nbsp() {
+                            trailing_spaces += advance;
+                        }
+                    }
+                    cluster_idx += 1;
+                }
+            }
+        }
+    }
+    points.push(BreakPoint {
+        item_idx: data.items.len(),
+        cluster_idx,
+        x,
+        content_x: x - trailing_whitespace,
+        spaces,
+        content_spaces: spaces - trailing_spaces,
+        is_hyphen: false,
+    });
+    (points.len() <= MAX_BREAK_POINTS).then_some(points)
+}
+
+/// Chooses the lines of a paragraph.
+///
+/// `widths` are the maximum advances of the first lines of the paragraph, and the last of them is
+/// the maximum advance of all the following lines. Returns the indices of the break points that
+/// end each line, or `None` if the paragraph should be broken greedily.
+pub(crate) fn choose_lines(
+    points: &[BreakPoint],
+    widths: &[f32],
+    choice: LineChoice,
+) -> Option<Vec<usize>> {
+    if points.len() <= 2
+        || widths.is_empty()
+        || widths
+            .iter()
+            .any(|&width| width <= 0. || !width.is_finite())
+    {
+        return None;
+    }
+    let widths: Vec<f64> = widths.iter().map(|&width| f64::from(width)).collect();
+    if line_width(points, 0, points.len() - 1) <= widths[0] {
+        // The paragraph fits on a single line.
+        return None;
+    }
+    match choice {
+        LineChoice::Optimal(options) => knuth_plass(points, &widths, &options),
+        LineChoice::Balance => balance(points, &widths),
+        LineChoice::Pretty => {
+            let last = points.len() - 1;
+            Some(minimize(points, &widths, |width, max_advance, end| {
+                let slack = (max_advance - width) / max_advance;
+                if end == last {
+                    // A last line shorter than a third of the maximum advance costs up to as
+                    // much as an extra line.
+                    let shortness = (1. - 3. * width / max_advance).max(0.);
+                    1. + shortness * shortness
+                } else {
+                    1. + slack * slack
+                }
+            }))
+        }
+    }
+}
+
+/// Returns the width of the line from break point `start` to break point `end`.
+fn line_width(points: &[BreakPoint], start: usize, end: usize) -> f64 {
+    points[end].content_x - points[start].x
+}
+
+/// Returns the maximum advance of the line with the given index.
+fn max_advance(widths: &[f64], line: usize) -> f64 {
+    widths[line.min(widths.len() - 1)]
+}
+
+/// Chooses the lines that minimize the sum of `line_cost(width, max_advance, end)` over all
+/// lines.
+fn minimize(
+    points: &[BreakPoint],
+    widths: &[f64],
+    line_cost: impl Fn(f64, f64, usize) -> f64,
+) -> Vec<usize> {
+    // Lines are tracked by their index, up to the index of the last width, after which all
+    // lines have the same maximum advance.
+    let classes = widths.len();
+    let widest = widths.iter().copied().fold(0., f64::max);
+
+    // best[point * classes + class] is the lowest cost of the lines before the point, and the
+    // point and class of the start of the last of those lines.
+    let mut best = vec![(f64::INFINITY, 0, 0); points.len() * classes];
+    best[0].0 = 0.;
+    for end in 1..points.len() {
+        for start in (0..end).rev() {
+            let width = line_width(points, start, end);
+            // Lines only get wider as they start earlier, so only a single overfull segment is
+            // considered.
+            if width > widest && start != end - 1 {
+                break;
+            }
+            for class in 0..classes {
+                let (total, _, _) = best[start * classes + class];
+                if total.is_infinite() {
+                    continue;
+                }
+                let max_advance = widths[class];
+                let cost = if width > max_advance {
+                    if start != end - 1 {
+                        continue;
+                    }
+                    OVERFULL_COST
+                } else {
+                    line_cost(width, max_advance, end)
+                };
+                let next = &mut best[end * classes + (class + 1).min(classes - 1)];
+                if total + cost < next.0 {
+                    *next = (total + cost, start, class);
+                }
+            }
+        }
+    }
+    let last = points.len() - 1;
+    let mut class = (0..classes)
+        .min_by(|a, b| {
+            best[last * classes + a]
+                .0
+                .total_cmp(&best[last * classes + b].0)
+        })
+        .unwrap_or(0);
+    let mut ends = Vec::new();
+    let mut end = last;
+    while end != 0 {
+        ends.push(end);
+        let (_, start, start_class) = best[end * classes + class];
+        end = start;
+        class = start_class;
+    }
+    ends.reverse();
+    ends
+}
+
+/// Chooses the same number of lines as greedy breaking, minimizing the sum of the squared
+/// slack of every line.
+fn balance(points: &[BreakPoint], widths: &[f64]) -> Option<Vec<usize>> {
+    // Count the lines of greedy breaking.
+    let last = points.len() - 1;
+    let mut line_count = 0;
+    let mut start = 0;
+    while start != last {
+        let max_advance = max_advance(widths, line_count);
+        let mut end = start + 1;
+        while end < last && line_width(points, start, end + 1) <= max_advance {
+            end += 1;
+        }
+        start = end;
+        line_count += 1;
+    }
+    if line_count <= 1 || line_count > MAX_BALANCED_LINES {
+        return None;
+    }
+
+    // best[lines][point] is the lowest cost of breaking the text before the point into the
+    // given number of lines, and the start of the last of those lines.
+    let mut best = vec![vec![(f64::INFINITY, 0); points.len()]; line_count + 1];
+    best[0][0].0 = 0.;
+    for lines in 1..=line_count {
+        let max_advance = max_advance(widths, lines - 1);
+        for end in 1..points.len() {
+            for start in (0..end).rev() {
+                let width = line_width(points, start, end);
+                let cost = if width > max_advance {
+                    if start != end - 1 {
+                        break;
+                    }
+                    OVERFULL_COST
+                } else {
+                    let slack = max_advance - width;
+                    slack * slack
+                };
+                let cost = best[lines - 1][start].0 + cost;
+                if cost < best[lines][end].0 {
+                    best[lines][end] = (cost, start);
+                }
+            }
+        }
+    }
+    if best[line_count][last].0.is_infinite() {
+        return None;
+    }
+    let mut ends = Vec::with_capacity(line_count);
+    let mut end = last;
+    for lines in (1..=line_count).rev() {
+        ends.push(end);
+        end = best[lines][end].1;
+    }
+    ends.reverse();
+    Some(ends)
+}
+
+/// Chooses the lines that minimize the total demerits with the Knuth–Plass algorithm.
+fn knuth_plass(
+    points: &[BreakPoint],
+    widths: &[f64],
+    options: &OptimalBreakOptions,
+) -> Option<Vec<usize>> {
+    let last = points.len() - 1;
+    let stretch_ratio = f64::from(options.space_stretch.max(0.));
+    let shrink_ratio = f64::from(options.space_shrink.max(0.));
+    let tolerance = f64::from(options.tolerance);
+    let line_penalty = f64::from(options.line_penalty);
+    let hyphen_penalty = f64::from(options.hyphen_penalty);
+    let consecutive_hyphen_demerits = f64::from(options.consecutive_hyphen_demerits);
+    let widest = widths.iter().copied().fold(0., f64::max);
+
+    // Lines are tracked by the number of consecutive lines ending with a hyphen before them,
+    // up to the limit. Without a limit, only whether the previous line ended with a hyphen
+    // matters.
+    let max_hyphens = options.max_consecutive_hyphens.map(usize::from);
+    let hyphen_states = max_hyphens.map_or(2, |max| max + 1);
+    // Lines are also tracked by their index, up to the index of the last width, after which all
+    // lines have the same maximum advance.
+    let classes = widths.len();
+    let states = classes * hyphen_states;
+
+    // best[point * states + class * hyphen_states + hyphens] is the lowest total demerits of
+    // the lines before the point, and the point and state of the start of the last of those
+    // lines.
+    let mut best = vec![(f64::INFINITY, 0, 0); points.len() * states];
+    best[0].0 = 0.;
+    for end in 1..points.len() {
+        let is_hyphen = points[end].is_hyphen && end != last;
+        for start in (0..end).rev() {
+            let width = line_width(points, start, end);
+            let spaces = points[end].content_spaces - points[start].spaces;
+            let stretch = spaces * stretch_ratio;
+            // The last line is not justified, so it can't shrink.
+            let shrink = if end == last {
+                0.
+            } else {
+                spaces * shrink_ratio
+            };
+            // Lines only get wider as they start earlier, so only a single overfull segment is
+            // considered.
+            if width - shrink > widest && start != end - 1 {
+                break;
+            }
+            for (class, &max_advance) in widths.iter().enumerate() {
+                let demerits = if width - shrink > max_advance {
+                    if start != end - 1 {
+                        continue;
+                    }
+                    OVERFULL_COST
+                } else {
+                    let badness = if width > max_advance {
+                        100. * ((width - max_advance) / shrink).powi(3)
+                    } else if end == last || width == max_advance {
+                        0.
+                    } else if stretch > 0. {
+                        100. * ((max_advance - width) / stretch).powi(3)
+                    } else {
+                        MAX_BADNESS
+                    }
+                    .min(MAX_BADNESS);
+                    let mut demerits = (line_penalty + badness).powi(2);
+                    if badness > tolerance {
+                        demerits += INFEASIBLE_COST;
+                    }
+                    if is_hyphen {
+                        demerits += hyphen_penalty * hyphen_penalty.abs();
+                    }
+                    demerits
+                };
+                let next_class = (class + 1).min(classes - 1);
+                for hyphens in 0..hyphen_states {
+                    let state = class * hyphen_states + hyphens;
+                    let (total, _, _) = best[start * states + state];
+                    if total.is_infinite() {
+                        continue;
+                    }
+                    let mut total = total + demerits;
+                    let next_hyphens = if is_hyphen {
+                        if hyphens > 0 {
+                            total += consecutive_hyphen_demerits;
+                        }
+                        if max_hyphens.is_some_and(|max| hyphens >= max) {
+                            continue;
+                        }
+                        (hyphens + 1).min(hyphen_states - 1)
+                    } else {
+                        0
+                    };
+                    let next = &mut best[end * states + next_class * hyphen_states + next_hyphens];
+                    if total < next.0 {
+                        *next = (total, start, state);
+                    }
+                }
+            }
+        }
+    }
+
+    // The end of the paragraph is unreachable if the hyphen limit rules out every break.
+    let mut state = (0..states)
+        .filter(|state| best[last * states + state].0.is_finite())
+        .min_by(|a, b| {
+            best[last * states + a]
+                .0
+                .total_cmp(&best[last * states + b].0)
+        })?;
+    let mut ends = Vec::new();
+    let mut end = last;
+    while end != 0 {
+        ends.push(end);
+        let (_, start, start_state) = best[end * states + state];
+        end = start;
+        state = start_state;
+    }
+    ends.reverse();
+    Some(ends)
+}
@@ -0,0 +1,149 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Splitting the lines of a layout into pages.
+
+use core::ops::Range;
+
+use crate::BreakInside;
+use crate::layout::flow::{ContainerLines, Flow};
+use crate::layout::{BreakReason, Layout, LayoutItemKind};
+use crate::style::Brush;
+
+/// Options for [`Layout::paginate`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct PaginationOptions {
+    /// The minimum number of lines of a paragraph left at the bottom of a page before a break.
+    ///
+    /// See: <https://www.w3.org/TR/css-break-3/#widows-orphans>
+    pub orphans: usize,
+    /// The minimum number of lines of a paragraph carried over to the top of the next page
+    /// after a break.
+    ///
+    /// See: <https://www.w3.org/TR/css-break-3/#widows-orphans>
+    pub widows: usize,
+}
+
+impl Default for PaginationOptions {
+    fn default() -> Self {
+        Self {
+            orphans: 2,
+            widows: 2,
+        }
+    }
+}
+
+/// Splits the lines of the layout into pages with the given heights.
+pub(crate) fn paginate<B: Brush>(
+    layout: &Layout<B>,
+    page_heights: impl IntoIterator<Item = f32>,
+    options: PaginationOptions,
+) -> Flow {
+    let lines = &layout.data.lines;
+    let mut flow = Flow::default();
+    let mut page_heights = page_heights.into_iter();
+    let mut start = 0;
+    let mut y = 0_f64;
+    while start < lines.len() {
+        let Some(page_height) = page_heights.next() else {
+            break;
+        };
+
+        // The lines that fit on the page, keeping at least one line so that pagination makes
+        // progress.
+        let mut height = 0_f64;
+        let mut end = start;
+        while end < lines.len() {
+            let line_height = f64::from(lines[end].metrics.line_height);
+            if end > start && height + line_height > f64::from(page_height) {
+                break;
+            }
+            height += line_height;
+            end += 1;
+        }
+
+        // Move the break up to the nearest allowed position. If there is none, the constraints
+        // are ignored for this page.
+        if end < lines.len() {
+            if let Some(allowed) = (start + 1..=end)
+                .rev()
+                .find(|&index| is_break_allowed(layout, start, index, options))
+            {
+                end = allowed;
+            }
+        }
+
+        let height: f64 = lines[start..end]
+            .iter()
+            .map(|line| f64::from(line.metrics.line_height))
+            .sum();
+        flow.containers.push(ContainerLines {
+            lines: start..end,
+            y: y as f32,
+            height: height as f32,
+        });
+        y += height;
+        start = end;
+    }
+    flow.overflow = start..lines.len();
+    flow
+}
+
+/// Returns `true` if a page that starts with the line at `page_start` can break before the line
+/// at `index`.
+fn is_break_allowed<B: Brush>(
+    layout: &Layout<B>,
+    page_start: usize,
+    index: usize,
+    options: PaginationOptions,
+) -> bool {
+    let lines = &layout.data.lines;
+    let ends_paragraph = |line: usize| {
+        matches!(
+            lines[line].break_reason,
+            BreakReason::Explicit | BreakReason::None
+        )
+    };
+    if ends_paragraph(index - 1) {
+        // Breaks between paragraphs are always allowed.
+        return true;
+    }
+
+    let paragraph_start = (0..index - 1)
+        .rev()
+        .find(|&line| ends_paragraph(line))
+        .map_or(0, |line| line + 1);
+    let paragraph_end = (index..lines.len())
+        .find(|&line| ends_paragraph(line))
+        .map_or(lines.len(), |line| line + 1);
+    if index - paragraph_start.max(page_start) < options.orphans
+        || paragraph_end - index < options.widows
+    {
+        return false;
+    }
+
+    // Avoid breaking between two lines of text that both avoid breaks inside.
+    let (Some(before), Some(after)) = (
+        cluster_range(layout, index - 1).map(|clusters| clusters.end),
+        cluster_range(layout, index).map(|clusters| clusters.start),
+    ) else {
+        return true;
+    };
+    let break_inside = |cluster: usize| {
+        layout.data.styles[layout.data.clusters[cluster].style_index as usize].break_inside
+    };
+    !(before > 0
+        && after < layout.data.clusters.len()
+        && break_inside(before - 1) == BreakInside::Avoid
+        && break_inside(after) == BreakInside::Avoid)
+}
+
+/// Returns the logical range of the clusters in the text runs of the line.
+fn cluster_range<B: Brush>(layout: &Layout<B>, line: usize) -> Option<Range<usize>> {
+    let line = &layout.data.lines[line];
+    layout.data.line_items[line.item_range.clone()]
+        .iter()
+        .filter(|item| item.kind == LayoutItemKind::TextRun && !item.cluster_range.is_empty())
+        .map(|item| item.cluster_range.clone())
+        .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+}
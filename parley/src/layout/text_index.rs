@@ -0,0 +1,351 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conversion between byte, UTF-16 and char offsets.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// The number of bytes of text between checkpoints, which bounds how much of the text is scanned
+/// for a conversion.
+const CHUNK_LEN: usize = 256;
+
+/// An index for converting between the UTF-8 byte offsets used throughout Parley and the UTF-16
+/// code unit and char offsets used by platform text input and accessibility APIs.
+///
+/// The index stores the UTF-16 and char offsets of a checkpoint about every 256 bytes of the
+/// text, so a conversion is a binary search over the checkpoints followed by a scan of at most
+/// that much text, and the index is small compared to the text. Conversions take the text that
+/// the index was built for, or updated to.
+///
+/// A [`PlainEditor`](crate::PlainEditor) keeps an index of its text, which is available from
+/// [`PlainEditor::text_index`](crate::PlainEditor::text_index).
+#[derive(Clone, Debug, Default)]
+pub struct TextIndex {
+    /// The checkpoints, in order, on char boundaries. Unless the text is empty, the first one is
+    /// at its start.
+    checkpoints: Vec<Checkpoint>,
+    len_utf8: usize,
+    len_utf16: usize,
+    len_chars: usize,
+}
+
+/// A position in the text, in each of the units.
+#[derive(Clone, Copy, Debug, Default)]
+struct Checkpoint {
+    byte: usize,
+    utf16: usize,
+    char: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Byte,
+    Utf16,
+    Char,
+}
+
+impl Checkpoint {
+    fn get(&self, unit: Unit) -> usize {
+        match unit {
+            Unit::Byte => self.byte,
+            Unit::Utf16 => self.utf16,
+            Unit::Char => self.char,
+        }
+    }
+
+    /// Returns the position after `text`, which starts at this position.
+    fn advance(self, text: &str) -> Self {
+        let chars = text.chars().count();
+        // Only chars that take four bytes in UTF-8 need a surrogate pair in UTF-16, and their
+        // leading bytes are the only ones from 0xF0.
+        let surrogates = text.bytes().filter(|&byte| byte >= 0xF0).count();
+        Self {
+            byte: self.byte + text.len(),
+            utf16: self.utf16 + chars + surrogates,
+            char: self.char + chars,
+        }
+    }
+}
+
+impl TextIndex {
+    /// Create an index for `text`.
+    pub fn new(text: &str) -> Self {
+        let mut index = Self::default();
+        index.rebuild(text);
+        index
+    }
+
+    /// Rebuild the index for `text`, reusing its allocation.
+    pub fn rebuild(&mut self, text: &str) {
+        self.checkpoints.clear();
+        if !text.is_empty() {
+            self.checkpoints.push(Checkpoint::default());
+        }
+        let end = scan(
+            &mut self.checkpoints,
+            text,
+            Checkpoint::default(),
+            text.len(),
+        );
+        self.len_utf8 = end.byte;
+        self.len_utf16 = end.utf16;
+        self.len_chars = end.char;
+    }
+
+    /// Update the index after the bytes in `range` of the text were replaced with `len` bytes,
+    /// given the new `text`.
+    ///
+    /// Only the checkpoints around the edit are recomputed, and the ones after it are shifted, so
+    /// this doesn't scan the whole text.
+    pub fn replace_range(&mut self, text: &str, range: Range<usize>, len: usize) {
+        debug_assert_eq!(
+            text.len() + range.len(),
+            self.len_utf8 + len,
+            "the edit doesn't match the length of the text"
+        );
+        if self.checkpoints.is_empty() {
+            self.rebuild(text);
+            return;
+        }
+        // The text before the edit is unchanged, and the text after it is only moved.
+        let first = self
+            .checkpoints
+            .partition_point(|checkpoint| checkpoint.byte <= range.start);
+        let start = self.checkpoints[first - 1];
+        let after = self
+            .checkpoints
+            .partition_point(|checkpoint| checkpoint.byte < range.end)
+            .max(first);
+        let old_end = self.checkpoints.get(after).copied().unwrap_or(Checkpoint {
+            byte: self.len_utf8,
+            utf16: self.len_utf16,
+            char: self.len_chars,
+        });
+        let mut checkpoints = Vec::new();
+        let end = scan(
+            &mut checkpoints,
+            text,
+            start,
+            old_end.byte + len - range.len(),
+        );
+        let shift = |checkpoint: &mut Checkpoint| {
+            checkpoint.byte = checkpoint.byte - old_end.byte + end.byte;
+            checkpoint.utf16 = checkpoint.utf16 - old_end.utf16 + end.utf16;
+            checkpoint.char = checkpoint.char - old_end.char + end.char;
+        };
+        self.checkpoints[after..].iter_mut().for_each(shift);
+        self.checkpoints.splice(first..after, checkpoints);
+        let mut len = Checkpoint {
+            byte: self.len_utf8,
+            utf16: self.len_utf16,
+            char: self.len_chars,
+        };
+        shift(&mut len);
+        self.len_utf8 = len.byte;
+        self.len_utf16 = len.utf16;
+        self.len_chars = len.char;
+    }
+
+    /// Returns the length of the text in bytes.
+    pub fn len_utf8(&self) -> usize {
+        self.len_utf8
+    }
+
+    /// Returns the length of the text in UTF-16 code units.
+    pub fn len_utf16(&self) -> usize {
+        self.len_utf16
+    }
+
+    /// Returns the length of the text in chars.
+    pub fn len_chars(&self) -> usize {
+        self.len_chars
+    }
+
+    /// Converts a byte offset in `text` to a UTF-16 offset.
+    ///
+    /// Returns `None` if `index` is out of bounds or not on a char boundary.
+    pub fn byte_to_utf16(&self, text: &str, index: usize) -> Option<usize> {
+        self.convert(text, index, Unit::Byte, Unit::Utf16)
+    }
+
+    /// Converts a UTF-16 offset in `text` to a byte offset.
+    ///
+    /// Returns `None` if `index` is out of bounds or within a surrogate pair.
+    pub fn utf16_to_byte(&self, text: &str, index: usize) -> Option<usize> {
+        self.convert(text, index, Unit::Utf16, Unit::Byte)
+    }
+
+    /// Converts a byte offset in `text` to a char offset.
+    ///
+    /// Returns `None` if `index` is out of bounds or not on a char boundary.
+    pub fn byte_to_char(&self, text: &str, index: usize) -> Option<usize> {
+        self.convert(text, index, Unit::Byte, Unit::Char)
+    }
+
+    /// Converts a char offset in `text` to a byte offset.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn char_to_byte(&self, text: &str, index: usize) -> Option<usize> {
+        self.convert(text, index, Unit::Char, Unit::Byte)
+    }
+
+    /// Converts a UTF-16 offset in `text` to a char offset.
+    ///
+    /// Returns `None` if `index` is out of bounds or within a surrogate pair.
+    pub fn utf16_to_char(&self, text: &str, index: usize) -> Option<usize> {
+        self.convert(text, index, Unit::Utf16, Unit::Char)
+    }
+
+    /// Converts a char offset in `text` to a UTF-16 offset.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn char_to_utf16(&self, text: &str, index: usize) -> Option<usize> {
+        self.convert(text, index, Unit::Char, Unit::Utf16)
+    }
+
+    fn len(&self, unit: Unit) -> usize {
+        match unit {
+            Unit::Byte => self.len_utf8,
+            Unit::Utf16 => self.len_utf16,
+            Unit::Char => self.len_chars,
+        }
+    }
+
+    fn convert(&self, text: &str, index: usize, from: Unit, to: Unit) -> Option<usize> {
+        debug_assert_eq!(
+            text.len(),
+            self.len_utf8,
+            "the text doesn't match the index"
+        );
+        if index >= self.len(from) {
+            return (index == self.len(from)).then(|| self.len(to));
+        }
+        // The first checkpoint is at the start of the text, so there is always one before
+        // `index`.
+        let mut position = self.checkpoints[self
+            .checkpoints
+            .partition_point(|checkpoint| checkpoint.get(from) <= index)
+            - 1];
+        for c in text[position.byte..].chars() {
+            if position.get(from) >= index {
+                break;
+            }
+            position = Checkpoint {
+                byte: position.byte + c.len_utf8(),
+                utf16: position.utf16 + c.len_utf16(),
+                char: position.char + 1,
+            };
+        }
+        (position.get(from) == index).then(|| position.get(to))
+    }
+}
+
+/// Push a checkpoint about every [`CHUNK_LEN`] bytes of `text` after `start`, up to `end`, which
+/// is a char boundary, and return the position at `end`.
+fn scan(
+    checkpoints: &mut Vec<Checkpoint>,
+    text: &str,
+    mut position: Checkpoint,
+    end: usize,
+) -> Checkpoint {
+    while position.byte < end {
+        let mut next = (position.byte + CHUNK_LEN).min(end);
+        while !text.is_char_boundary(next) {
+            next += 1;
+        }
+        position = position.advance(&text[position.byte..next]);
+        if position.byte < end {
+            checkpoints.push(position);
+        }
+    }
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextIndex;
+    use alloc::string::String;
+
+    /// Check the conversions of `index` for every offset in `text` against a scan of the text.
+    fn assert_matches_scan(index: &TextIndex, text: &str) {
+        assert_eq!(index.len_utf8(), text.len());
+        assert_eq!(index.len_utf16(), text.encode_utf16().count());
+        assert_eq!(index.len_chars(), text.chars().count());
+
+        let mut utf16 = 0;
+        for (char, (byte, c)) in text.char_indices().enumerate() {
+            assert_eq!(index.byte_to_utf16(text, byte), Some(utf16));
+            assert_eq!(index.byte_to_char(text, byte), Some(char));
+            assert_eq!(index.utf16_to_byte(text, utf16), Some(byte));
+            assert_eq!(index.utf16_to_char(text, utf16), Some(char));
+            assert_eq!(index.char_to_byte(text, char), Some(byte));
+            assert_eq!(index.char_to_utf16(text, char), Some(utf16));
+            for inner in 1..c.len_utf8() {
+                assert_eq!(index.byte_to_utf16(text, byte + inner), None);
+            }
+            if c.len_utf16() == 2 {
+                assert_eq!(index.utf16_to_byte(text, utf16 + 1), None);
+            }
+            utf16 += c.len_utf16();
+        }
+        assert_eq!(index.byte_to_utf16(text, text.len()), Some(utf16));
+        assert_eq!(
+            index.char_to_byte(text, index.len_chars()),
+            Some(text.len())
+        );
+        assert_eq!(index.byte_to_utf16(text, text.len() + 1), None);
+        assert_eq!(index.char_to_utf16(text, index.len_chars() + 1), None);
+    }
+
+    #[test]
+    fn conversions_match_scan() {
+        let text = "aé😀€bc😀😀d";
+        assert_matches_scan(&TextIndex::new(text), text);
+        // Text longer than a chunk has several checkpoints.
+        let text = text.repeat(100);
+        assert_matches_scan(&TextIndex::new(&text), &text);
+    }
+
+    #[test]
+    fn replace_range() {
+        let mut text = String::from("aé😀€bc😀😀d").repeat(60);
+        let mut index = TextIndex::new(&text);
+        let long = "é".repeat(300);
+        let edits = [
+            (0..0, "€"),
+            (10..300, ""),
+            (200..204, "xy😀z"),
+            (3..3, long.as_str()),
+            (500..1000, "b"),
+        ];
+        for (range, replacement) in edits {
+            let boundary = |mut index: usize| {
+                while !text.is_char_boundary(index) {
+                    index += 1;
+                }
+                index
+            };
+            let range = boundary(range.start)..boundary(range.end);
+            text.replace_range(range.clone(), replacement);
+            index.replace_range(&text, range, replacement.len());
+            assert_matches_scan(&index, &text);
+        }
+        let len = text.len();
+        text.clear();
+        index.replace_range(&text, 0..len, 0);
+        assert_matches_scan(&index, &text);
+        text.push('😀');
+        index.replace_range(&text, 0..0, 4);
+        assert_matches_scan(&index, &text);
+    }
+
+    #[test]
+    fn empty_text() {
+        let mut index = TextIndex::new("abc");
+        index.rebuild("");
+        assert_eq!(index.len_utf16(), 0);
+        assert_eq!(index.byte_to_utf16("", 0), Some(0));
+        assert_eq!(index.utf16_to_char("", 1), None);
+    }
+}
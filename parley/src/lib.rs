@@ -10,8 +10,9 @@
 //! - [`RangedBuilder`] and [`TreeBuilder`] which are builders for creating a [`Layout`].
 //!     - [`RangedBuilder`] allows styles to be specified as a flat `Vec` of spans
 //!     - [`TreeBuilder`] allows styles to be specified as a tree of spans
+//!     - [`AttributedBuilder`] lays out an [`AttributedText`](attributed_text::AttributedText) whose attributes implement [`LayoutAttribute`]
 //!
-//!   They are constructed using the [`ranged_builder`](LayoutContext::ranged_builder) and [`tree_builder`](LayoutContext::ranged_builder) methods on [`LayoutContext`].
+//!   They are constructed using the [`ranged_builder`](LayoutContext::ranged_builder), [`tree_builder`](LayoutContext::ranged_builder) and [`attributed_builder`](LayoutContext::attributed_builder) methods on [`LayoutContext`].
 //! - [`Layout`] which represents styled paragraph(s) of text and can perform shaping, line-breaking, bidi-reordering, and alignment of that text.
 //!
 //!   `Layout` supports re-linebreaking and re-aligning many times (in case the width at which wrapping should occur changes). But if the text content or
@@ -103,6 +104,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub use attributed_text;
 pub use fontique;
 
 mod analysis;
@@ -122,13 +124,16 @@ pub mod layout;
 pub mod setting;
 pub mod style;
 
-#[cfg(test)]
+#[cfg(all(test, any(feature = "baked_data", feature = "runtime_data")))]
 mod tests;
 
+#[cfg(feature = "runtime_data")]
+pub use analysis::data::{UnicodeData, UnicodeDataError};
+pub use analysis::dictionary::{DictionaryError, DictionaryMode, SegmentationDictionary};
 pub use linebender_resource_handle::FontData;
 pub use util::BoundingBox;
 
-pub use builder::{RangedBuilder, TreeBuilder};
+pub use builder::{AttributedBuilder, LayoutAttribute, RangedBuilder, TreeBuilder};
 pub use context::LayoutContext;
 pub use font::FontContext;
 pub use inline_box::InlineBox;
@@ -11,14 +11,14 @@ pub(crate) use range::RangedStyleBuilder;
 use alloc::{vec, vec::Vec};
 
 use super::style::{
-    Brush, FontFamily, FontFamilyName, FontFeature, FontFeatures, FontStyle, FontVariation,
-    FontVariations, FontWeight, FontWidth, StyleProperty,
+    Brush, FontFamily, FontFamilyName, FontFeature, FontFeatures, FontOpticalSizing, FontStyle,
+    FontVariation, FontVariations, FontWeight, FontWidth, StyleProperty,
 };
 use crate::font::FontContext;
 use crate::style::TextStyle;
 use crate::util::nearly_eq;
+use crate::{BreakInside, InitialLetter, LineBreak, TextWrapMode, TextWrapStyle, WordBreak};
 use crate::{LineHeight, OverflowWrap, layout};
-use crate::{TextWrapMode, WordBreak};
 use core::borrow::Borrow;
 use core::ops::Range;
 use fontique::FamilyId;
@@ -141,6 +141,7 @@ impl ResolveContext {
             StyleProperty::FontWeight(value) => FontWeight(*value),
             StyleProperty::FontVariations(value) => FontVariations(self.resolve_variations(value)),
             StyleProperty::FontFeatures(value) => FontFeatures(self.resolve_features(value)),
+            StyleProperty::FontOpticalSizing(value) => FontOpticalSizing(*value),
             StyleProperty::Locale(value) => Locale(*value),
             StyleProperty::Brush(value) => Brush(value.clone()),
             StyleProperty::Underline(value) => Underline(*value),
@@ -157,8 +158,12 @@ impl ResolveContext {
             StyleProperty::WordSpacing(value) => WordSpacing(*value * scale),
             StyleProperty::LetterSpacing(value) => LetterSpacing(*value * scale),
             StyleProperty::WordBreak(value) => WordBreak(*value),
+            StyleProperty::LineBreak(value) => LineBreak(*value),
             StyleProperty::OverflowWrap(value) => OverflowWrap(*value),
             StyleProperty::TextWrapMode(value) => TextWrapMode(*value),
+            StyleProperty::TextWrapStyle(value) => TextWrapStyle(*value),
+            StyleProperty::BreakInside(value) => BreakInside(*value),
+            StyleProperty::InitialLetter(value) => InitialLetter(*value),
         }
     }
 
@@ -176,6 +181,7 @@ impl ResolveContext {
             font_weight: raw_style.font_weight,
             font_variations: self.resolve_variations(&raw_style.font_variations),
             font_features: self.resolve_features(&raw_style.font_features),
+            font_optical_sizing: raw_style.font_optical_sizing,
             locale: raw_style.locale,
             brush: raw_style.brush.clone(),
             underline: ResolvedDecoration {
@@ -194,8 +200,12 @@ impl ResolveContext {
             word_spacing: raw_style.word_spacing * scale,
             letter_spacing: raw_style.letter_spacing * scale,
             word_break: raw_style.word_break,
+            line_break: raw_style.line_break,
             overflow_wrap: raw_style.overflow_wrap,
             text_wrap_mode: raw_style.text_wrap_mode,
+            text_wrap_style: raw_style.text_wrap_style,
+            break_inside: raw_style.break_inside,
+            initial_letter: raw_style.initial_letter,
         }
     }
 
@@ -348,6 +358,8 @@ pub(crate) enum ResolvedProperty<B: Brush> {
     FontVariations(Resolved<FontVariation>),
     /// Font feature settings.
     FontFeatures(Resolved<FontFeature>),
+    /// Automatic optical sizing.
+    FontOpticalSizing(FontOpticalSizing),
     /// Locale.
     Locale(Option<Language>),
     /// Brush for rendering text.
@@ -376,10 +388,18 @@ pub(crate) enum ResolvedProperty<B: Brush> {
     LetterSpacing(f32),
     /// Control over where words can wrap.
     WordBreak(WordBreak),
+    /// Control over the strictness of line-breaking rules.
+    LineBreak(LineBreak),
     /// Control over "emergency" line-breaking.
     OverflowWrap(OverflowWrap),
     /// Control over non-"emergency" line-breaking.
     TextWrapMode(TextWrapMode),
+    /// Control over how wrapped lines are balanced.
+    TextWrapStyle(TextWrapStyle),
+    /// Control over page and column breaks between lines.
+    BreakInside(BreakInside),
+    /// Initial letter, or `None` for normal text.
+    InitialLetter(Option<InitialLetter>),
 }
 
 /// Flattened group of style properties.
@@ -399,6 +419,8 @@ pub(crate) struct ResolvedStyle<B: Brush> {
     pub(crate) font_variations: Resolved<FontVariation>,
     /// Font feature settings.
     pub(crate) font_features: Resolved<FontFeature>,
+    /// Automatic optical sizing.
+    pub(crate) font_optical_sizing: FontOpticalSizing,
     /// Locale.
     pub(crate) locale: Option<Language>,
     /// Brush for rendering text.
@@ -415,10 +437,18 @@ pub(crate) struct ResolvedStyle<B: Brush> {
     pub(crate) letter_spacing: f32,
     /// Control over where words can wrap.
     pub(crate) word_break: WordBreak,
+    /// Control over the strictness of line-breaking rules.
+    pub(crate) line_break: LineBreak,
     /// Control over "emergency" line-breaking.
     pub(crate) overflow_wrap: OverflowWrap,
     /// Control over non-"emergency" line-breaking.
     pub(crate) text_wrap_mode: TextWrapMode,
+    /// Control over how wrapped lines are balanced.
+    pub(crate) text_wrap_style: TextWrapStyle,
+    /// Control over page and column breaks between lines.
+    pub(crate) break_inside: BreakInside,
+    /// Initial letter, or `None` for normal text.
+    pub(crate) initial_letter: Option<InitialLetter>,
 }
 
 impl<B: Brush> ResolvedStyle<B> {
@@ -433,6 +463,7 @@ impl<B: Brush> ResolvedStyle<B> {
             FontWeight(value) => self.font_weight = value,
             FontVariations(value) => self.font_variations = value,
             FontFeatures(value) => self.font_features = value,
+            FontOpticalSizing(value) => self.font_optical_sizing = value,
             Locale(value) => self.locale = value,
             Brush(value) => self.brush = value,
             Underline(value) => self.underline.enabled = value,
@@ -447,8 +478,12 @@ impl<B: Brush> ResolvedStyle<B> {
             WordSpacing(value) => self.word_spacing = value,
             LetterSpacing(value) => self.letter_spacing = value,
             WordBreak(value) => self.word_break = value,
+            LineBreak(value) => self.line_break = value,
             OverflowWrap(value) => self.overflow_wrap = value,
             TextWrapMode(value) => self.text_wrap_mode = value,
+            TextWrapStyle(value) => self.text_wrap_style = value,
+            BreakInside(value) => self.break_inside = value,
+            InitialLetter(value) => self.initial_letter = value,
         }
     }
 
@@ -462,6 +497,7 @@ impl<B: Brush> ResolvedStyle<B> {
             FontWeight(value) => self.font_weight == *value,
             FontVariations(value) => self.font_variations == *value,
             FontFeatures(value) => self.font_features == *value,
+            FontOpticalSizing(value) => self.font_optical_sizing == *value,
             Locale(value) => self.locale == *value,
             Brush(value) => self.brush == *value,
             Underline(value) => self.underline.enabled == *value,
@@ -476,8 +512,12 @@ impl<B: Brush> ResolvedStyle<B> {
             WordSpacing(value) => nearly_eq(self.word_spacing, *value),
             LetterSpacing(value) => nearly_eq(self.letter_spacing, *value),
             WordBreak(value) => self.word_break == *value,
+            LineBreak(value) => self.line_break == *value,
             OverflowWrap(value) => self.overflow_wrap == *value,
             TextWrapMode(value) => self.text_wrap_mode == *value,
+            TextWrapStyle(value) => self.text_wrap_style == *value,
+            BreakInside(value) => self.break_inside == *value,
+            InitialLetter(value) => self.initial_letter == *value,
         }
     }
 
@@ -489,6 +529,9 @@ impl<B: Brush> ResolvedStyle<B> {
             line_height: self.line_height,
             overflow_wrap: self.overflow_wrap,
             text_wrap_mode: self.text_wrap_mode,
+            text_wrap_style: self.text_wrap_style,
+            break_inside: self.break_inside,
+            initial_letter: self.initial_letter,
         }
     }
 }
@@ -42,6 +42,7 @@ pub(crate) struct ShapeInstanceId {
     /// The font's index in the font collection.
     font_index: u32,
     synthesis: fontique::Synthesis,
+    optical_size: Option<f32>,
     variations: Option<Box<[FontVariation]>>,
 }
 
@@ -51,6 +52,7 @@ pub(crate) struct ShapeInstanceKey<'a> {
     /// The font's index in the font collection.
     font_index: u32,
     synthesis: &'a fontique::Synthesis,
+    optical_size: Option<f32>,
     variations: Option<&'a [FontVariation]>,
 }
 
@@ -59,12 +61,14 @@ impl<'a> ShapeInstanceKey<'a> {
         font_blob_id: u64,
         font_index: u32,
         synthesis: &'a fontique::Synthesis,
+        optical_size: Option<f32>,
         variations: Option<&'a [FontVariation]>,
     ) -> Self {
         Self {
             font_blob_id,
             font_index,
             synthesis,
+            optical_size,
             variations,
         }
     }
@@ -76,6 +80,7 @@ impl<'a> Equivalent<ShapeInstanceId> for ShapeInstanceKey<'a> {
         self.font_blob_id == key.font_blob_id
             && self.font_index == key.font_index
             && *self.synthesis == key.synthesis
+            && self.optical_size == key.optical_size
             && self.variations == key.variations.as_deref()
     }
 }
@@ -87,6 +92,7 @@ impl<'a> From<ShapeInstanceKey<'a>> for ShapeInstanceId {
             font_blob_id: key.font_blob_id,
             font_index: key.font_index,
             synthesis: *key.synthesis,
+            optical_size: key.optical_size,
             variations: key.variations.map(|v| v.to_vec().into()),
         }
     }
@@ -98,6 +104,7 @@ pub(crate) struct ShapePlanId {
     /// The font's index in the font collection.
     font_index: u32,
     synthesis: fontique::Synthesis,
+    optical_size: Option<f32>,
     direction: harfrust::Direction,
     script: harfrust::Script,
     language: Option<harfrust::Language>,
@@ -111,6 +118,7 @@ pub(crate) struct ShapePlanKey<'a> {
     /// The font's index in the font collection.
     font_index: u32,
     synthesis: &'a fontique::Synthesis,
+    optical_size: Option<f32>,
     direction: harfrust::Direction,
     script: harfrust::Script,
     language: Option<harfrust::Language>,
@@ -123,6 +131,7 @@ impl<'a> ShapePlanKey<'a> {
         font_blob_id: u64,
         font_index: u32,
         synthesis: &'a fontique::Synthesis,
+        optical_size: Option<f32>,
         direction: harfrust::Direction,
         script: harfrust::Script,
         language: Option<harfrust::Language>,
@@ -133,6 +142,7 @@ impl<'a> ShapePlanKey<'a> {
             font_blob_id,
             font_index,
             synthesis,
+            optical_size,
             direction,
             script,
             language,
@@ -148,6 +158,7 @@ impl<'a> Equivalent<ShapePlanId> for ShapePlanKey<'a> {
         self.font_blob_id == key.font_blob_id
             && self.font_index == key.font_index
             && *self.synthesis == key.synthesis
+            && self.optical_size == key.optical_size
             && self.direction == key.direction
             && self.script == key.script
             && self.language == key.language
@@ -168,6 +179,7 @@ impl<'a> From<ShapePlanKey<'a>> for ShapePlanId {
             font_blob_id: key.font_blob_id,
             font_index: key.font_index,
             synthesis: *key.synthesis,
+            optical_size: key.optical_size,
             direction: key.direction,
             script: key.script,
             language: key.language,
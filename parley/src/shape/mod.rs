@@ -9,15 +9,15 @@ use core::mem;
 use core::ops::RangeInclusive;
 
 use super::layout::Layout;
-use super::resolve::{RangedStyle, ResolveContext, Resolved};
-use super::style::{Brush, FontFeature, FontVariation};
+use super::resolve::{RangedStyle, ResolveContext, Resolved, ResolvedStyle};
+use super::style::{Brush, FontFeature, FontOpticalSizing, FontVariation};
 use crate::analysis::cluster::{Char, CharCluster, Status};
 use crate::analysis::{AnalysisDataSources, CharInfo};
 use crate::convert::script_to_harfrust;
 use crate::inline_box::InlineBox;
 use crate::lru_cache::LruCache;
 use crate::util::nearly_eq;
-use crate::{FontData, convert};
+use crate::{FontData, InitialLetter, convert};
 use fontique::Language;
 use icu_properties::props::Script;
 
@@ -56,8 +56,33 @@ struct Item {
     locale: Option<Language>,
     variations: Resolved<FontVariation>,
     features: Resolved<FontFeature>,
+    optical_sizing: FontOpticalSizing,
     word_spacing: f32,
     letter_spacing: f32,
+    initial_letter: Option<InitialLetter>,
+}
+
+impl Item {
+    fn new<B: Brush>(
+        style_index: u16,
+        style: &ResolvedStyle<B>,
+        level: u8,
+        script: Script,
+    ) -> Self {
+        Self {
+            style_index,
+            size: style.font_size,
+            script,
+            level,
+            locale: style.locale,
+            variations: style.font_variations,
+            features: style.font_features,
+            optical_sizing: style.font_optical_sizing,
+            word_spacing: style.word_spacing,
+            letter_spacing: style.letter_spacing,
+            initial_letter: style.initial_letter,
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -90,25 +115,57 @@ pub(crate) fn shape_text<'a, B: Brush>(
 
     // Setup mutable state for iteration
     let mut style = &styles[0].style;
-    let mut item = Item {
-        style_index: 0,
-        size: style.font_size,
-        level: levels.first().copied().unwrap_or(0),
-        script: infos
+    let mut item = Item::new(
+        0,
+        style,
+        levels.first().copied().unwrap_or(0),
+        infos
             .iter()
             .map(|x| x.0.script)
             .find(|&script| real_script(script))
             .unwrap_or(Script::Latin),
-        locale: style.locale,
-        variations: style.font_variations,
-        features: style.font_features,
-        word_spacing: style.word_spacing,
-        letter_spacing: style.letter_spacing,
-    };
+    );
 
     let mut char_range = 0..0;
     let mut text_range = 0..0;
 
+    // This macro shapes the current item, after sizing it if it is an initial letter. It exists
+    // solely to cut down on the boilerplate of passing the shaping state around.
+    macro_rules! shape_current_item {
+        () => {
+            if let Some(initial_letter) = item.initial_letter {
+                item.size = initial_letter_size(
+                    &mut fq,
+                    rcx,
+                    styles,
+                    &item,
+                    initial_letter,
+                    scx,
+                    text,
+                    &text_range,
+                    &char_range,
+                    infos,
+                    levels,
+                    layout,
+                    analysis_data_sources,
+                );
+            }
+            shape_item(
+                &mut fq,
+                rcx,
+                styles,
+                &item,
+                scx,
+                text,
+                &text_range,
+                &char_range,
+                infos,
+                layout,
+                analysis_data_sources,
+            );
+        };
+    }
+
     let mut inline_box_iter = inline_boxes.iter().enumerate();
     let mut current_box = inline_box_iter.next();
 
@@ -129,8 +186,10 @@ pub(crate) fn shape_text<'a, B: Brush>(
                 || style.locale != item.locale
                 || style.font_variations != item.variations
                 || style.font_features != item.features
+                || style.font_optical_sizing != item.optical_sizing
                 || !nearly_eq(style.letter_spacing, item.letter_spacing)
                 || !nearly_eq(style.word_spacing, item.word_spacing)
+                || style.initial_letter != item.initial_letter
             {
                 break_run = true;
             }
@@ -162,27 +221,8 @@ pub(crate) fn shape_text<'a, B: Brush>(
         }
 
         if break_run && !text_range.is_empty() {
-            shape_item(
-                &mut fq,
-                rcx,
-                styles,
-                &item,
-                scx,
-                text,
-                &text_range,
-                &char_range,
-                infos,
-                layout,
-                analysis_data_sources,
-            );
-            item.size = style.font_size;
-            item.level = level;
-            item.script = script;
-            item.locale = style.locale;
-            item.variations = style.font_variations;
-            item.features = style.font_features;
-            item.word_spacing = style.word_spacing;
-            item.letter_spacing = style.letter_spacing;
+            shape_current_item!();
+            item = Item::new(item.style_index, style, level, script);
             text_range.start = text_range.end;
             char_range.start = char_range.end;
         }
@@ -198,27 +238,105 @@ pub(crate) fn shape_text<'a, B: Brush>(
     }
 
     if !text_range.is_empty() {
+        shape_current_item!();
+    }
+
+    // Process any remaining inline boxes whose index is greater than the length of the text
+    if let Some((box_idx, _inline_box)) = current_box {
+        layout.data.push_inline_box(box_idx);
+    }
+    for (box_idx, _inline_box) in inline_box_iter {
+        layout.data.push_inline_box(box_idx);
+    }
+}
+
+/// Returns the font size at which the initial letter of `item` spans its number of lines, from
+/// the cap height of the first line to the baseline of the last line.
+///
+/// The size of the lines is taken from the first character following the initial letter that
+/// isn't part of an initial letter itself, or from the initial letter if there is none. The
+/// first character of the initial letter and that character are shaped at their specified size
+/// to measure them, and then discarded.
+#[allow(clippy::too_many_arguments)]
+fn initial_letter_size<'a, B: Brush>(
+    fq: &mut Query<'a>,
+    rcx: &'a ResolveContext,
+    styles: &'a [RangedStyle<B>],
+    item: &Item,
+    initial_letter: InitialLetter,
+    scx: &mut ShapeContext,
+    text: &str,
+    text_range: &core::ops::Range<usize>,
+    char_range: &core::ops::Range<usize>,
+    infos: &[(CharInfo, u16)],
+    levels: &[u8],
+    layout: &mut Layout<B>,
+    analysis_data_sources: &AnalysisDataSources,
+) -> f32 {
+    let mut measure = |item: &Item, byte_index: usize, char_index: usize, ch: char| {
+        let (fonts, runs) = (layout.data.fonts.len(), layout.data.runs.len());
         shape_item(
-            &mut fq,
+            fq,
             rcx,
             styles,
-            &item,
+            item,
             scx,
             text,
-            &text_range,
-            &char_range,
+            &(byte_index..byte_index + ch.len_utf8()),
+            &(char_index..char_index + 1),
             infos,
             layout,
             analysis_data_sources,
         );
-    }
+        let metrics = layout
+            .data
+            .runs
+            .get(runs)
+            .map(|run| (run.metrics.line_height, run.cap_height));
+        layout.data.truncate_runs(fonts, runs);
+        metrics
+    };
 
-    // Process any remaining inline boxes whose index is greater than the length of the text
-    if let Some((box_idx, _inline_box)) = current_box {
-        layout.data.push_inline_box(box_idx);
-    }
-    for (box_idx, _inline_box) in inline_box_iter {
-        layout.data.push_inline_box(box_idx);
+    let Some(first) = text[text_range.clone()].chars().next() else {
+        return item.size;
+    };
+    let Some((line_height, cap_height)) = measure(item, text_range.start, char_range.start, first)
+    else {
+        return item.size;
+    };
+    let body = text[text_range.end..]
+        .char_indices()
+        .zip(char_range.end..)
+        .find(|(_, char_index)| {
+            styles[infos[*char_index].1 as usize]
+                .style
+                .initial_letter
+                .is_none()
+        })
+        .and_then(|((offset, ch), char_index)| {
+            let (info, style_index) = &infos[char_index];
+            let script = if real_script(info.script) {
+                info.script
+            } else {
+                item.script
+            };
+            let level = levels.get(char_index).copied().unwrap_or(0);
+            let body = Item::new(
+                *style_index,
+                &styles[*style_index as usize].style,
+                level,
+                script,
+            );
+            measure(&body, text_range.end + offset, char_index, ch)
+        });
+    let (body_line_height, body_cap_height) = body.unwrap_or((line_height, cap_height));
+
+    let target = (initial_letter.size - 1.).max(0.) * body_line_height + body_cap_height;
+    let scale = target / cap_height;
+    if scale.is_finite() && scale > 0. {
+        item.size * scale
+    } else {
+        item.size
     }
 }
 
@@ -349,6 +467,12 @@ fn shape_item<'a, B: Brush>(
         let font_ref =
             harfrust::FontRef::from_index(font.font.blob.as_ref(), font.font.index).unwrap();
 
+        // Automatic optical sizing uses the font size before the layout scale is
+        // applied, matching the CSS `font-optical-sizing` property.
+        let optical_size = (item.optical_sizing == FontOpticalSizing::Auto
+            && font.font.has_optical_size_axis)
+            .then(|| item.size / layout.data.scale);
+
         // Create harfrust shaper
         let shaper_data = scx.shape_data_cache.entry(
             cache::ShapeDataKey::new(font.font.blob.id(), font.font.index),
@@ -359,12 +483,18 @@ fn shape_item<'a, B: Brush>(
                 font.font.blob.id(),
                 font.font.index,
                 &font.font.synthesis,
+                optical_size,
                 rcx.variations(item.variations),
             ),
             || {
                 harfrust::ShaperInstance::from_variations(
                     &font_ref,
-                    variations_iter(&font.font.synthesis, rcx.variations(item.variations)),
+                    variations_iter(
+                        font.font.named_instance.as_ref(),
+                        &font.font.synthesis,
+                        optical_size,
+                        rcx.variations(item.variations),
+                    ),
                 )
             },
         );
@@ -397,6 +527,7 @@ fn shape_item<'a, B: Brush>(
                 font.font.blob.id(),
                 font.font.index,
                 &font.font.synthesis,
+                optical_size,
                 direction,
                 hb_script,
                 language.clone(),
@@ -455,6 +586,7 @@ fn shape_item<'a, B: Brush>(
             font.font.synthesis,
             &glyph_buffer,
             item.level,
+            item.script,
             item.style_index,
             item.word_spacing,
             item.letter_spacing,
@@ -474,16 +606,26 @@ fn real_script(script: Script) -> bool {
 }
 
 fn variations_iter<'a>(
+    instance: Option<&'a fontique::NamedInstance>,
     synthesis: &'a fontique::Synthesis,
+    optical_size: Option<f32>,
     item: Option<&'a [FontVariation]>,
 ) -> impl Iterator<Item = harfrust::Variation> + 'a {
-    synthesis
-        .variation_settings()
+    // Later settings take precedence, so the coordinates of a named instance
+    // come first and explicit variation settings come last.
+    instance
+        .map(|instance| instance.coords())
+        .unwrap_or(&[])
         .iter()
+        .chain(synthesis.variation_settings())
         .map(|(tag, value)| harfrust::Variation {
             tag: *tag,
             value: *value,
         })
+        .chain(optical_size.map(|value| harfrust::Variation {
+            tag: harfrust::Tag::new(b"opsz"),
+            value,
+        }))
         .chain(
             item.unwrap_or(&[])
                 .iter()
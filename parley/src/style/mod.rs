@@ -11,12 +11,14 @@ use alloc::borrow::Cow;
 
 pub use brush::*;
 pub use font::{
-    FontFamily, FontFamilyName, FontFeature, FontFeatures, FontStyle, FontVariation,
-    FontVariations, FontWeight, FontWidth, GenericFamily,
+    FontFamily, FontFamilyName, FontFeature, FontFeatures, FontOpticalSizing, FontStyle,
+    FontVariation, FontVariations, FontWeight, FontWidth, GenericFamily,
 };
 pub use fontique::Language;
 pub use styleset::StyleSet;
-pub use text_primitives::{OverflowWrap, TextWrapMode, WordBreak};
+pub use text_primitives::{
+    BreakInside, InitialLetter, LineBreak, OverflowWrap, TextWrapMode, TextWrapStyle, WordBreak,
+};
 
 use crate::util::nearly_eq;
 
@@ -85,6 +87,8 @@ pub enum StyleProperty<'a, B: Brush> {
     FontVariations(FontVariations<'a>),
     /// Font feature settings.
     FontFeatures(FontFeatures<'a>),
+    /// Automatic optical sizing.
+    FontOpticalSizing(FontOpticalSizing),
     /// Locale.
     Locale(Option<Language>),
     /// Brush for rendering text.
@@ -113,10 +117,18 @@ pub enum StyleProperty<'a, B: Brush> {
     LetterSpacing(f32),
     /// Control over where words can wrap.
     WordBreak(WordBreak),
+    /// Control over the strictness of line-breaking rules.
+    LineBreak(LineBreak),
     /// Control over "emergency" line-breaking.
     OverflowWrap(OverflowWrap),
     /// Control over non-"emergency" line-breaking.
     TextWrapMode(TextWrapMode),
+    /// Control over how wrapped lines are balanced.
+    TextWrapStyle(TextWrapStyle),
+    /// Control over page and column breaks between lines.
+    BreakInside(BreakInside),
+    /// Initial letter, or `None` for normal text.
+    InitialLetter(Option<InitialLetter>),
 }
 
 /// Unresolved styles.
@@ -136,6 +148,8 @@ pub struct TextStyle<'a, B: Brush> {
     pub font_variations: FontVariations<'a>,
     /// Font feature settings.
     pub font_features: FontFeatures<'a>,
+    /// Automatic optical sizing.
+    pub font_optical_sizing: FontOpticalSizing,
     /// Locale.
     pub locale: Option<Language>,
     /// Brush for rendering text.
@@ -164,10 +178,18 @@ pub struct TextStyle<'a, B: Brush> {
     pub letter_spacing: f32,
     /// Control over where words can wrap.
     pub word_break: WordBreak,
+    /// Control over the strictness of line-breaking rules.
+    pub line_break: LineBreak,
     /// Control over "emergency" line-breaking.
     pub overflow_wrap: OverflowWrap,
     /// Control over non-"emergency" line-breaking.
     pub text_wrap_mode: TextWrapMode,
+    /// Control over how wrapped lines are balanced.
+    pub text_wrap_style: TextWrapStyle,
+    /// Control over page and column breaks between lines.
+    pub break_inside: BreakInside,
+    /// Initial letter, or `None` for normal text.
+    pub initial_letter: Option<InitialLetter>,
 }
 
 impl<B: Brush> Default for TextStyle<'_, B> {
@@ -180,6 +202,7 @@ impl<B: Brush> Default for TextStyle<'_, B> {
             font_weight: FontWeight::default(),
             font_variations: FontVariations::empty(),
             font_features: FontFeatures::empty(),
+            font_optical_sizing: FontOpticalSizing::default(),
             locale: None,
             brush: B::default(),
             has_underline: false,
@@ -194,8 +217,12 @@ impl<B: Brush> Default for TextStyle<'_, B> {
             word_spacing: 0.0,
             letter_spacing: 0.0,
             word_break: WordBreak::default(),
+            line_break: LineBreak::default(),
             overflow_wrap: OverflowWrap::default(),
             text_wrap_mode: TextWrapMode::default(),
+            text_wrap_style: TextWrapStyle::default(),
+            break_inside: BreakInside::default(),
+            initial_letter: None,
         }
     }
 }
@@ -6,7 +6,10 @@ mod test_basic;
 mod test_builders;
 mod test_cursor;
 mod test_editor;
+mod test_flow;
 mod test_issues;
 mod test_lines;
+#[cfg(feature = "runtime_data")]
+mod test_runtime_data;
 mod test_wrap;
 mod utils;
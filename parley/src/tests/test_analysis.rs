@@ -2,17 +2,29 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::analysis::Boundary;
-use crate::{FontContext, LayoutContext, RangedBuilder, StyleProperty, WordBreak};
-use alloc::{vec, vec::Vec};
+use crate::tests::utils::create_layout_context;
+use crate::{
+    DictionaryMode, FontContext, LayoutContext, LineBreak, RangedBuilder, SegmentationDictionary,
+    StyleProperty, WordBreak,
+};
+use alloc::{sync::Arc, vec, vec::Vec};
 use fontique::FontWeight;
 use icu_properties::props::{GraphemeClusterBreak, Script};
 
-#[derive(Default)]
 struct TestContext {
     pub layout_context: LayoutContext,
     pub font_context: FontContext,
 }
 
+impl Default for TestContext {
+    fn default() -> Self {
+        Self {
+            layout_context: create_layout_context(),
+            font_context: FontContext::default(),
+        }
+    }
+}
+
 impl TestContext {
     fn expect_boundary_list(self, expected: Vec<Boundary>) -> Self {
         let actual: Vec<_> = self
@@ -91,8 +103,14 @@ fn verify_analysis(
     text: &str,
     configure_builder: impl for<'a> FnOnce(&mut RangedBuilder<'a, [u8; 4]>),
 ) -> TestContext {
-    let mut test_context = TestContext::default();
+    verify_analysis_with_context(TestContext::default(), text, configure_builder)
+}
 
+fn verify_analysis_with_context(
+    mut test_context: TestContext,
+    text: &str,
+    configure_builder: impl for<'a> FnOnce(&mut RangedBuilder<'a, [u8; 4]>),
+) -> TestContext {
     {
         let mut builder = test_context.layout_context.ranged_builder(
             &mut test_context.font_context,
@@ -1142,3 +1160,152 @@ fn test_whitespace_contiguous_interspersed_in_latin_mixed() {
         Script::Latin,
     ]);
 }
+
+fn with_dictionary(words: &[&str], mode: DictionaryMode) -> TestContext {
+    let mut test_context = TestContext::default();
+    test_context.layout_context.set_segmentation_dictionary(
+        fontique::Script::from_bytes(*b"Thai"),
+        Arc::new(SegmentationDictionary::from_words(words)),
+        mode,
+    );
+    test_context
+}
+
+#[test]
+fn test_dictionary_override() {
+    // "กินข้าว" ("eat rice") segmented as two words.
+    verify_analysis_with_context(
+        with_dictionary(&["กิน", "ข้าว"], DictionaryMode::Override),
+        "กินข้าว",
+        |_| {},
+    )
+    .expect_boundary_list(vec![
+        Boundary::Word,
+        Boundary::None,
+        Boundary::None,
+        Boundary::Line,
+        Boundary::None,
+        Boundary::None,
+        Boundary::None,
+    ]);
+
+    // The same text treated as a single word.
+    verify_analysis_with_context(
+        with_dictionary(&["กินข้าว"], DictionaryMode::Override),
+        "กินข้าว",
+        |_| {},
+    )
+    .expect_boundary_list(vec![
+        Boundary::Word,
+        Boundary::None,
+        Boundary::None,
+        Boundary::None,
+        Boundary::None,
+        Boundary::None,
+        Boundary::None,
+    ]);
+}
+
+#[test]
+fn test_dictionary_keeps_line_breaks() {
+    // Overriding the word boundaries keeps the line break opportunities that
+    // `LineBreak::Anywhere` adds.
+    verify_analysis_with_context(
+        with_dictionary(&["กินข้าว"], DictionaryMode::Override),
+        "กินข้าว",
+        |builder| {
+            builder.push_default(StyleProperty::LineBreak(LineBreak::Anywhere));
+        },
+    )
+    .expect_boundary_list(vec![
+        Boundary::Word,
+        Boundary::Line,
+        Boundary::Line,
+        Boundary::Line,
+        Boundary::Line,
+        Boundary::Line,
+        Boundary::Line,
+    ]);
+}
+
+#[test]
+fn test_dictionary_ignores_other_scripts() {
+    verify_analysis_with_context(
+        with_dictionary(&["AB"], DictionaryMode::Override),
+        "AB CD",
+        |_| {},
+    )
+    .expect_boundary_list(vec![
+        Boundary::Word,
+        Boundary::None,
+        Boundary::Word,
+        Boundary::Line,
+        Boundary::None,
+    ]);
+}
+
+#[test]
+fn test_dictionary_from_bytes() {
+    let mut data = b"PRLYDICT\x01".to_vec();
+    data.extend_from_slice("กิน\nข้าว\n".as_bytes());
+    let dictionary = SegmentationDictionary::from_bytes(&data).unwrap();
+    assert_eq!(dictionary.len(), 2);
+    assert!(dictionary.contains("ข้าว"));
+    assert!(!dictionary.contains("กินข้าว"));
+
+    // Unsorted words are rejected.
+    let mut data = b"PRLYDICT\x01".to_vec();
+    data.extend_from_slice("ข้าว\nกิน\n".as_bytes());
+    assert!(SegmentationDictionary::from_bytes(&data).is_err());
+    assert!(SegmentationDictionary::from_bytes(b"PRLYDICT\x02").is_err());
+}
+
+fn boundaries(test_context: &TestContext) -> Vec<Boundary> {
+    test_context
+        .layout_context
+        .info
+        .iter()
+        .map(|(info, _)| info.boundary)
+        .collect()
+}
+
+#[test]
+fn test_line_break_strictness() {
+    // Only loose and normal line breaking allow a line to start with a small kana.
+    for (line_break, break_before_small_kana) in [
+        (LineBreak::Auto, false),
+        (LineBreak::Loose, true),
+        (LineBreak::Normal, true),
+        (LineBreak::Strict, false),
+        (LineBreak::Anywhere, true),
+    ] {
+        let test_context = verify_analysis("あっあ", |builder| {
+            builder.push_default(StyleProperty::LineBreak(line_break));
+        });
+        let boundaries = boundaries(&test_context);
+        assert_eq!(
+            boundaries[1] == Boundary::Line,
+            break_before_small_kana,
+            "{line_break:?}"
+        );
+    }
+}
+
+#[test]
+fn test_line_break_anywhere() {
+    verify_analysis("abc", |builder| {
+        builder.push_default(StyleProperty::LineBreak(LineBreak::Anywhere));
+    })
+    .expect_boundary_list(vec![Boundary::Word, Boundary::Line, Boundary::Line]);
+}
+
+#[test]
+fn test_line_break_strictness_per_span() {
+    let test_context = verify_analysis("あっあっ", |builder| {
+        builder.push(StyleProperty::LineBreak(LineBreak::Normal), 0..6);
+        builder.push(StyleProperty::LineBreak(LineBreak::Strict), 6..12);
+    });
+    let boundaries = boundaries(&test_context);
+    assert_eq!(boundaries[1], Boundary::Line);
+    assert_ne!(boundaries[3], Boundary::Line);
+}
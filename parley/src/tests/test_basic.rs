@@ -9,11 +9,14 @@ use peniko::{
 
 use super::utils::{
     ColorBrush, FONT_FAMILY_LIST, TestEnv, asserts::assert_eq_layout_data_alignments,
+    create_font_context, create_layout_context,
 };
 use crate::setting::{FontFeature, FontVariation};
 use crate::{
-    Alignment, AlignmentOptions, ContentWidths, FontFamily, FontFeatures, FontVariations,
-    InlineBox, Layout, LineHeight, StyleProperty, TextStyle, WhiteSpaceCollapse, test_name,
+    Alignment, AlignmentOptions, BreakReason, ContentWidths, FontContext, FontFamily, FontFeatures,
+    FontOpticalSizing, FontVariations, FontWeight, InitialLetter, InlineBox, Layout, LayoutContext,
+    Line, LineHeight, PositionedLayoutItem, StyleProperty, TextJustify, TextStyle,
+    WhiteSpaceCollapse, test_name,
 };
 
 #[test]
@@ -471,8 +474,8 @@ fn ligatures_ltr() {
     let line = layout.lines().next().unwrap();
     let item = line.items().next().unwrap();
     let glyph_run = match item {
-        crate::PositionedLayoutItem::GlyphRun(glyph_run) => glyph_run,
-        crate::PositionedLayoutItem::InlineBox(_) => unreachable!(),
+        PositionedLayoutItem::GlyphRun(glyph_run) => glyph_run,
+        PositionedLayoutItem::InlineBox(_) => unreachable!(),
     };
     let mut last_advance = f32::MAX;
     glyph_run.run().clusters().enumerate().for_each(|(i, c)| {
@@ -518,8 +521,8 @@ fn ligatures_rtl() {
     let line = layout.lines().next().unwrap();
     let item = line.items().next().unwrap();
     let glyph_run = match item {
-        crate::PositionedLayoutItem::GlyphRun(glyph_run) => glyph_run,
-        crate::PositionedLayoutItem::InlineBox(_) => unreachable!(),
+        PositionedLayoutItem::GlyphRun(glyph_run) => glyph_run,
+        PositionedLayoutItem::InlineBox(_) => unreachable!(),
     };
     let mut last_advance = f32::MAX;
     glyph_run.run().clusters().enumerate().for_each(|(i, c)| {
@@ -596,7 +599,7 @@ fn text_range_rtl() {
 
     for line in layout.lines() {
         for item in line.items() {
-            if let crate::PositionedLayoutItem::GlyphRun(glyph_run) = item {
+            if let PositionedLayoutItem::GlyphRun(glyph_run) = item {
                 glyph_run.run().clusters().for_each(|c| {
                     if !c.is_space_or_nbsp() {
                         assert_eq!(c.text_range().len(), 2);
@@ -699,6 +702,7 @@ fn realign_all() {
             None,
             AlignmentOptions {
                 align_when_overflowing: true,
+                ..AlignmentOptions::default()
             },
             "none",
             "awo_true",
@@ -707,6 +711,7 @@ fn realign_all() {
             None,
             AlignmentOptions {
                 align_when_overflowing: false,
+                ..AlignmentOptions::default()
             },
             "none",
             "awo_false",
@@ -769,6 +774,125 @@ fn realign_all() {
     }
 }
 
+/// Returns the total advance of the glyphs on the line, excluding trailing whitespace.
+fn justified_line_advance(line: &Line<'_, ColorBrush>) -> (f32, usize) {
+    let mut advance = 0.;
+    let mut glyph_count = 0;
+    for item in line.items() {
+        if let PositionedLayoutItem::GlyphRun(glyph_run) = item {
+            for glyph in glyph_run.glyphs() {
+                advance += glyph.advance;
+                glyph_count += 1;
+            }
+        }
+    }
+    (advance - line.metrics().trailing_whitespace, glyph_count)
+}
+
+#[test]
+/// Lines without spaces are only justified when space can be added between characters.
+fn justify_inter_character() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let text = "日本語の文章には単語の間に空白がないので、文字の間に空白を入れて両端を揃えます。";
+    for (text_justify, is_justified) in [
+        (TextJustify::InterWord, false),
+        (TextJustify::Auto, true),
+        (TextJustify::InterCharacter, true),
+    ] {
+        let builder = env.ranged_builder(text);
+        let mut layout = builder.build(text);
+        layout.break_all_lines(Some(150.0));
+        let options = AlignmentOptions {
+            text_justify,
+            ..AlignmentOptions::default()
+        };
+        layout.align(Some(150.0), Alignment::Justify, options);
+
+        let line = layout.lines().next().unwrap();
+        assert_eq!(line.break_reason(), BreakReason::Regular);
+        let (advance, _) = justified_line_advance(&line);
+        assert_eq!(
+            (advance - 150.0).abs() < 0.01,
+            is_justified,
+            "{text_justify:?}: unexpected line advance {advance}"
+        );
+    }
+}
+
+#[test]
+/// Arabic text is justified by inserting tatweel glyphs, which is undone by re-aligning.
+fn justify_kashida() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let text = "عند برمجة أجهزة الكمبيوتر، قد تجد نفسك فجأة في مواقف غريبة، مثل الكتابة بلغة لا تتحدثها فعليًا.";
+    let builder = env.ranged_builder(text);
+    let mut layout = builder.build(text);
+    layout.break_all_lines(Some(150.0));
+    layout.align(None, Alignment::Start, AlignmentOptions::default());
+    let start_layout = layout.clone();
+    let (_, start_glyph_count) = justified_line_advance(&layout.lines().next().unwrap());
+
+    let options = AlignmentOptions {
+        text_justify: TextJustify::Auto,
+        ..AlignmentOptions::default()
+    };
+    layout.align(None, Alignment::Justify, options);
+    let (advance, glyph_count) = justified_line_advance(&layout.lines().next().unwrap());
+    assert!(
+        (advance - layout.width()).abs() < 0.01,
+        "unexpected line advance {advance}"
+    );
+    assert!(
+        glyph_count > start_glyph_count,
+        "expected tatweel glyphs to be inserted"
+    );
+
+    layout.align(None, Alignment::Start, AlignmentOptions::default());
+    let (_, glyph_count) = justified_line_advance(&layout.lines().next().unwrap());
+    assert_eq!(glyph_count, start_glyph_count);
+    // Undoing justification subtracts the advance it added, which may be off by an ulp.
+    assert_eq!(start_layout.data.clusters.len(), layout.data.clusters.len());
+    for (a, b) in start_layout.data.clusters.iter().zip(&layout.data.clusters) {
+        assert!(
+            (a.advance - b.advance).abs() < 1e-4,
+            "cluster advance mismatch with kashida: {} != {}",
+            a.advance,
+            b.advance
+        );
+    }
+    for (a, b) in start_layout.lines().zip(layout.lines()) {
+        assert_eq!(a.metrics().offset, b.metrics().offset);
+    }
+}
+
+#[test]
+/// Cursive connections are known for every joining script, not only the main Arabic block.
+fn cursive_joining() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    // Arabic Supplement, Arabic Extended-A, Syriac and N'Ko letters, an alef and a space.
+    let text = "\u{0750}\u{08A0}\u{0712}\u{07CA}\u{0627} ";
+    let layout = env.ranged_builder(text).build(text);
+    let joins: Vec<_> = layout
+        .data
+        .clusters
+        .iter()
+        .map(|cluster| (cluster.joins_to_following(), cluster.joins_to_preceding()))
+        .collect();
+    assert_eq!(
+        joins,
+        [
+            (true, true),
+            (true, true),
+            (true, true),
+            (true, true),
+            (false, true),
+            (false, false),
+        ]
+    );
+}
+
 #[test]
 fn spacing_changes_per_style_run() {
     let mut env = TestEnv::new(test_name!(), None);
@@ -802,3 +926,294 @@ fn layout_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Layout<()>>();
 }
+
+#[test]
+fn layout_font_generation() {
+    let mut font_cx = create_font_context();
+    let mut layout_cx: LayoutContext<ColorBrush> = create_layout_context();
+
+    let text = "Hello world!";
+    let layout = layout_cx
+        .ranged_builder(&mut font_cx, text, 1.0, true)
+        .build(text);
+    assert_eq!(layout.font_generation(), font_cx.collection.generation());
+
+    // Changing the collection makes the layout stale.
+    let roboto = font_cx.collection.family_id("Roboto").unwrap();
+    font_cx
+        .collection
+        .set_generic_families(fontique::GenericFamily::Emoji, [roboto].into_iter());
+    assert_ne!(layout.font_generation(), font_cx.collection.generation());
+}
+
+#[test]
+fn han_fallback_by_locale() {
+    use fontique::{Blob, FontInfoOverride, Language, Script};
+
+    let mut font_cx = create_font_context();
+    let mut layout_cx: LayoutContext<ColorBrush> = create_layout_context();
+
+    // Register a distinct copy of a bundled font for each regional variant
+    // so that the selected fallback can be identified by its blob.
+    let roboto = parley_dev::font_dirs()
+        .map(|dir| dir.join("Roboto-Regular.ttf"))
+        .find(|path| path.exists())
+        .unwrap();
+    let roboto = std::fs::read(roboto).unwrap();
+    let hani = Script::from_bytes(*b"Hani");
+    let mut blobs = Vec::new();
+    for (name, locale) in [
+        ("Test JP", "ja"),
+        ("Test KR", "ko"),
+        ("Test SC", "zh-CN"),
+        ("Test TC", "zh-TW"),
+        ("Test HK", "zh-HK"),
+    ] {
+        let blob = Blob::new(alloc::sync::Arc::new(roboto.clone()));
+        font_cx.collection.register_fonts(
+            blob.clone(),
+            Some(FontInfoOverride {
+                family_name: Some(name),
+                ..Default::default()
+            }),
+        );
+        let id = font_cx.collection.family_id(name).unwrap();
+        assert!(
+            font_cx
+                .collection
+                .set_fallbacks((hani, locale), core::iter::once(id))
+        );
+        blobs.push((locale, blob.id()));
+    }
+
+    let text = "漢字";
+    for (locale, expected) in [
+        ("ja-JP", "ja"),
+        ("ko", "ko"),
+        ("zh-Hans", "zh-CN"),
+        ("zh-SG", "zh-CN"),
+        ("zh-Hant", "zh-TW"),
+        ("zh-TW", "zh-TW"),
+        ("zh-Hant-HK", "zh-HK"),
+        ("zh-MO", "zh-HK"),
+        ("yue", "zh-HK"),
+    ] {
+        let mut builder = layout_cx.ranged_builder(&mut font_cx, text, 1.0, true);
+        builder.push_default(FontFamily::named("Not A Font"));
+        builder.push_default(StyleProperty::Locale(Some(
+            Language::parse(locale).unwrap(),
+        )));
+        let mut layout: Layout<ColorBrush> = builder.build(text);
+        layout.break_all_lines(None);
+
+        let expected = blobs.iter().find(|(l, _)| *l == expected).unwrap().1;
+        let run = layout.lines().next().unwrap().runs().next().unwrap();
+        assert_eq!(run.font().data.id(), expected, "{locale}");
+    }
+}
+
+#[test]
+fn variable_font_named_instances() {
+    let mut font_cx = create_font_context();
+    let mut layout_cx: LayoutContext<ColorBrush> = create_layout_context();
+
+    let text = "Hello";
+    // Named instances are looked up first, so they must resolve without their
+    // family having been loaded.
+    for (family, weight, instance) in [
+        ("ArimoRoman-Bold", None, Some(1)),
+        ("Arimo Bold", None, Some(1)),
+        ("Arimo", None, None),
+        ("Arimo", Some(FontWeight::BOLD), Some(1)),
+    ] {
+        let mut builder = layout_cx.ranged_builder(&mut font_cx, text, 1.0, true);
+        builder.push_default(FontFamily::named(family));
+        if let Some(weight) = weight {
+            builder.push_default(StyleProperty::FontWeight(weight));
+        }
+        let mut layout: Layout<ColorBrush> = builder.build(text);
+        layout.break_all_lines(None);
+
+        let line = layout.lines().next().unwrap();
+        let run = line.runs().next().unwrap();
+        assert_eq!(run.synthesis().named_instance(), instance, "{family}");
+        // The `wght` axis of Arimo ranges from 400 to 700.
+        let wght = run.normalized_coords().first().copied().unwrap_or(0);
+        assert_eq!(
+            wght,
+            if instance.is_some() { 1 << 14 } else { 0 },
+            "{family}"
+        );
+    }
+}
+
+#[test]
+fn optical_sizing_without_opsz_axis() {
+    let mut font_cx = create_font_context();
+    let mut layout_cx: LayoutContext<ColorBrush> = create_layout_context();
+
+    // Arimo has no `opsz` axis, so automatic optical sizing must not
+    // produce any variation coordinates.
+    let text = "Hello";
+    let mut coords = Vec::new();
+    for optical_sizing in [FontOpticalSizing::Auto, FontOpticalSizing::None] {
+        let mut builder = layout_cx.ranged_builder(&mut font_cx, text, 2.0, true);
+        builder.push_default(FontFamily::named("Arimo"));
+        builder.push_default(StyleProperty::FontSize(48.0));
+        builder.push_default(StyleProperty::FontOpticalSizing(optical_sizing));
+        let mut layout: Layout<ColorBrush> = builder.build(text);
+        layout.break_all_lines(None);
+
+        let line = layout.lines().next().unwrap();
+        let run = line.runs().next().unwrap();
+        coords.push((run.normalized_coords().to_vec(), run.advance()));
+    }
+    assert_eq!(coords[0], coords[1]);
+    assert!(coords[0].0.iter().all(|coord| *coord == 0));
+}
+
+#[test]
+fn optical_sizing_with_opsz_axis() {
+    let mut font_cx = create_font_context();
+    let mut layout_cx: LayoutContext<ColorBrush> = create_layout_context();
+    register_arimo_optical(&mut font_cx);
+
+    let text = "Hello";
+    for (optical_sizing, font_size, expected) in [
+        (FontOpticalSizing::Auto, 550.0, 1 << 13),
+        (FontOpticalSizing::Auto, 700.0, 1 << 14),
+        (FontOpticalSizing::None, 700.0, 0),
+    ] {
+        // The optical size is the font size before the layout scale is applied.
+        let mut builder = layout_cx.ranged_builder(&mut font_cx, text, 2.0, true);
+        builder.push_default(FontFamily::named("Arimo Optical"));
+        builder.push_default(StyleProperty::FontSize(font_size));
+        builder.push_default(StyleProperty::FontOpticalSizing(optical_sizing));
+        let mut layout: Layout<ColorBrush> = builder.build(text);
+        layout.break_all_lines(None);
+
+        let line = layout.lines().next().unwrap();
+        let run = line.runs().next().unwrap();
+        let opsz = run.normalized_coords().first().copied().unwrap_or(0);
+        assert_eq!(opsz, expected, "{optical_sizing:?} at {font_size}");
+    }
+}
+
+/// Registers a copy of Arimo with an `opsz` axis from 400 to 700 as "Arimo Optical".
+fn register_arimo_optical(font_cx: &mut FontContext) {
+    use fontique::{Blob, FontInfoOverride};
+
+    // None of the bundled fonts have an `opsz` axis, so make one by renaming
+    // the `wght` axis of Arimo (400 to 700) in its `fvar` and `STAT` tables.
+    let arimo = parley_dev::font_dirs()
+        .map(|dir| dir.join("Arimo-VariableFont_wght.ttf"))
+        .find(|path| path.exists())
+        .unwrap();
+    let mut arimo = std::fs::read(arimo).unwrap();
+    for start in 0..arimo.len() - 4 {
+        if &arimo[start..start + 4] == b"wght" {
+            arimo[start..start + 4].copy_from_slice(b"opsz");
+        }
+    }
+    font_cx.collection.register_fonts(
+        Blob::new(alloc::sync::Arc::new(arimo)),
+        Some(FontInfoOverride {
+            family_name: Some("Arimo Optical"),
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+/// An initial letter spans three lines, which are indented beside it.
+fn initial_letter() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let text = "Once upon a time, there was a paragraph with enough words to wrap onto several \
+        lines of text beside its initial letter.";
+    let mut builder = env.ranged_builder(text);
+    builder.push(
+        StyleProperty::InitialLetter(Some(InitialLetter::new(3.))),
+        0..1,
+    );
+    let mut layout = builder.build(text);
+    layout.break_all_lines(Some(150.));
+    layout.align(None, Alignment::Start, AlignmentOptions::default());
+
+    let lines: Vec<_> = layout.lines().collect();
+    assert!(lines.len() > 3, "Expected more than three lines");
+    assert_eq!(
+        lines[0].metrics().line_height,
+        lines[3].metrics().line_height,
+        "The initial letter should not make the first line taller"
+    );
+
+    let glyph_runs: Vec<_> = lines[0]
+        .items()
+        .filter_map(|item| match item {
+            PositionedLayoutItem::GlyphRun(glyph_run) => Some(glyph_run),
+            PositionedLayoutItem::InlineBox(_) => None,
+        })
+        .collect();
+    let (initial_letter, body) = (&glyph_runs[0], &glyph_runs[1]);
+    assert!(initial_letter.run().font_size() > 2. * body.run().font_size());
+    assert!(
+        (initial_letter.baseline() - lines[2].metrics().baseline).abs() < 1e-3,
+        "The initial letter should sit on the baseline of the third line"
+    );
+
+    let advance = initial_letter.advance();
+    assert_eq!(lines[0].metrics().offset, 0.);
+    for line in &lines[1..3] {
+        assert_eq!(line.metrics().offset, advance);
+    }
+    assert_eq!(lines[3].metrics().offset, 0.);
+}
+
+#[test]
+/// An initial letter is shaped at its final size, which selects its optical size.
+fn initial_letter_optical_size() {
+    let mut font_cx = create_font_context();
+    let mut layout_cx: LayoutContext<ColorBrush> = create_layout_context();
+    register_arimo_optical(&mut font_cx);
+
+    let first_run = |font_cx: &mut FontContext,
+                     layout_cx: &mut LayoutContext<ColorBrush>,
+                     text: &str,
+                     font_size: f32,
+                     initial_letter: Option<InitialLetter>| {
+        let mut builder = layout_cx.ranged_builder(font_cx, text, 1.0, true);
+        builder.push_default(FontFamily::named("Arimo Optical"));
+        builder.push_default(StyleProperty::FontSize(font_size));
+        builder.push(StyleProperty::InitialLetter(initial_letter), 0..1);
+        let mut layout: Layout<ColorBrush> = builder.build(text);
+        layout.break_all_lines(None);
+        let line = layout.lines().next().unwrap();
+        let run = line.runs().next().unwrap();
+        (
+            run.font_size(),
+            run.normalized_coords().to_vec(),
+            run.advance(),
+        )
+    };
+
+    // The body text is smaller than the optical size range, but the initial letter is within it.
+    let (font_size, coords, advance) = first_run(
+        &mut font_cx,
+        &mut layout_cx,
+        "Once upon a time",
+        150.,
+        Some(InitialLetter::new(3.)),
+    );
+    assert!(font_size > 400. && font_size < 700.);
+    assert_ne!(coords.first().copied().unwrap_or(0), 0);
+    assert_eq!(
+        (coords, advance),
+        {
+            let (_, coords, advance) =
+                first_run(&mut font_cx, &mut layout_cx, "O", font_size, None);
+            (coords, advance)
+        },
+        "The initial letter should be shaped like a letter of its size"
+    );
+}
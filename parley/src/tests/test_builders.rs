@@ -3,15 +3,22 @@
 
 //! Test that the various builders produce the same results.
 
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use attributed_text::{AttributedText, TextChunks, TextStorage};
 use fontique::{FontStyle, FontWeight, FontWidth};
 use peniko::color::palette;
 
 use super::utils::{
     ColorBrush, FONT_FAMILY_LIST, asserts::assert_eq_layout_data, create_font_context,
+    create_layout_context,
 };
 use crate::{
-    FontContext, FontFamily, FontFeatures, FontVariations, Layout, LayoutContext, LineHeight,
-    OverflowWrap, RangedBuilder, StyleProperty, TextStyle, TextWrapMode, TreeBuilder, WordBreak,
+    BreakInside, FontContext, FontFamily, FontFeatures, FontOpticalSizing, FontVariations,
+    InitialLetter, InlineBox, Layout, LayoutAttribute, LayoutContext, LineBreak, LineHeight,
+    OverflowWrap, RangedBuilder, StyleProperty, TextStyle, TextWrapMode, TextWrapStyle,
+    TreeBuilder, WordBreak,
 };
 
 /// Set of options for [`build_layout_with_ranged`].
@@ -85,10 +92,10 @@ fn assert_builders_produce_same_result<'a, 'b>(
 ) {
     let mut fcx = create_font_context();
 
-    let mut lcx_a: LayoutContext<ColorBrush> = LayoutContext::new();
-    let mut lcx_b: LayoutContext<ColorBrush> = LayoutContext::new();
-    let mut lcx_c: LayoutContext<ColorBrush> = LayoutContext::new();
-    let mut lcx_d: LayoutContext<ColorBrush> = LayoutContext::new();
+    let mut lcx_a: LayoutContext<ColorBrush> = create_layout_context();
+    let mut lcx_b: LayoutContext<ColorBrush> = create_layout_context();
+    let mut lcx_c: LayoutContext<ColorBrush> = create_layout_context();
+    let mut lcx_d: LayoutContext<ColorBrush> = create_layout_context();
 
     let ropts = RangedOptions {
         scale,
@@ -169,6 +176,7 @@ fn create_root_style() -> TextStyle<'static, ColorBrush> {
         font_weight: FontWeight::BOLD,
         font_variations: FontVariations::empty(), // TODO: Set a non-default value
         font_features: FontFeatures::empty(),     // TODO: Set a non-default value
+        font_optical_sizing: FontOpticalSizing::None,
         locale: Some("en-US".parse().unwrap()),
         brush: ColorBrush::new(palette::css::GREEN),
         has_underline: true,
@@ -183,8 +191,12 @@ fn create_root_style() -> TextStyle<'static, ColorBrush> {
         word_spacing: 2.,
         letter_spacing: 1.5,
         word_break: WordBreak::BreakAll,
+        line_break: LineBreak::Strict,
         overflow_wrap: OverflowWrap::Anywhere,
         text_wrap_mode: TextWrapMode::Wrap,
+        text_wrap_style: TextWrapStyle::Pretty,
+        break_inside: BreakInside::Avoid,
+        initial_letter: Some(InitialLetter::new(2.)),
     }
 }
 
@@ -199,6 +211,7 @@ fn set_root_style(rb: &mut RangedBuilder<'_, ColorBrush>) {
     rb.push_default(StyleProperty::FontWeight(FontWeight::BOLD));
     rb.push_default(FontVariations::empty());
     rb.push_default(FontFeatures::empty());
+    rb.push_default(StyleProperty::FontOpticalSizing(FontOpticalSizing::None));
     rb.push_default(StyleProperty::Locale(Some("en-US".parse().unwrap())));
     rb.push_default(StyleProperty::Brush(ColorBrush::new(palette::css::GREEN)));
     rb.push_default(StyleProperty::Underline(true));
@@ -217,7 +230,11 @@ fn set_root_style(rb: &mut RangedBuilder<'_, ColorBrush>) {
     rb.push_default(StyleProperty::WordSpacing(2.));
     rb.push_default(StyleProperty::LetterSpacing(1.5));
     rb.push_default(StyleProperty::WordBreak(WordBreak::BreakAll));
+    rb.push_default(StyleProperty::LineBreak(LineBreak::Strict));
     rb.push_default(StyleProperty::OverflowWrap(OverflowWrap::Anywhere));
+    rb.push_default(StyleProperty::TextWrapStyle(TextWrapStyle::Pretty));
+    rb.push_default(StyleProperty::BreakInside(BreakInside::Avoid));
+    rb.push_default(StyleProperty::InitialLetter(Some(InitialLetter::new(2.))));
 }
 
 /// Test that all the builders have the same default behavior.
@@ -357,3 +374,200 @@ fn builders_mixed_styles() {
         false,
     );
 }
+
+/// An attribute of rich text, for [`builders_attributed`].
+#[derive(Debug)]
+enum TestAttribute {
+    Emphasis,
+    Size(f32),
+    Image(f32),
+}
+
+impl LayoutAttribute<ColorBrush> for TestAttribute {
+    fn style_properties(&self, push: &mut dyn FnMut(StyleProperty<'_, ColorBrush>)) {
+        match self {
+            Self::Emphasis => {
+                push(StyleProperty::FontWeight(FontWeight::BOLD));
+                push(StyleProperty::LetterSpacing(2.));
+            }
+            Self::Size(size) => push(StyleProperty::FontSize(*size)),
+            Self::Image(_) => {}
+        }
+    }
+
+    fn inline_box(&self) -> Option<InlineBox> {
+        match self {
+            Self::Image(width) => Some(InlineBox {
+                id: 7,
+                index: 0,
+                width: *width,
+                height: 10.,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Test that the attributed builder behaves the same as pushing its attributes to a ranged
+/// builder in the order they were applied.
+#[test]
+fn builders_attributed() {
+    let text = "Builders often wear hard hats for safety while working on construction sites.";
+    let scale = 2.;
+    let quantize = false;
+    let max_advance = Some(50.);
+
+    let mut attributed = AttributedText::new(text);
+    attributed
+        .apply_attribute(0..14, TestAttribute::Size(30.))
+        .unwrap();
+    attributed
+        .apply_attribute(9..19, TestAttribute::Emphasis)
+        .unwrap();
+    // Later attributes take precedence over earlier ones.
+    attributed
+        .apply_attribute(12..29, TestAttribute::Size(12.))
+        .unwrap();
+    attributed
+        .apply_attribute(20..20, TestAttribute::Image(15.))
+        .unwrap();
+
+    let mut fcx = create_font_context();
+    let mut lcx: LayoutContext<ColorBrush> = create_layout_context();
+
+    let mut ab = lcx.attributed_builder(&mut fcx, &attributed, scale, quantize);
+    ab.push_default(FontFamily::from(FONT_FAMILY_LIST));
+    ab.push_default(StyleProperty::FontSize(20.));
+    let mut layout = ab.build();
+    layout.break_all_lines(max_advance);
+    assert_eq!(layout.data.inline_boxes.len(), 1);
+
+    let ropts = RangedOptions {
+        scale,
+        quantize,
+        max_advance,
+        text,
+    };
+    let layout_truth = build_layout_with_ranged(&mut fcx, &mut lcx, &ropts, |rb| {
+        rb.push_default(FontFamily::from(FONT_FAMILY_LIST));
+        rb.push_default(StyleProperty::FontSize(20.));
+        rb.push(StyleProperty::FontSize(30.), 0..14);
+        rb.push(StyleProperty::FontWeight(FontWeight::BOLD), 9..19);
+        rb.push(StyleProperty::LetterSpacing(2.), 9..19);
+        rb.push(StyleProperty::FontSize(12.), 12..29);
+        rb.push_inline_box(InlineBox {
+            id: 7,
+            index: 20,
+            width: 15.,
+            height: 10.,
+        });
+    });
+    assert_eq_layout_data(&layout_truth.data, &layout.data, "attributed");
+}
+
+/// Text storage that holds its text in several chunks, like a rope.
+#[derive(Debug)]
+struct ChunkedText(Vec<&'static str>);
+
+impl TextStorage for ChunkedText {
+    fn len(&self) -> usize {
+        self.0.iter().map(|chunk| chunk.len()).sum()
+    }
+
+    fn is_char_boundary(&self, mut index: usize) -> bool {
+        for chunk in &self.0 {
+            if index <= chunk.len() {
+                return chunk.is_char_boundary(index);
+            }
+            index -= chunk.len();
+        }
+        false
+    }
+}
+
+impl TextChunks for ChunkedText {
+    fn chunks(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().copied()
+    }
+}
+
+/// Generates a `Layout` with an attributed builder, given the scale, quantization and maximum
+/// advance.
+fn build_layout_with_attributed<T: Debug + TextChunks>(
+    fcx: &mut FontContext,
+    lcx: &mut LayoutContext<ColorBrush>,
+    (scale, quantize, max_advance): (f32, bool, Option<f32>),
+    text: &AttributedText<T, TestAttribute>,
+) -> Layout<ColorBrush> {
+    let mut ab = lcx.attributed_builder(fcx, text, scale, quantize);
+    ab.push_default(FontFamily::from(FONT_FAMILY_LIST));
+    let mut layout = ab.build();
+    layout.break_all_lines(max_advance);
+    layout
+}
+
+/// Test that text in chunked storage is laid out the same as contiguous text.
+#[test]
+fn builders_attributed_chunks() {
+    let chunks = [
+        "Builders often wear ",
+        "hard hats for safety ",
+        "while working.",
+    ];
+    let text = chunks.concat();
+    let scale = 1.;
+    let quantize = false;
+    let max_advance = Some(50.);
+
+    let mut contiguous = AttributedText::new(text.as_str());
+    contiguous
+        .apply_attribute(15..30, TestAttribute::Emphasis)
+        .unwrap();
+    let mut chunked = AttributedText::new(ChunkedText(chunks.into()));
+    chunked
+        .apply_attribute(15..30, TestAttribute::Emphasis)
+        .unwrap();
+
+    let mut fcx = create_font_context();
+    let mut lcx: LayoutContext<ColorBrush> = create_layout_context();
+    let opts = (scale, quantize, max_advance);
+    let layout_truth = build_layout_with_attributed(&mut fcx, &mut lcx, opts, &contiguous);
+    // The second layout reuses the buffer the chunks were copied into.
+    for case in ["chunked_one", "chunked_two"] {
+        let layout = build_layout_with_attributed(&mut fcx, &mut lcx, opts, &chunked);
+        assert_eq_layout_data(&layout_truth.data, &layout.data, case);
+    }
+    assert_eq!(lcx.text_buffer, text);
+}
+
+/// Test that a ranged builder lays out chunks the same as contiguous text.
+#[test]
+fn builders_ranged_chunks() {
+    let chunks = ["Builders often wear ", "hard hats ", "for safety."];
+    let text = chunks.concat();
+    let opts = RangedOptions {
+        scale: 1.,
+        quantize: false,
+        max_advance: Some(50.),
+        text: &text,
+    };
+    let with_builder = |rb: &mut RangedBuilder<'_, ColorBrush>| {
+        rb.push_default(FontFamily::from(FONT_FAMILY_LIST));
+        rb.push(StyleProperty::FontSize(20.), 15..30);
+    };
+
+    let mut fcx = create_font_context();
+    let mut lcx: LayoutContext<ColorBrush> = create_layout_context();
+    let layout_truth = build_layout_with_ranged(&mut fcx, &mut lcx, &opts, with_builder);
+
+    for (case, chunks) in [
+        ("chunks", &chunks[..]),
+        ("single_chunk", &[text.as_str()][..]),
+    ] {
+        let mut rb = lcx.ranged_builder_for_len(&mut fcx, text.len(), opts.scale, opts.quantize);
+        with_builder(&mut rb);
+        let mut layout = rb.build_from_chunks(chunks.iter().copied());
+        layout.break_all_lines(opts.max_advance);
+        assert_eq_layout_data(&layout_truth.data, &layout.data, case);
+    }
+}
@@ -1,12 +1,19 @@
 // Copyright 2024 the Parley Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::tests::utils::CursorTest;
-use crate::{Cursor, FontContext, LayoutContext, Selection};
+use alloc::string::String;
+use alloc::vec::Vec;
+use peniko::color::palette::css;
+
+use crate::tests::utils::{ColorBrush, CursorTest, TestEnv, create_layout_context};
+use crate::{
+    Affinity, Cursor, FontContext, InlineBox, LayoutContext, Selection, SelectionFragment,
+    StyleProperty, test_name,
+};
 
 #[test]
 fn cursor_previous_visual() {
-    let (mut lcx, mut fcx) = (LayoutContext::new(), FontContext::new());
+    let (mut lcx, mut fcx) = (create_layout_context(), FontContext::new());
     let text = "Lorem ipsum dolor sit amet";
     let layout = CursorTest::single_line(text, &mut lcx, &mut fcx);
 
@@ -19,7 +26,7 @@ fn cursor_previous_visual() {
 
 #[test]
 fn cursor_next_visual() {
-    let (mut lcx, mut fcx) = (LayoutContext::new(), FontContext::new());
+    let (mut lcx, mut fcx) = (create_layout_context(), FontContext::new());
     let text = "Lorem ipsum dolor sit amet";
     let layout = CursorTest::single_line(text, &mut lcx, &mut fcx);
 
@@ -35,7 +42,7 @@ fn cursor_ligature_selection() {
     use crate::tests::utils::ColorBrush;
     use crate::{Affinity, Cursor};
     let (mut lcx, mut fcx): (LayoutContext<ColorBrush>, _) =
-        (LayoutContext::new(), FontContext::new());
+        (create_layout_context(), FontContext::new());
 
     // Test with ligature text "fi" using serif font which should support ligatures
     let text = "fi";
@@ -56,3 +63,62 @@ fn cursor_ligature_selection() {
 
     assert_eq!(clusters[0].as_ref().map(|c| c.text_range()), Some(1..2));
 }
+
+#[test]
+fn selection_fragments() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let text = "Hello bold \u{639}\u{631}\u{628}\u{64a} world";
+    let mut builder = env.ranged_builder(text);
+    builder.push(StyleProperty::Brush(ColorBrush::new(css::RED)), 6..10);
+    builder.push_inline_box(InlineBox {
+        id: 7,
+        index: 5,
+        width: 10.0,
+        height: 10.0,
+    });
+    let mut layout = builder.build(text);
+    layout.break_all_lines(None);
+
+    let selection = Selection::new(
+        Cursor::from_byte_index(&layout, 2, Affinity::Downstream),
+        Cursor::from_byte_index(&layout, 23, Affinity::Downstream),
+    );
+    let fragments = selection.fragments(&layout, text);
+
+    // Fragments are in logical order, so their text is the selected text.
+    let mut selected = String::new();
+    let mut texts = Vec::new();
+    for fragment in &fragments {
+        match fragment {
+            SelectionFragment::Text(fragment) => {
+                selected.push_str(fragment.text);
+                texts.push(fragment);
+            }
+            SelectionFragment::InlineBox(inline_box) => {
+                selected.push_str(&alloc::format!("[{}]", inline_box.id));
+            }
+        }
+    }
+    assert_eq!(selected, "llo[7] bold \u{639}\u{631}\u{628}\u{64a} wor");
+
+    assert_eq!(texts[0].text, "llo");
+    assert_eq!(texts[0].family_name().as_deref(), Some("Roboto"));
+    assert!(!texts[0].is_rtl);
+    let bold = texts
+        .iter()
+        .find(|fragment| fragment.text == "bold")
+        .unwrap();
+    assert_eq!(bold.style.brush, ColorBrush::new(css::RED));
+    let arabic = texts
+        .iter()
+        .find(|fragment| fragment.text == "\u{639}\u{631}\u{628}\u{64a}")
+        .unwrap();
+    assert!(arabic.is_rtl);
+    assert_eq!(arabic.family_name().as_deref(), Some("Noto Kufi Arabic"));
+
+    assert!(
+        Selection::from(selection.focus())
+            .fragments(&layout, text)
+            .is_empty()
+    );
+}
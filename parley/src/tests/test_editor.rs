@@ -1,7 +1,10 @@
 // Copyright 2024 the Parley Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::test_name;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{SelectionFragment, SurroundingText, test_name};
 
 use super::utils::TestEnv;
 
@@ -34,6 +37,205 @@ fn editor_select_all() {
     env.check_editor_snapshot(&mut editor);
 }
 
+#[test]
+fn editor_selected_fragments() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let mut editor = env.editor("Hi, all!\nNext");
+    assert!(editor.selected_fragments().is_none());
+    env.driver(&mut editor).select_all();
+    let fragments = editor.selected_fragments().unwrap();
+    let text: String = fragments
+        .iter()
+        .map(|fragment| match fragment {
+            SelectionFragment::Text(fragment) => fragment.text,
+            SelectionFragment::InlineBox(_) => "",
+        })
+        .collect();
+    assert_eq!(text, "Hi, all!\nNext");
+}
+
+#[test]
+fn editor_surrounding_text() {
+    let mut env = TestEnv::new(test_name!(), None);
+    // "é" is two bytes and one UTF-16 code unit, and "😀" is four bytes and two code units.
+    let mut editor = env.editor("abé😀def😀");
+    env.driver(&mut editor).select_byte_range(11, 8);
+
+    let surrounding = editor.surrounding_text(usize::MAX, usize::MAX).unwrap();
+    assert_eq!(
+        surrounding,
+        SurroundingText {
+            text: "abé😀def😀",
+            offset: 0,
+            anchor: 11,
+            focus: 8,
+            anchor_utf16: 8,
+            focus_utf16: 5,
+        }
+    );
+
+    // The window is shortened to char boundaries.
+    let surrounding = editor.surrounding_text(6, 2).unwrap();
+    assert_eq!(surrounding.text, "é😀def");
+    assert_eq!(surrounding.offset, 2);
+    assert_eq!((surrounding.anchor, surrounding.focus), (9, 6));
+    assert_eq!((surrounding.anchor_utf16, surrounding.focus_utf16), (6, 3));
+
+    env.driver(&mut editor).set_compose("x", None);
+    assert!(editor.surrounding_text(6, 2).is_none());
+}
+
+#[test]
+fn editor_reconvert() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let mut editor = env.editor("one two three");
+    let mut driver = env.driver(&mut editor);
+    driver.move_to_byte(13);
+    driver.reconvert(4, 7);
+    assert_eq!(editor.raw_compose(), &Some(4..7));
+    assert_eq!(editor.raw_text(), "one two three");
+    assert_eq!(editor.raw_selection().text_range(), 4..7);
+
+    // Cancelling the reconversion restores the original text.
+    let mut driver = env.driver(&mut editor);
+    driver.set_compose("2", Some((1, 1)));
+    assert_eq!(editor.raw_text(), "one 2 three");
+    env.driver(&mut editor).clear_compose();
+    assert_eq!(editor.raw_compose(), &None);
+    assert_eq!(editor.raw_text(), "one two three");
+    assert_eq!(editor.raw_selection().text_range(), 4..7);
+
+    // Finishing the reconversion commits the new text.
+    let mut driver = env.driver(&mut editor);
+    driver.reconvert(0, 3);
+    driver.set_compose("1", Some((1, 1)));
+    driver.finish_compose();
+    assert_eq!(editor.raw_text(), "1 two three");
+    env.driver(&mut editor).clear_compose();
+    assert_eq!(editor.raw_text(), "1 two three");
+}
+
+#[test]
+fn editor_text_index() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let mut editor = env.editor("a😀b");
+    let index = editor.text_index();
+    let text = editor.raw_text();
+    assert_eq!(index.byte_to_utf16(text, 5), Some(3));
+    assert_eq!(index.utf16_to_byte(text, 3), Some(5));
+    assert_eq!(index.byte_to_char(text, 5), Some(2));
+
+    // The index follows edits to the buffer, including the preedit text.
+    let mut driver = env.driver(&mut editor);
+    driver.move_to_byte(0);
+    driver.insert_or_replace_selection("é");
+    driver.move_to_byte(8);
+    driver.set_compose("😀", None);
+    let index = editor.text_index();
+    let text = editor.raw_text();
+    assert_eq!(index.len_utf8(), 12);
+    assert_eq!(index.len_utf16(), 7);
+    assert_eq!(index.char_to_byte(text, 2), Some(3));
+    assert_eq!(index.utf16_to_byte(text, 7), Some(12));
+}
+
+#[test]
+fn editor_multi_cursor_edit() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let mut editor = env.editor("one\ntwo\nthree");
+    let mut driver = env.driver(&mut editor);
+    driver.move_to_byte(0);
+    driver.add_selection_byte_range(8, 8);
+    driver.add_selection_byte_range(4, 4);
+    driver.insert_or_replace_selection("> ");
+    assert_eq!(editor.raw_text(), "> one\n> two\n> three");
+    let ranges: Vec<_> = editor
+        .raw_selections()
+        .iter()
+        .map(|selection| selection.text_range())
+        .collect();
+    assert_eq!(ranges, [2..2, 8..8, 14..14]);
+    assert_eq!(editor.primary_selection_index(), 1);
+    assert_eq!(editor.raw_selection().text_range(), 8..8);
+    assert_eq!(editor.all_cursor_geometry(1.).len(), 3);
+
+    env.driver(&mut editor).backdelete();
+    assert_eq!(editor.raw_text(), ">one\n>two\n>three");
+    let ranges: Vec<_> = editor
+        .raw_selections()
+        .iter()
+        .map(|selection| selection.text_range())
+        .collect();
+    assert_eq!(ranges, [1..1, 6..6, 11..11]);
+
+    // Selections which move to the same place are merged.
+    env.driver(&mut editor).move_to_text_start();
+    assert_eq!(editor.raw_selections().len(), 1);
+    assert_eq!(editor.raw_selection().text_range(), 0..0);
+}
+
+#[test]
+fn editor_multi_cursor_merge() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let mut editor = env.editor("abcdef");
+    let mut driver = env.driver(&mut editor);
+    driver.select_byte_range(0, 2);
+    driver.add_selection_byte_range(3, 4);
+    assert_eq!(editor.raw_selections().len(), 2);
+
+    // Extending the primary selection over another merges them, keeping its direction.
+    env.driver(&mut editor).extend_selection_to_byte(1);
+    let selections = editor.raw_selections();
+    assert_eq!(selections.len(), 1);
+    assert_eq!(selections[0].anchor().index(), 3);
+    assert_eq!(selections[0].focus().index(), 0);
+
+    let mut driver = env.driver(&mut editor);
+    driver.add_cursor_at_point(1000., 0.);
+    assert_eq!(editor.raw_selections().len(), 2);
+    env.driver(&mut editor).clear_secondary_selections();
+    assert_eq!(editor.raw_selections().len(), 1);
+    assert_eq!(editor.raw_selection().text_range(), 6..6);
+}
+
+#[test]
+fn editor_add_cursor_below() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let mut editor = env.editor("aaa\naaa\naaa");
+    let mut driver = env.driver(&mut editor);
+    driver.move_to_byte(1);
+    driver.add_cursor_below();
+    driver.add_cursor_below();
+    driver.select_right();
+    let ranges: Vec<_> = editor
+        .raw_selections()
+        .iter()
+        .map(|selection| selection.text_range())
+        .collect();
+    assert_eq!(ranges, [1..2, 5..6, 9..10]);
+}
+
+#[test]
+fn editor_select_next_occurrence() {
+    let mut env = TestEnv::new(test_name!(), None);
+    let mut editor = env.editor("foo bar foo baz foo");
+    let mut driver = env.driver(&mut editor);
+    driver.move_to_byte(9);
+    driver.select_next_occurrence();
+    assert_eq!(editor.raw_selection().text_range(), 8..11);
+
+    let mut driver = env.driver(&mut editor);
+    driver.select_next_occurrence();
+    driver.select_next_occurrence();
+    // All occurrences are selected, so this is a no-op.
+    driver.select_next_occurrence();
+    assert_eq!(editor.raw_selections().len(), 3);
+    assert_eq!(editor.raw_selection().text_range(), 0..3);
+
+    env.driver(&mut editor).insert_or_replace_selection("x");
+    assert_eq!(editor.raw_text(), "x bar x baz x");
+}
+
 #[test]
 fn editor_select_hard_line() {
     let mut env = TestEnv::new(test_name!(), None);
@@ -0,0 +1,182 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Test flowing a layout across several containers.
+
+use super::utils::{ColorBrush, TestEnv};
+use alloc::format;
+
+use crate::{
+    Affinity, BreakInside, Cursor, FlowContainer, Layout, PaginationOptions, StyleProperty,
+    test_name,
+};
+
+const TEXT: &str = "Some text here. Let's make it a bit longer so that we have more lines. \
+    And also some latin text for this spot right here, and some extra more text.";
+
+/// Returns a layout of [`TEXT`] and the height of its first line.
+fn build_layout(env: &mut TestEnv) -> (Layout<ColorBrush>, f32) {
+    let mut layout = env.ranged_builder(TEXT).build(TEXT);
+    layout.break_all_lines(Some(100.));
+    let line_height = layout.get(0).unwrap().metrics().line_height;
+    (layout, line_height)
+}
+
+#[test]
+fn flow_into_containers() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let (mut layout, line_height) = build_layout(&mut env);
+    let containers = [
+        FlowContainer::new(100., line_height * 2.5),
+        FlowContainer::new(150., line_height * 2.5),
+    ];
+    let flow = layout.break_into_containers(&containers);
+
+    assert_eq!(flow.containers().len(), 2);
+    assert_eq!(flow.containers()[0].lines, 0..2);
+    assert_eq!(flow.containers()[1].lines, 2..4);
+    assert!(flow.has_overflow());
+    assert_eq!(flow.overflow().start, 4);
+    assert_eq!(flow.overflow().end, layout.len());
+
+    for (index, container) in flow.containers().iter().enumerate() {
+        assert!(container.height <= containers[index].height);
+        for line_index in container.lines.clone() {
+            let metrics = *layout.get(line_index).unwrap().metrics();
+            assert!(metrics.advance - metrics.trailing_whitespace <= containers[index].width);
+            assert_eq!(flow.container_for_line(line_index), Some(index));
+            assert_eq!(flow.container_for_offset(metrics.baseline), Some(index));
+        }
+    }
+    for line_index in flow.overflow() {
+        let metrics = *layout.get(line_index).unwrap().metrics();
+        assert!(metrics.advance - metrics.trailing_whitespace <= 150.);
+        assert_eq!(flow.container_for_line(line_index), None);
+        assert_eq!(flow.container_for_offset(metrics.baseline), None);
+    }
+}
+
+#[test]
+fn flow_into_containers_without_overflow() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let (mut layout, line_height) = build_layout(&mut env);
+    let containers = [
+        FlowContainer::new(100., line_height * 3.5),
+        FlowContainer::new(100., line_height * 100.),
+        FlowContainer::new(100., line_height * 100.),
+    ];
+    let flow = layout.break_into_containers(&containers);
+
+    assert!(!flow.has_overflow());
+    assert_eq!(flow.containers()[0].lines, 0..3);
+    assert_eq!(flow.containers()[1].lines, 3..layout.len());
+    assert!(flow.containers()[2].lines.is_empty());
+}
+
+#[test]
+fn flow_geometry_mapping() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let (mut layout, line_height) = build_layout(&mut env);
+    let containers = [
+        FlowContainer::new(100., line_height * 2.5),
+        FlowContainer::new(100., line_height * 2.5),
+    ];
+    let flow = layout.break_into_containers(&containers);
+
+    // A cursor at the start of the first line of the second container.
+    let line = layout.get(2).unwrap();
+    let cursor = Cursor::from_byte_index(&layout, line.text_range().start, Affinity::Downstream);
+    let rect = cursor.geometry(&layout, 1.);
+    let (container, local) = flow.to_container_rect(rect).unwrap();
+    assert_eq!(container, 1);
+    // The rectangle spans the glyph extents, which may exceed the line box.
+    let y = f64::from(flow.containers()[1].y);
+    assert_eq!((local.y0, local.y1), (rect.y0 - y, rect.y1 - y));
+    let center = (local.y0 + local.y1) * 0.5;
+    assert!(center >= 0. && center <= f64::from(flow.containers()[1].height));
+    assert!((local.x0 - rect.x0).abs() < 1e-6);
+
+    // Hit testing a point in the local coordinates of the container finds the same cursor.
+    let (x, y) = flow
+        .from_container_point(
+            container,
+            local.x0 as f32 + 0.5,
+            (local.y0 + local.y1) as f32 * 0.5,
+        )
+        .unwrap();
+    let hit = Cursor::from_point(&layout, x, y);
+    assert_eq!(hit.index(), cursor.index());
+    assert_eq!(
+        flow.to_container_point(x, y).map(|(index, ..)| index),
+        Some(1)
+    );
+}
+
+#[test]
+fn paginate_widows() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let (layout, line_height) = build_layout(&mut env);
+    let line_count = layout.len();
+    assert!(line_count > 4);
+    // All but the last line fit on the first page.
+    let page_height = line_height * (line_count as f32 - 0.5);
+
+    let options = PaginationOptions {
+        orphans: 1,
+        widows: 1,
+    };
+    let flow = layout.paginate(core::iter::repeat(page_height), options);
+    assert_eq!(flow.containers()[0].lines, 0..line_count - 1);
+
+    let flow = layout.paginate(
+        core::iter::repeat(page_height),
+        PaginationOptions::default(),
+    );
+    assert_eq!(flow.containers().len(), 2);
+    assert_eq!(flow.containers()[0].lines, 0..line_count - 2);
+    assert_eq!(flow.containers()[1].lines, line_count - 2..line_count);
+    assert!(!flow.has_overflow());
+}
+
+#[test]
+fn paginate_orphans() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let text = format!("Short.\n{TEXT}");
+    let mut layout = env.ranged_builder(&text).build(&text);
+    layout.break_all_lines(Some(100.));
+    let line_height = layout.get(0).unwrap().metrics().line_height;
+
+    // The first page fits the first paragraph and the first line of the second one.
+    let flow = layout.paginate([line_height * 2.5], PaginationOptions::default());
+    assert_eq!(flow.containers().len(), 1);
+    assert_eq!(flow.containers()[0].lines, 0..1);
+    assert_eq!(flow.overflow(), 1..layout.len());
+}
+
+#[test]
+fn paginate_break_inside_avoid() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let (layout, line_height) = build_layout(&mut env);
+    let start = layout.get(1).unwrap().text_range().start + 2;
+    let end = layout.get(3).unwrap().text_range().start + 2;
+    let mut builder = env.ranged_builder(TEXT);
+    builder.push(StyleProperty::BreakInside(BreakInside::Avoid), start..end);
+    let mut layout = builder.build(TEXT);
+    layout.break_all_lines(Some(100.));
+
+    let options = PaginationOptions {
+        orphans: 1,
+        widows: 1,
+    };
+    let flow = layout.paginate(core::iter::repeat(line_height * 2.5), options);
+    // The break inside the span moves up, and is kept when the span doesn't fit on a page.
+    assert_eq!(flow.containers()[0].lines, 0..1);
+    assert_eq!(flow.containers()[1].lines, 1..3);
+    assert_eq!(flow.containers()[2].lines, 3..5);
+}
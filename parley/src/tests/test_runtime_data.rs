@@ -0,0 +1,110 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use super::utils::{ColorBrush, create_font_context, load_unicode_data};
+use crate::{
+    FontContext, FontFamily, LayoutContext, PositionedLayoutItem, StyleProperty, UnicodeData,
+    UnicodeDataError,
+};
+
+const TEXT: &str = "Hello world! The quick brown fox jumps over the lazy dog.\n\
+    مرحبا بالعالم e\u{301}\u{301} 👩‍👩‍👧\nLine 3";
+
+/// The text range of a line along with the ids and positions of its glyphs.
+type LineGlyphs = (Range<usize>, Vec<(u32, f32)>);
+
+/// Lays out [`TEXT`] and returns the glyphs of every line.
+fn lay_out(
+    font_cx: &mut FontContext,
+    layout_cx: &mut LayoutContext<ColorBrush>,
+) -> Vec<LineGlyphs> {
+    let mut builder = layout_cx.ranged_builder(font_cx, TEXT, 1.0, true);
+    builder.push_default(FontFamily::named("Arimo"));
+    builder.push_default(StyleProperty::FontSize(16.0));
+    let mut layout = builder.build(TEXT);
+    layout.break_all_lines(Some(150.0));
+    layout
+        .lines()
+        .map(|line| {
+            let glyphs = line
+                .items()
+                .filter_map(|item| match item {
+                    PositionedLayoutItem::GlyphRun(run) => Some(run),
+                    PositionedLayoutItem::InlineBox(_) => None,
+                })
+                .flat_map(|run| {
+                    run.positioned_glyphs()
+                        .map(|glyph| (glyph.id, glyph.x))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            (line.text_range(), glyphs)
+        })
+        .collect()
+}
+
+#[test]
+fn try_with_unicode_data() {
+    let data = load_unicode_data();
+    let mut font_cx = create_font_context();
+    let mut layout_cx = LayoutContext::try_with_unicode_data(&data).unwrap();
+
+    let lines = lay_out(&mut font_cx, &mut layout_cx);
+    assert!(lines.len() > 3, "the first paragraph must wrap");
+    assert!(lines.iter().all(|(_, glyphs)| !glyphs.is_empty()));
+    // Mandatory breaks come from the composite data and soft breaks from the
+    // line segmenter.
+    assert_eq!(
+        lines.last().unwrap().0,
+        TEXT.len() - "Line 3".len()..TEXT.len()
+    );
+    assert!(
+        lines[..2]
+            .iter()
+            .all(|(range, _)| TEXT[..range.end].ends_with(' '))
+    );
+
+    // A context created from the same data can be cloned and reused.
+    let mut other = layout_cx.clone();
+    assert_eq!(lay_out(&mut font_cx, &mut other), lines);
+}
+
+#[test]
+#[cfg(feature = "baked_data")]
+fn runtime_data_matches_baked_data() {
+    let mut font_cx = create_font_context();
+    let mut baked = LayoutContext::new();
+    let mut runtime = LayoutContext::try_with_unicode_data(&load_unicode_data()).unwrap();
+    assert_eq!(
+        lay_out(&mut font_cx, &mut runtime),
+        lay_out(&mut font_cx, &mut baked)
+    );
+}
+
+#[test]
+fn invalid_unicode_data() {
+    let blob = std::fs::read(parley_dev::unicode_data_blob()).unwrap();
+    let (_, icu4x_data) = parley_data::blob::split(&blob).unwrap();
+
+    assert_eq!(
+        UnicodeData::try_from_blob(b"").unwrap_err(),
+        UnicodeDataError::Format
+    );
+    assert_eq!(
+        UnicodeData::try_from_blob(&blob[1..]).unwrap_err(),
+        UnicodeDataError::Format
+    );
+    // The composite section is truncated.
+    assert_eq!(
+        UnicodeData::try_from_blob(&blob[..32]).unwrap_err(),
+        UnicodeDataError::Format
+    );
+    // The ICU4X data is missing.
+    assert!(matches!(
+        UnicodeData::try_from_blob(&blob[..blob.len() - icu4x_data.len()]),
+        Err(UnicodeDataError::Data(_))
+    ));
+}
@@ -3,8 +3,12 @@
 
 use peniko::color::palette::css;
 
+use alloc::{format, string::String, vec::Vec};
+
 use crate::{
-    Alignment, AlignmentOptions, OverflowWrap, StyleProperty, TextWrapMode, WordBreak, test_name,
+    Alignment, AlignmentOptions, Cluster, InitialLetter, Layout, LineBreakStrategy, LineSpan,
+    OptimalBreakOptions, OverflowWrap, PositionedLayoutItem, StyleProperty, TextWrapMode,
+    TextWrapStyle, WordBreak, test_name,
 };
 
 use super::utils::{ColorBrush, TestEnv};
@@ -404,3 +408,345 @@ fn text_wrap_mode_updates_min_content_width() {
         span_width
     );
 }
+
+/// Text that greedily wraps into two full lines of five words and a last line of a single word
+/// at [`five_words_width`].
+const WRAP_STYLE_TEXT: &str = "word word word word word word word word word word end";
+
+/// Returns a width that fits five words of [`WRAP_STYLE_TEXT`], but not six.
+fn five_words_width(env: &mut TestEnv) -> f32 {
+    let text = "word word word word word";
+    let mut layout = env.ranged_builder(text).build(text);
+    layout.break_all_lines(None);
+    layout.width() + 0.5
+}
+
+/// Returns the text and width (excluding trailing whitespace) of each line.
+fn wrap_style_lines(
+    env: &mut TestEnv,
+    text_wrap_style: TextWrapStyle,
+    max_advance: f32,
+) -> Vec<(String, f32)> {
+    let text = WRAP_STYLE_TEXT;
+    let mut builder = env.ranged_builder(text);
+    builder.push_default(StyleProperty::TextWrapStyle(text_wrap_style));
+    let mut layout = builder.build(text);
+    layout.break_all_lines(Some(max_advance));
+    layout
+        .lines()
+        .map(|line| {
+            let metrics = line.metrics();
+            (
+                String::from(text[line.text_range()].trim_end()),
+                metrics.advance - metrics.trailing_whitespace,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn text_wrap_style_auto_is_greedy() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let max_advance = five_words_width(&mut env);
+    let lines = wrap_style_lines(&mut env, TextWrapStyle::Auto, max_advance);
+    let texts: Vec<_> = lines.iter().map(|(text, _)| text.as_str()).collect();
+    assert_eq!(
+        texts,
+        [
+            "word word word word word",
+            "word word word word word",
+            "end"
+        ]
+    );
+}
+
+#[test]
+fn text_wrap_style_balance() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let max_advance = five_words_width(&mut env);
+    let greedy = wrap_style_lines(&mut env, TextWrapStyle::Auto, max_advance);
+    let balanced = wrap_style_lines(&mut env, TextWrapStyle::Balance, max_advance);
+
+    assert_eq!(
+        balanced.len(),
+        greedy.len(),
+        "Balancing should not change the number of lines"
+    );
+    let spread = |lines: &[(String, f32)]| {
+        let widths = lines.iter().map(|(_, width)| *width);
+        widths.clone().fold(0_f32, f32::max) - widths.fold(f32::MAX, f32::min)
+    };
+    assert!(
+        spread(&balanced) < spread(&greedy),
+        "Balanced lines {balanced:?} should be more even than greedy lines {greedy:?}"
+    );
+    for (text, width) in &balanced {
+        assert!(*width <= max_advance, "Line {text:?} overflows");
+    }
+}
+
+#[test]
+fn text_wrap_style_pretty() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let max_advance = five_words_width(&mut env);
+    let pretty = wrap_style_lines(&mut env, TextWrapStyle::Pretty, max_advance);
+
+    assert_eq!(pretty.len(), 3, "Unexpected lines {pretty:?}");
+    let (last_line, _) = pretty.last().unwrap();
+    assert!(
+        last_line.contains(' '),
+        "The last line {last_line:?} should have more than one word"
+    );
+    for (text, width) in &pretty {
+        assert!(*width <= max_advance, "Line {text:?} overflows");
+    }
+}
+
+#[test]
+fn text_wrap_style_per_paragraph() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let max_advance = five_words_width(&mut env);
+    let text = format!("{WRAP_STYLE_TEXT}\n{WRAP_STYLE_TEXT}");
+    let mut builder = env.ranged_builder(&text);
+    let second_paragraph = WRAP_STYLE_TEXT.len() + 1..text.len();
+    builder.push(
+        StyleProperty::TextWrapStyle(TextWrapStyle::Pretty),
+        second_paragraph,
+    );
+    let mut layout = builder.build(&text);
+    layout.break_all_lines(Some(max_advance));
+
+    let lines: Vec<String> = layout
+        .lines()
+        .map(|line| String::from(text[line.text_range()].trim_end()))
+        .collect();
+    assert_eq!(lines.len(), 6, "Unexpected lines {lines:?}");
+    assert_eq!(lines[2], "end", "The first paragraph should wrap greedily");
+    assert!(
+        lines[5].contains(' '),
+        "The last line {:?} of the second paragraph should have more than one word",
+        lines[5]
+    );
+}
+
+/// Returns the text of each line, without trailing whitespace.
+fn line_texts(layout: &Layout<ColorBrush>, text: &str) -> Vec<String> {
+    layout
+        .lines()
+        .map(|line| String::from(text[line.text_range()].trim_end()))
+        .collect()
+}
+
+#[test]
+fn line_break_strategy_optimal() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let max_advance = five_words_width(&mut env);
+    let text = WRAP_STYLE_TEXT;
+    let mut layout = env.ranged_builder(text).build(text);
+    layout.break_all_lines(Some(max_advance));
+    let greedy = line_texts(&layout, text);
+    layout.break_all_lines_with_strategy(
+        Some(max_advance),
+        LineBreakStrategy::Optimal(OptimalBreakOptions::default()),
+    );
+    let optimal = line_texts(&layout, text);
+
+    // Full lines followed by a short last line are optimal, as the last line isn't justified.
+    assert_eq!(optimal, greedy);
+    for line in layout.lines() {
+        let metrics = line.metrics();
+        assert!(metrics.advance - metrics.trailing_whitespace <= max_advance);
+    }
+}
+
+#[test]
+fn line_break_strategy_optimal_hyphen_limit() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let text = "well-known well-known well-known well-known well-known well-known";
+    let max_advance = {
+        let prefix = "well-known well-";
+        let mut layout = env.ranged_builder(prefix).build(prefix);
+        layout.break_all_lines(None);
+        layout.width() + 0.5
+    };
+    let mut layout = env.ranged_builder(text).build(text);
+    layout.break_all_lines(Some(max_advance));
+    let greedy = line_texts(&layout, text);
+    assert!(
+        greedy.iter().any(|line| line.ends_with('-')),
+        "Greedy lines {greedy:?} should break after hyphens"
+    );
+
+    let options = OptimalBreakOptions {
+        max_consecutive_hyphens: Some(0),
+        ..OptimalBreakOptions::default()
+    };
+    layout.break_all_lines_with_strategy(Some(max_advance), LineBreakStrategy::Optimal(options));
+    let optimal = line_texts(&layout, text);
+    assert!(
+        optimal.iter().all(|line| !line.ends_with('-')),
+        "Optimal lines {optimal:?} should not break after hyphens"
+    );
+}
+
+#[test]
+fn line_break_strategy_optimal_shrink() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    // Five words overflow by half a unit, which shrinking their spaces makes up for.
+    let max_advance = five_words_width(&mut env) - 1.;
+    let text = WRAP_STYLE_TEXT;
+    let mut layout = env.ranged_builder(text).build(text);
+    layout.break_all_lines(Some(max_advance));
+    assert_eq!(line_texts(&layout, text)[0], "word word word word");
+
+    let options = OptimalBreakOptions {
+        space_shrink: 0.5,
+        ..OptimalBreakOptions::default()
+    };
+    layout.break_all_lines_with_strategy(Some(max_advance), LineBreakStrategy::Optimal(options));
+    let lines = line_texts(&layout, text);
+    assert_eq!(
+        lines[0], "word word word word word",
+        "Unexpected lines {lines:?}"
+    );
+
+    layout.align(
+        Some(max_advance),
+        Alignment::Justify,
+        AlignmentOptions::default(),
+    );
+    for line in layout.lines() {
+        let mut advance = 0.;
+        for item in line.items() {
+            if let PositionedLayoutItem::GlyphRun(glyph_run) = item {
+                advance += glyph_run.glyphs().map(|glyph| glyph.advance).sum::<f32>();
+            }
+        }
+        advance -= line.metrics().trailing_whitespace;
+        assert!(
+            advance <= max_advance + 0.01,
+            "Justified line {:?} overflows",
+            &text[line.text_range()]
+        );
+    }
+}
+
+#[test]
+fn break_lines_in_shape() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let width = five_words_width(&mut env);
+    let text = format!("{WRAP_STYLE_TEXT} {WRAP_STYLE_TEXT}");
+    let mut layout = env.ranged_builder(&text).build(&text);
+    layout.break_all_lines(Some(width));
+    let line_height = layout.get(0).unwrap().metrics().line_height;
+
+    // A float beside the first two lines takes up the left half of the layout.
+    let float_bottom = line_height * 1.5;
+    let float_width = width * 0.5;
+    let shape = |y: core::ops::Range<f32>| {
+        if y.start < float_bottom {
+            LineSpan::new(float_width, width - float_width)
+        } else {
+            LineSpan::new(0., width)
+        }
+    };
+    layout.break_all_lines_in_shape(shape);
+
+    let lines: Vec<_> = layout.lines().collect();
+    assert!(lines.len() > 3, "Unexpected line count {}", lines.len());
+    for (index, line) in lines.iter().enumerate() {
+        let metrics = line.metrics();
+        let span = shape(metrics.min_coord..metrics.max_coord);
+        assert_eq!(
+            metrics.offset, span.offset,
+            "Line {index} has the wrong offset"
+        );
+        assert!(
+            metrics.advance - metrics.trailing_whitespace <= span.width,
+            "Line {index} overflows its span"
+        );
+    }
+    assert_eq!(lines[0].metrics().offset, float_width);
+    assert_eq!(lines[1].metrics().offset, float_width);
+    assert_eq!(lines[2].metrics().offset, 0.);
+
+    // Alignment and hit testing honor the offset.
+    layout.align(None, Alignment::End, AlignmentOptions::default());
+    for line in layout.lines() {
+        let metrics = line.metrics();
+        let span = shape(metrics.min_coord..metrics.max_coord);
+        let end = metrics.offset + metrics.advance - metrics.trailing_whitespace;
+        assert!(
+            (end - (span.offset + span.width)).abs() < 0.01,
+            "Line should be aligned to the end of its span"
+        );
+        for item in line.items() {
+            let PositionedLayoutItem::GlyphRun(glyph_run) = item else {
+                continue;
+            };
+            for glyph in glyph_run.positioned_glyphs() {
+                assert!(glyph.x >= span.offset - 0.01);
+                let (cluster, _) = Cluster::from_point(&layout, glyph.x + 0.1, glyph.y).unwrap();
+                assert_eq!(cluster.glyphs().next().unwrap().id, glyph.id);
+            }
+        }
+    }
+}
+
+#[test]
+/// Lines beside an initial letter are chosen with the width left beside it.
+fn wrap_style_initial_letter() {
+    let mut env = TestEnv::new(test_name!(), None);
+
+    let text = "Once upon a time, there was a paragraph with enough words to wrap onto several \
+        lines of text beside its initial letter, and then some more words after it.";
+    let max_advance = 150.;
+    for (text_wrap_style, strategy) in [
+        (TextWrapStyle::Balance, LineBreakStrategy::Greedy),
+        (TextWrapStyle::Pretty, LineBreakStrategy::Greedy),
+        (
+            TextWrapStyle::Auto,
+            LineBreakStrategy::Optimal(OptimalBreakOptions::default()),
+        ),
+    ] {
+        let mut builder = env.ranged_builder(text);
+        builder.push_default(StyleProperty::TextWrapStyle(text_wrap_style));
+        builder.push(
+            StyleProperty::InitialLetter(Some(InitialLetter::new(3.))),
+            0..1,
+        );
+        let mut layout = builder.build(text);
+        layout.break_all_lines_with_strategy(Some(max_advance), strategy);
+        layout.align(None, Alignment::Start, AlignmentOptions::default());
+
+        let lines: Vec<_> = layout.lines().collect();
+        let indent = lines[1].metrics().offset;
+        assert!(indent > 0., "{text_wrap_style:?}: lines should be indented");
+        for (index, line) in lines.iter().enumerate() {
+            let metrics = line.metrics();
+            let width = metrics.advance - metrics.trailing_whitespace;
+            assert!(
+                metrics.offset + width <= max_advance,
+                "{text_wrap_style:?}: line {index} overflows"
+            );
+            // The lines beside the initial letter are planned with the width left beside it, so
+            // they are not cut short by breaking them greedily.
+            if (1..3).contains(&index) {
+                assert_eq!(metrics.offset, indent);
+                assert!(
+                    width >= (max_advance - indent) * 0.5,
+                    "{text_wrap_style:?}: line {index} is too short: {:?}",
+                    &text[line.text_range()]
+                );
+            }
+        }
+    }
+}
@@ -11,6 +11,10 @@ pub(crate) fn assert_eq_layout_data<B: Brush>(a: &LayoutData<B>, b: &LayoutData<
     assert_eq!(a.quantize, b.quantize, "{case} quantize mismatch");
     assert_eq!(a.base_level, b.base_level, "{case} base_level mismatch");
     assert_eq!(a.text_len, b.text_len, "{case} text_len mismatch");
+    assert_eq!(
+        a.font_generation, b.font_generation,
+        "{case} font_generation mismatch"
+    );
     assert_eq!(a.width, b.width, "{case} width mismatch");
     assert_eq!(a.full_width, b.full_width, "{case} full_width mismatch");
     assert_eq!(a.height, b.height, "{case} height mismatch");
@@ -39,6 +43,10 @@ pub(crate) fn assert_eq_layout_data<B: Brush>(a: &LayoutData<B>, b: &LayoutData<
         a.is_aligned_justified, b.is_aligned_justified,
         "{case} is_aligned_justified mismatch"
     );
+    assert_eq!(
+        a.text_justify, b.text_justify,
+        "{case} text_justify mismatch"
+    );
     assert_eq!(
         a.alignment_width, b.alignment_width,
         "{case} alignment_width mismatch"
@@ -8,6 +8,7 @@ use alloc::{
 use std::eprintln;
 use tiny_skia::{Color, Pixmap, PixmapPaint, Transform};
 
+use crate::tests::utils::create_layout_context;
 use crate::tests::utils::renderer::{ColorBrush, RenderingConfig, render_layout};
 use crate::{Affinity, Cursor, FontContext, Layout, LayoutContext};
 
@@ -417,7 +418,7 @@ mod tests {
 
     #[test]
     fn cursor_next_visual() {
-        let (mut lcx, mut fcx) = (LayoutContext::new(), FontContext::new());
+        let (mut lcx, mut fcx) = (create_layout_context(), FontContext::new());
         let text = "Lorem ipsum dolor sit amet";
         let layout = CursorTest::single_line(text, &mut lcx, &mut fcx);
 
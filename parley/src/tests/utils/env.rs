@@ -1,11 +1,13 @@
 // Copyright 2024 the Parley Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(feature = "runtime_data")]
+use crate::UnicodeData;
 use crate::tests::utils::renderer::{
     ColorBrush, RenderingConfig, render_layout, render_layout_with_clusters,
 };
 use crate::{
-    BoundingBox, FontContext, FontFamily, FontFamilyName, Layout, LayoutContext, LineHeight,
+    BoundingBox, Brush, FontContext, FontFamily, FontFamilyName, Layout, LayoutContext, LineHeight,
     PlainEditor, PlainEditorDriver, RangedBuilder, StyleProperty, TextStyle, TreeBuilder,
 };
 use alloc::{
@@ -108,6 +110,26 @@ pub(crate) fn load_fonts(
     Ok(())
 }
 
+/// Creates a layout context from the baked Unicode data, or from the blob in
+/// `parley_dev` if the data is not baked.
+pub(crate) fn create_layout_context<B: Brush>() -> LayoutContext<B> {
+    #[cfg(feature = "baked_data")]
+    {
+        LayoutContext::new()
+    }
+    #[cfg(not(feature = "baked_data"))]
+    {
+        LayoutContext::try_with_unicode_data(&load_unicode_data()).unwrap()
+    }
+}
+
+/// Loads the Unicode data blob in `parley_dev`.
+#[cfg(feature = "runtime_data")]
+pub(crate) fn load_unicode_data() -> UnicodeData {
+    let blob = std::fs::read(parley_dev::unicode_data_blob()).unwrap();
+    UnicodeData::try_from_blob(&blob).unwrap()
+}
+
 pub(crate) fn create_font_context() -> FontContext {
     let mut collection = Collection::new(CollectionOptions {
         shared: false,
@@ -147,7 +169,7 @@ impl TestEnv {
             check_counter: 0,
             font_cx: create_font_context(),
             tolerance: 0.0,
-            layout_cx: LayoutContext::new(),
+            layout_cx: create_layout_context(),
             text_color: Color::BLACK,
             rendering_config: RenderingConfig {
                 background_color: Color::WHITE,
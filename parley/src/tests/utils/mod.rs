@@ -7,5 +7,7 @@ mod env;
 mod renderer;
 
 pub(crate) use cursor_test::CursorTest;
-pub(crate) use env::{FONT_FAMILY_LIST, TestEnv, create_font_context};
+#[cfg(feature = "runtime_data")]
+pub(crate) use env::load_unicode_data;
+pub(crate) use env::{FONT_FAMILY_LIST, TestEnv, create_font_context, create_layout_context};
 pub(crate) use renderer::ColorBrush;
@@ -0,0 +1,98 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Container format for Unicode data that is loaded at runtime.
+//!
+//! The format consists of the [`MAGIC`] bytes, a single [`VERSION`] byte,
+//! the length of the composite section as a little endian `u32`, the
+//! composite section and finally the ICU4X data as a postcard blob. The
+//! composite section holds the `CodePointTrie` backing [`CompositeProps`]
+//! serialized with postcard.
+//!
+//! [`CompositeProps`]: crate::CompositeProps
+
+/// Bytes at the start of every blob.
+pub const MAGIC: &[u8; 8] = b"PRLYDATA";
+
+/// Current version of the blob format.
+pub const VERSION: u8 = 1;
+
+/// Splits a blob into its composite section and its ICU4X data.
+///
+/// Returns `None` if the header is missing, of an unsupported version or if
+/// the data is truncated.
+pub fn split(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let rest = data.strip_prefix(MAGIC.as_slice())?;
+    let (&version, rest) = rest.split_first()?;
+    if version != VERSION {
+        return None;
+    }
+    let (len, rest) = rest.split_first_chunk::<4>()?;
+    let len = usize::try_from(u32::from_le_bytes(*len)).ok()?;
+    if rest.len() < len {
+        return None;
+    }
+    Some(rest.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{MAGIC, VERSION, split};
+    use std::vec::Vec;
+
+    fn blob(version: u8, composite: &[u8], icu4x_data: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(MAGIC);
+        data.push(version);
+        data.extend_from_slice(&u32::try_from(composite.len()).unwrap().to_le_bytes());
+        data.extend_from_slice(composite);
+        data.extend_from_slice(icu4x_data);
+        data
+    }
+
+    #[test]
+    fn split_sections() {
+        let data = blob(VERSION, b"composite", b"icu4x");
+        assert_eq!(
+            split(&data),
+            Some((b"composite".as_slice(), b"icu4x".as_slice()))
+        );
+        let data = blob(VERSION, b"", b"");
+        assert_eq!(split(&data), Some((b"".as_slice(), b"".as_slice())));
+    }
+
+    #[test]
+    fn split_invalid() {
+        let data = blob(VERSION, b"composite", b"icu4x");
+        assert_eq!(split(b""), None);
+        assert_eq!(split(&data[1..]), None);
+        assert_eq!(split(&data[..MAGIC.len() + 3]), None);
+        // The composite section is truncated.
+        assert_eq!(split(&data[..MAGIC.len() + 5 + 4]), None);
+        assert_eq!(split(&blob(VERSION + 1, b"composite", b"icu4x")), None);
+    }
+
+    /// The blob checked in for Parley's tests holds the same composite trie
+    /// as the baked data.
+    #[test]
+    #[cfg(all(feature = "baked", feature = "runtime"))]
+    fn composite_round_trip() {
+        use crate::CompositeProps;
+        use std::boxed::Box;
+
+        let data = std::fs::read(parley_dev::unicode_data_blob()).unwrap();
+        let (composite, _) = split(&data).unwrap();
+        let runtime = CompositeProps::try_from_postcard(composite.into()).unwrap();
+        let baked = CompositeProps::baked();
+        for ch in 0..=0x10FFFF {
+            assert_eq!(
+                u32::from(runtime.properties(ch)),
+                u32::from(baked.properties(ch)),
+                "{ch:#x}"
+            );
+        }
+        assert!(CompositeProps::try_from_postcard(Box::default()).is_none());
+    }
+}
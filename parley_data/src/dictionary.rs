@@ -0,0 +1,62 @@
+// Copyright 2025 the Parley Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compact binary format for word segmentation dictionaries.
+//!
+//! The format consists of the [`MAGIC`] bytes, a single [`VERSION`] byte and
+//! a list of UTF-8 encoded words, each terminated by a `\n`. Words are unique
+//! and sorted by their byte representation so that they can be searched with
+//! a binary search after loading.
+
+/// Bytes at the start of every dictionary.
+pub const MAGIC: &[u8; 8] = b"PRLYDICT";
+
+/// Current version of the dictionary format.
+pub const VERSION: u8 = 1;
+
+/// A validated, borrowed view of dictionary data.
+#[derive(Copy, Clone, Debug)]
+pub struct DictionaryData<'a> {
+    words: &'a str,
+}
+
+impl<'a> DictionaryData<'a> {
+    /// Validates the given bytes and returns a view of the contained words.
+    ///
+    /// Returns `None` if the header is missing or of an unsupported version,
+    /// if the data is not valid UTF-8 or if the words are not sorted and
+    /// unique.
+    pub fn new(data: &'a [u8]) -> Option<Self> {
+        let rest = data.strip_prefix(MAGIC.as_slice())?;
+        let (&version, rest) = rest.split_first()?;
+        if version != VERSION {
+            return None;
+        }
+        let words = core::str::from_utf8(rest).ok()?;
+        if !words.is_empty() && !words.ends_with('\n') {
+            return None;
+        }
+        let data = Self { words };
+        let mut words = data.words();
+        if let Some(mut prev) = words.next() {
+            for word in words {
+                if word.as_bytes() <= prev.as_bytes() {
+                    return None;
+                }
+                prev = word;
+            }
+        }
+        Some(data)
+    }
+
+    /// Returns the words in sorted order, each terminated by a `\n`.
+    pub fn as_str(&self) -> &'a str {
+        self.words
+    }
+
+    /// Returns an iterator over the words in sorted order.
+    pub fn words(&self) -> impl Iterator<Item = &'a str> + Clone {
+        let words = self.words;
+        words.split_terminator('\n').filter(|word| !word.is_empty())
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 /// Backing data for the `CompositeProps`
-/// Expected size: 70344B
+/// Expected size: 73260B
 #[rustfmt::skip]
 #[allow(unsafe_code, unused_unsafe, clippy::unseparated_literal_suffix, reason = "databake behaviour")]
-pub const COMPOSITE: icu_collections::codepointtrie::CodePointTrie<'static, u32> = unsafe { icu_collections :: codepointtrie :: CodePointTrie :: from_parts_unstable_unchecked_v1 (icu_collections :: codepointtrie :: CodePointTrieHeader { high_start : 1114112u32 , shifted12_high_start : 272u16 , index3_null_offset : 1927u16 , data_null_offset : 3399u32 , null_value : 103u32 , trie_type : icu_collections :: codepointtrie :: TrieType :: Small , } , unsafe { zerovec :: ZeroVec :: from_bytes_unchecked (b"\0\0@\0\x7F\0\xBF\0\xFF\0.\x01m\x01\xAD\x01\xE5\x01$\x02P\x02\x8E\x02\xCE\x02\xDE\x02\x1E\x03O\x03\x8F\x03\xBF\x03\xFD\x03=\x04M\x04~\x04\xB5\x04\xF5\x045\x05u\x05\xA6\x05\xD2\x05\x12\x06G\x06\x87\x06\xC7\x06\x07\x07G\x07\x7F\x07\xB6\x07\xF6\x075\x08u\x08\xB4\x08\xF3\x082\tq\t\xB0\t\xF0\t0\nn\n\xAE\n\xEE\n-\x0Bm\x0B\xAD\x0B\xED\x0B,\x0Cl\x0C\xAC\x0C\xEB\x0C+\rj\r\xAA\r\xEA\r*\x0Ej\x0E\xA8\x0E&\r@\rP\ri\r\x89\r\xA8\r\xC3\r\xE2\r\x02\x0E\x02\x0E\x0F\x0E,\x0EL\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0Eg\x0Eg\x0Eg\x0Eh\x0E\x88\x0Eg\x0Eg\x0Eh\x0E\xA8\x0E\xA8\x0E\xA8\x0E\xA9\x0E\xA8\x0E\xA8\x0E\xA8\x0E\xA9\x0E\0\0\x10\0 \x000\0@\0P\0`\0p\0\x7F\0\x8F\0\x9F\0\xAF\0\xBF\0\xCF\0\xDF\0\xEF\0\xFF\0\x0F\x01\x1F\x01/\x01.\x01>\x01N\x01^\x01m\x01}\x01\x8D\x01\x9D\x01\xAD\x01\xBD\x01\xCD\x01\xDD\x01\xE5\x01\xF5\x01\x05\x02\x15\x02$\x024\x02D\x02T\x02P\x02`\x02p\x02\x80\x02\x8E\x02\x9E\x02\xAE\x02\xBE\x02\xCE\x02\xDE\x02\xEE\x02\xFE\x02\xDE\x02\xEE\x02\xFE\x02\x0E\x03\x1E\x03.\x03>\x03N\x03O\x03_\x03o\x03\x7F\x03\x8F\x03\x9F\x03\xAF\x03\xBF\x03\xBF\x03\xCF\x03\xDF\x03\xEF\x03\xFD\x03\r\x04\x1D\x04-\x04=\x04M\x04]\x04m\x04M\x04]\x04m\x04}\x04~\x04\x8E\x04\x9E\x04\xAE\x04\xB5\x04\xC5\x04\xD5\x04\xE5\x04\xF5\x04\x05\x05\x15\x05%\x055\x05E\x05U\x05e\x05u\x05\x85\x05\x95\x05\xA5\x05\xA6\x05\xB6\x05\xC6\x05\xD6\x05\xD2\x05\xE2\x05\xF2\x05\x02\x06\x12\x06\"\x062\x06B\x06G\x06W\x06g\x06w\x06\x87\x06\x97\x06\xA7\x06\xB7\x06\xC7\x06\xD7\x06\xE7\x06\xF7\x06\x07\x07\x17\x07'\x077\x07G\x07W\x07g\x07w\x07\x7F\x07\x8F\x07\x9F\x07\xAF\x07\xB6\x07\xC6\x07\xD6\x07\xE6\x07\xF6\x07\x06\x08\x16\x08&\x085\x08E\x08U\x08e\x08u\x08\x85\x08\x95\x08\xA5\x08\xB4\x08\xC4\x08\xD4\x08\xE4\x08\xF3\x08\x03\t\x13\t#\t2\tB\tR\tb\tq\t\x81\t\x91\t\xA1\t\xB0\t\xC0\t\xD0\t\xE0\t\xF0\t\0\n\x10\n \n0\n@\nP\n`\nn\n~\n\x8E\n\x9E\n\xAE\n\xBE\n\xCE\n\xDE\n\xEE\n\xFE\n\x0E\x0B\x1E\x0B-\x0B=\x0BM\x0B]\x0Bm\x0B}\x0B\x8D\x0B\x9D\x0B\xAD\x0B\xBD\x0B\xCD\x0B\xDD\x0B\xED\x0B\xFD\x0B\r\x0C\x1D\x0C,\x0C<\x0CL\x0C\\\x0Cl\x0C|\x0C\x8C\x0C\x9C\x0C\xAC\x0C\xBC\x0C\xCC\x0C\xDC\x0C\xEB\x0C\xFB\x0C\x0B\r\x1B\r+\r;\rK\r[\rj\rz\r\x8A\r\x9A\r\xAA\r\xBA\r\xCA\r\xDA\r\xEA\r\xFA\r\n\x0E\x1A\x0E*\x0E:\x0EJ\x0EZ\x0Ej\x0Ez\x0E\x8A\x0E\x9A\x0E\xA8\x0E\xB8\x0E\xC8\x0E\xD8\x0E\xE8\x0E\xE8\x0E\xED\x0E\xFC\x0E\x0C\x0F\x1C\x0F+\x0F:\x0FH\x0FX\x0Fh\x0Fh\x0Fr\x0F\x82\x0F\x82\x0F\x87\x0F\x97\x0F\x97\x0F\x97\x0F\x97\x0F\x97\x0F\x97\x0F\xA7\x0F\xA7\x0F\xA7\x0F\xA7\x0F\xAF\x0F\xB7\x0F\xB7\x0F\xB7\x0F\xB7\x0F\xB7\x0F\xC7\x0F\xC7\x0F\xC7\x0F\xC7\x0F\xCE\x0F\xDE\x0F\xC7\x0F\xC7\x0F\xCE\x0F\xC7\x0F\xC7\x0F\xD6\x0F\xE6\x0F\xEF\x0F\xC7\x0F\xC7\x0F\xC7\x0F\xE6\x0F\xC7\x0F\xC7\x0F\xC7\x0F\xF7\x0F\x07\x10\x10\x10\xC7\x0F \x100\x100\x100\x100\x100\x10:\x10J\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10K\x10N\x10K\x10^\x10c\x10s\x10s\x10s\x10s\x10x\x10\x87\x10\x97\x10\xA5\x10\xB5\x10\xC3\x10\xD3\x10\xE1\x10\xF1\x10\0\x11\x10\x11\x10\x11\x10\x11\x1C\x11*\x116\x11F\x11V\x11f\x11v\x11\x86\x11\x86\x11\x93\x11\x86\x11\x86\x11\x9A\x11\xAA\x11\x86\x11\xB1\x11K\x10K\x10K\x10K\x10\xC1\x11\xD1\x11\xD2\x11\xE2\x11\xF2\x11\x02\x12\x12\x12\x14\x12\x1D\x12-\x12-\x121\x12-\x123\x12C\x12S\x12S\x12c\x12l\x12|\x12|\x12|\x12\x87\x12\x97\x12\xA4\x12\xB4\x12\xB4\x12\xC4\x12\xD4\x12\xCE\x02\xE3\x12\xE5\x12G\r\xF5\x12\xFA\x12\xFA\x12\x06\x13\x14\x13$\x133\x13?\x13O\x13R\x13a\x13q\x13\x81\x13\x81\x13\x8B\x13\x9A\x13\xAA\x13\xAA\x13\xB6\x13\xC2\x13\xD2\x13\xE2\x13\xEC\x13\xF4\x13\x04\x14h\x0Fh\x0F\x14\x14$\x144\x14C\x14L\x14a\0a\0\\\x14h\x14h\x14k\x14y\x14\x84\x14a\0u\x02h\x14i\x14\xCE\x02\xCE\x02\xCE\x02\xCE\x02\xFF\0\xFF\0\xFF\0\xFF\0\xFF\0\xFF\0\xFF\0\xFF\0\xFF\0\x94\x14\xFF\0\xFF\0\xFF\0\xFF\0\xFF\0\xFF\0\xA4\x14\xB4\x14\xA4\x14\xA4\x14\xB4\x14\xC4\x14\xA4\x14\xD4\x14\xE4\x14\xE4\x14\xE4\x14\xF4\x14\x03\x15\x13\x15#\x153\x15C\x15S\x15c\x15s\x15\x82\x15\x90\x15\xA0\x15\xB0\x15\xC0\x15\xD0\x15\xE0\x15\xE0\x15\xEE\x15\xFE\x15\r\x16\x1C\x16,\x16<\x16J\x16Z\x16j\x16z\x16\x8A\x16\x8A\x16\x97\x16\xA7\x16\xB7\x16\xC6\x16\xC8\x16\xD8\x16\xC6\x16\xE4\x16\xE8\x16\xF6\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\x06\x17\x12\x17\"\x17-\x173\x173\x173\x178\x17\xC6\x16E\x17\xE8\x16Q\x17U\x17e\x17s\x17\x7F\x17\xC6\x16\xC6\x16\x8A\x17G\r\x9A\x17G\rz\x16z\x16\xAA\x17\xB2\x173\x173\x17\xC0\x173\x17\xC6\x17z\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\x12\x17\xD6\x17\xDC\x17\xC6\x16\xC6\x16\xE4\x17\xF4\x17\x01\x18\x11\x18!\x181\x18=\x18L\x18\\\x18\xC6\x16h\x18s\x18\x83\x18\x8C\x18\x9C\x18\xA3\x18\xB3\x18\xBE\x18\xCE\x18\xD3\x18\x80\x18\xDF\x18\xEE\x18\xFB\x18\x05\x19z\x16\x11\x19 \x19!\x191\x19\xE8\x16;\x19\xE8\x16K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19K\x19\xE8\x16\xE8\x16\xE8\x16[\x19\xE8\x16\xE8\x16\xE8\x16\xE8\x16h\x19p\x19\xE8\x16\xE8\x16\xE8\x16y\x19\xE8\x16\x85\x19\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\xE8\x16\x95\x19\xA1\x18\xC6\x16\xE8\x16\xA5\x19\xB5\x19\xC6\x16\xC1\x19\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xD1\x19\xD1\x19\xD1\x19\xE1\x19\xE1\x19\xE1\x19\xF1\x19\0\x1A\x10\x1A\x10\x1A\x10\x1A\x10\x1A\x10\x1A\x10\x1A\x1C\x1A+\x1A\x82\x0F\x82\x0F;\x1AK\x1AK\x1AK\x1AS\x1Ac\x1A\xC7\x0Fs\x1A\x83\x1A\x83\x1A\x83\x1A\x83\x1A\x93\x1A\x93\x1A\xA3\x1A\xB1\x1A\xC1\x1A\xD1\x1A\xE1\x1A\xF1\x1AG\rG\r\x01\x1B\x07\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x13\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B\x01\x1B#\x1BG\r\xC6\x163\x1BA\x1BQ\x1Ba\x1Bq\x1Br\x1Br\x1Br\x1Br\x1B{\x1B\x8B\x1B\x8C\x1B\x8C\x1B\x8C\x1B\x8C\x1B\x91\x1B\xA1\x1B\xA6\x1B\xA6\x1B\xB6\x1B\xB7\x1B\xB7\x1B\xB7\x1B\xB7\x1B\xB8\x1B\xC8\x1B\xA6\x1B\xA6\x1B\xC6\x16\xC6\x16\xD8\x1B\x8C\x1B\xE8\x1B\xEB\x1B\xFB\x1B3\x17\x05\x1C\x15\x1C\xE8\x1B%\x1C\xFB\x1B4\x1C3\x17y\x16>\x1CN\x1CN\x1CO\x1CN\x1CN\x1CN\x1CN\x1CN\x1CV\x1C3\x17_\x1C3\x173\x173\x173\x173\x17j\x1C3\x174\x17z\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xC6\x16\xC6\x16\xC6\x16\xC6\x16\x8A\x1C\x95\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x9B\x1C\xAB\x1C\xAB\x1C\xAB\x1C\xB4\x1C\xC4\x1C\xC4\x1C\xCC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xDC\x1C\xE0\x1C\xDC\x1C\xF0\x1CG\r\xDF\x03\xDF\x03\0\x1D\x10\x1D\xDF\x03 \x1D0\x1D0\x1D0\x1D0\x1D:\x1DJ\x1D\xBD\x02Z\x1Dj\x1D\xFD\0\xFF\0\xFF\0\xFF\0z\x1D\x88\x1D\x98\x1D\x9E\x1D\xA8\x1D\xB4\x1D\xC0\x1DG\r\xCF\x1D\xDF\x1D\xEB\x1D\xF8\x1D\x08\x1E\x18\x1E\x18\x1E\x18\x1E$\x1E4\x1E6\x1E6\x1EB\x1EN\x1E^\x1En\x1E|\x1E\x8C\x1E\x96\x1E\xA0\x1E\xB0\x1E\xB9\x1E\xC7\x1E\x97\x0F\xD7\x1E\xE7\x1E\xEB\x1E\xEB\x1E\xF8\x1E\x08\x1F\x18\x1F(\x1F8\x1FH\x1FH\x1FO\x1F^\x1Fn\x1F~\x1F\xE8\x0E\x8E\x1F\x9E\x1F\x9E\x1F\x9E\x1F\xAE\x1F\xBE\x1F\xC3\x1F\xD3\x1F\xE3\x1F\xF2\x1F\xFA\x1F\x83\x1Aa\0a\0\n \x1A * * * * * : : G W g k x o x s x g k x o x s x g k x o x s x g k x o x s x g k x o x s x g k x o x \x84 \xA7\x0F\x94 \xB7\x0F\xB7\x0F\x9F \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xAF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF z\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xCF z\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xD3 G\rG\r\xE3 \xF0 \xFF \t!\x19!\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05)!6!C!\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05F!V!\xA6\x05\xA6\x05\xA6\x05\xA6\x05D!\xA6\x05\xA6\x05f!v!v!\x86!\x96!\xA6!\xB6!\xC6!\xD2!\xE2!\xF2!\x02\"\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\xA6\x05\x08\"\x18\"(\"@\0P\0`\08\"H\"X\"\x8C\x1BZ\"\xB7\x1B\xB8\x1Bj\"r\"\x82\"\x92\"\xA2\"\xAF\"\xA7\"\xB4\"\xC3\"\xC3\"G\rG\r\xAF\"\xAF\"\xAF\"\xAF\"\xAF\"\xAF\"\xAF\"\xC6\"\xD6\"\xDD\"\xDD\"\xE9\"\xF9\"\xF9\"\xF9\"\x04#\r#\x1D#-#G\rG\r3\x173\x17=#G\rG\rG\rG\rG\rG\rG\rG\rM#P#`#`#`#o#\x7F#\x83#\x93#\x93#\xA3#\xB3#\xC2#\xD2#\xD2#\xDC#\xEC#\xEE#\xFE#\xFE#\n$\x1A$G\rG\r*$*$2$:$:$J$J$J$Z$\\$l$|$|$\x88$\x90$\x94$\xA4$\xA4$\xAC$\xBC$\xBC$\xBC$\xC8$\xD8$\xD8$\xE5$\xF3$\x01%\x11%\x11%\x11%\x1D%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%-%6%-%7%5%G\rG%h\x14h\x14L%G\rG\rG\rG\r\\%f%f%p%\x80%\x8A%\x9A%\xA3%\xB3%\xB4%\xC3%\xD3%\xD3%\xD3%\xE3%\xF0%\0&\n&\x1A& &0&6&\xD3%\xD3%F&F&V&^&n&~&n&n&\x8E&\x9E&\xA7&\xB1&\xC1&\xD1&\xE1&\xE4&\xF4&\xF7&\xD3%\xD3%\x07'\x10'\x1B'+';';';'E'U'_'o'|'\x8C'\x9A'\xA7'\xD3%\xD3%\xD3%\xD3%\xD3%\xB7'\xB7'\xB7'\xB7'\xBE'\xD3%\xD3%\xD3%\xCE'\xCE'\xCE'\xDB'\xEB'\xEB'\xEB'\xF8'\x08(\x08(\x14($(4(D(N(^(h(\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%x(y(\x89(\x89(\x8F(\x9F(\xAF(\xBF(\xC8(\xCE(\xDE(\xE1(\xEE(\xFE(\x08)\x17)\xC8(')5)\xD3%\xD3%E)P)\xD3%`)i)y)|)|)\x84)\x8D)\x9B)\xA5)\xB5)\xC5)\xC8)\xC8)\xD8)\xE6)\xF6)\xFD)\r*\x1D* *)*6*F*V*V*c*s*v*v*\x83*\x93*\xA3*\xB3*\xBE*\xCE*\xDC*\xE0*\xEF*\xFE*G\rG\rG\r\x0E+\x1D+,+<+<+=+M+]+m+y+\x80+\x8F+\x9F+\xAF+\xBD+\xC5+\xD5+\xE5+\xE5+\xEF+\xFE+\x0E,\x1D,G\r-,-,-,8,H,X,g,G\rw,w,w,\x87,\x96,\xA6,G\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xB6,\xB6,\xB7,\xC7,\xD6,\xDE,G\rG\r\xEE,\xEE,\xEE,\xFE,\r-\x1D---G\r=-=-B-R-b-r-~-G\r\x8E-\x93-\xA3-\xB3-\xC3-G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xD3-\xD3-\xD7-\xE6-G\rG\rG\rG\rG\rG\r\xF6-\xF6-\x06.\x06.\x16.#.3.?.G.W.g.w.G\rG\rG\rG\r\x87.\x91.\x91.\xA0.\xB0.G\r\xC0.\xCB.\xCB.\xD8.\xE7.\xF7.\x03/\x03/\x0F/\x19/'/K\x107/7/7/>/N/G\rG\rG\rG\rG\r^/G\rG\rG\rG\rG\rn/n/}/\x8D/\x9D/\xA7/\xA8/\xB8/\xC8/\xD8/\xE2/\xF2/\xF4/\x040\x0C0\x1B0G\rG\rG\rG\r+06060E0T0d0t0~0\x840\x940\xA40\xB40\xB40\xBB0\xCB0G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xDB0\xE80\xF80\x071\t1\x151%151G\rG\rG\rG\rG\rE1U1`1o1}1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x931G\rG\rG\rG\rG\rG\r\xA31\xA31\xA31\xA31\xA31\xA31\xA41\xB41\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\x8D1\xC41G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xD41\xD41\xD41\xD41\xD41\xD41\xE31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\x032\x132\x1D2\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31\xF31-2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2=2F2G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rV2X2f2v2G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D0\x1D\x862\x962\x972\xA72\xB72\xB72\xB72\xB72\xB82\xC82\xD82\xDA2\xEA2\xFA2\xFA2\xFA2\n3\x1A3*383@3\xFA2G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rP3S3`3p3G\rG\rG\rG\rG\rG\rG\rG\r\x803\x803\x903\x903\xA03\xA93\xB93\xC03\xCB3\xD73G\rG\r\xE73\xE73\xE73\xE73\xEC3\xFC3\xFD3\xFD3\x054\x144G\rG\rG\rG\r$444D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4D4T4T4T4T4T4T4T4T4T4T4T4T4T4T4T4T4^4G\rd4D4t4G\rG\rG\rG\rG\rG\rD4D4D4D4D4D4D4\x804G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\x904\xA04r\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1Br\x1B\xB04\xBE4G\r\xCE4\xDA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEA4\xEE4G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xFE4\xFE4\xFE4\xFE4\xFE4\xFE4\x035\x015\x055\x155%5G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16-\x173\x1755\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\x90\x17\xC6\x16A5\xC6\x16Q5\xCE\x02\xCE\x02\xE3\x12\xCE\x02\x16\x163\x173\x173\x173\x173\x173\x173\x17a5G\rG\rG\r3\x173\x173\x173\x173\x173\x173\x173\x173\x173\x173\x173\x173\x173\x173\x17q53\x173\x17\x8153\x173\x173\x17\x8C5\x995\xA653\x17\xB253\x173\x173\x17\xC05G\r\xD05\xD05\xD05\xD05\xDE5G\rG\rG\rG\rG\rG\rG\r\xDD\"\xEE5\xDD\"\xEE5\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xFE5\xDD\"\x0E6G\rG\rG\rG\rG\rG\rG\rG\r\x1E6$6.6:6 6J666\x1E6,6P6`6n6K6\x1E6$6.6{6\x886.6\x966\xA66\xB5626\x1E6(6.6\x1E6$6.6:6 6.666\x1E6,626\x1E6(6.6\x1E6$6.6\xBF6\x1E6\xCE6\xD36\xE16\xE66.6\xF26\x1E6\xCA6\x02786\x127.6\"7\x1E6\x187.6(7676767F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7F7V7V7V7_7V7V7Y7o7{7\x877\x917G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xA17\xAC7\xBB7G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xCB7\xD37\xE17\xF17\xF17\xF17\xF37G\r\x018G\rG\rG\rG\rG\rG\rG\r\x118\x118\x148$848G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rD8F8G\rV8V8Z8j8G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rz8\x7F8\x8F8G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\x9F8\xA18\xB18G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xC18\xC28\xD28\xE28G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xF28\xC8\x0F\x029\x029\x029\x029\x029\x029\x029\x029\x029\x029\x029\x029\r9\x1D9\xD3%\xD3%-9-9;9=9I9Y9\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%i9j9j9n9~9\xD3%\xD3%\xD3%\xD3%i9j9l9\x8E9\xC8(\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\x03\"\xA6\x05\x9D9\xAA9\xB89\xC89\xD69\xDE9\xEE9\xF99\x08:\xF99\xC8(\xC8(\xC8(\x18:\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%\xD3%`\x17\xC6\x16(:\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x160:@:?:O:?:\xC6\x16.:_:3\x174\x173\x173\x173\x17\xCE\x1Bo:\x7F:\x8E:\x99:\xA7:\xA7:\xA7:\xB1:\xB7:\xC7:\xD7:\xE2:\xEF:\xFF:\x0F;.:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\x1F;\x1F;-;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;9;\x1F;\x1F;\x1F;\x1F;\x1F;D;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;T;\x1F;\x1F;\x1F;!;d;\x1F;t;\x83;\x8E;\x9B;\xA7;\xB6;\xC4;\xD3;\xE2;\xE7;\x1F;\x1F;\x1F;\x1F;\x1F;\xC6\x16\xC6\x16\xC6\x16\x1F;\x1F;\x1F;\x1F;\xF1;\xFE;\n<\x1A<\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16*:*<5<(:\xC6\x16\xC6\x16\xC6\x16,:*:\xC6\x16\xC6\x16,:\xC6\x16E<(:Q<a<\xA7:\xA7:q<\x1F;\x1F;}<\x82<\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\x1F;\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16,:E<\x89<\x8B<\x1F;\x1F;\x1F;\x99<\xA6<\xB5<-<\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC5<\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xC6\x16\xD5<\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xA7:\xE5<z\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1CG\rG\rz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xCF z\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xF5<z\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xCF G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\xF7<z\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xD2 z\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1Cz\x1C\xD3 G\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\rG\r\x07=\t=\x19=\x19=\x19=\x19=\x19=\x19=\t=\t=\t=\t=\t=\t=\t=\t=\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\x96!\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\t=\xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF \xBF )=\x84\0\xA4\0\xC4\0\xE4\0\x04\x01$\x01D\x01d\x01\x84\x01\xA4\x01\xC4\x01\xDE\x01\xFE\x01\x1E\x02>\x02^\x02~\x02\x9E\x02\xBE\x02\xDE\x02\xFE\x02\x1E\x03>\x03^\x03~\x03\x9E\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xC2\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xE2\x03\xE4\x03\xFC\x03\x1C\x04<\x04\\\x04|\x04\x80\x04}\x04\x81\x04~\x04\x82\x04\x7F\x04|\x04\x80\x04}\x04\x81\x04~\x04\x82\x04\x7F\x04\x8A\x04\xAA\x04\xAA\x04\xAA\x04\xAA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xDA\x04\xF4\x04\x12\x052\x05R\x05r\x05\x92\x05\xB2\x05\xD2\x05\xF2\x05\x12\x06,\x06L\x06l\x06\x8C\x06\xAC\x06\xCC\x06\xEC\x06\x0C\x07+\x07K\x07R\x07r\x07\x87\x07\x87\x07\x87\x07\x87\x07\x8E\x07\xAE\x07\xAE\x07\xCB\x07\xAE\x07\xAE\x07\xAE\x07\xAE\x07\xAE\x07\xD1\x07\xF1\x07\r\x08\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x1D\x08\x87\x07\x87\x07\x87\x07=\x08Z\x08s\x08\x8F\x08\xAF\x08\xAF\x08\xAF\x08\xAF\x08\xAF\x08\xAF\x08\xAF\x08\xAF\x08\xBF\x08\xD2\x08\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\xF2\x08\x12\t)\t\x87\x07\x87\x07\x87\x07\x87\x07I\t\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07i\t~\t\x9E\t\xBE\t\xDE\t\xFE\t\x1E\n>\n\x87\x07N\nn\n\x85\n\x98\n\xB8\n\xD8\n\xEE\n\x07\x0B'\x0BG\x0Bg\x0B\x87\x0B\xA7\x0B\xC7\x0B\xE7\x0B\x07\x0C\x08\x0C\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03(\x0C\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03G\x0C\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03>\x0C\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03I\x0C\xBE\x03h\x0C\x87\x07\x87\x07\x87\x07\x87\x07\xBE\x03l\x0C\x87\x07n\x0C\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\x8E\x0C\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xBE\x03\xA7\x0C\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07n\x0C\xC7\x0C\xE6\x0C\xE6\x0C\xE6\x0C\xE6\x0C\xE6\x0C\xE6\x0C\xE6\x0C\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\x87\x07\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\xCA\x04\x06\r\xEE\xFF") } , unsafe { zerovec :: ZeroVec :: from_bytes_unchecked (b"\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/ \0\0\xAF\x1C\x04\0/ \x04\0/$\x04\0O\x1C\x04\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/\x1C\0\0/\x1C\0\0/\x1C\0\0/ \0\0\x0C$\0\0\x17(\0\0\x17(\0\0\x17\x90\0\0\x19\x10\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17\xA8\0\0\x18\x0C\0\0\x17\x18\0\0\x13\x0C\0\0\x17\x18\0\0\x17\x18\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\x17\x18\0\0\x17(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x17(\0\0\x17(\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\0\x14(\0\0\x17(\0\0\x15(\0\0\x1A(\0\0\x16(\0\0\x1A(\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x14(\0\0\x18(\0\0\x15(\0\0\x18(\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/\x1C\x04\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0\x0C\x18\0\0\x17(\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x1B(\0\0\x17(\0\0\x1A(\0\0\x1B\xA8\0\x19\x05\0\0\0\x1C(\0\0\x18(\0\x000H\0\0\x1B\xA8\0\0\x1A(\0\0\x1B\x10\0\0\x18\x10\0\0\x0B\x08\0\0\x0B\x08\0\0\x1A(\0\0\x02\0\0\0\x17(\0\0\x17(\0\0\x1A(\0\0\x0B\x08\0\x19\x05\0\0\0\x1D(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x17(\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\0\x18(\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x18(\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x05\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\0\x04(\0\0\x04(\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04\0\0\0\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\x05\x1A(\0\x05\x1A(\0\0\x04(\0\0\x1A(\0\0\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\0\x04(\0\x0E\x1A(\0\x0E\x01\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x04\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\0\x17(\0\x0E\x01\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0E\x1A(\0\0\x1A(\0\x0E\x01\0\0\0\x17(\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x18(\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x1B\0\0\x08fD\0\x08fD\0\x01fD\0\x01fD\0\x08fD\0\x08gD\0\x08gD\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0g\0\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0g\0\0\0g\0\0\0\x03\x04\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x17\0\0\x03\x13(\0g\0\0\0g\0\0\0\x03\x1B(\0\x03\x1B(\0\x03\x19\x10\0g\0\x04\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13fD\0\x13\x13\x04\0\x13fD\0\x13\x17\x04\0\x13fD\0\x13fD\0\x13\x17\x04\0\x13fD\0\x13fD\0\x13\x17\x04\0\x13fD\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x17\x04\0\x13\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x02p\x15\0\x02p\x15\0\x02p\x15\0\x02p\x15\0\x02p\x15\0\0p\x15\0\x02\x18(\0\x02\x18(\0\x02\x184\0\x02\x17\x10\0\x02\x17\x10\0\x02\x194\0\0\x17\x18\0\x02\x174\0\x02\x1B(\0\x02\x1B(\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\0\x174\0\x0204\0\x02\x174\0\x02\x174\0\0\x174\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\0\x044\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\x17\x10\0\x02\x17\x14\0\x02\x17\x14\0\x02\x174\0\x02\x054\0\x02\x054\0\x01fD\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x174\0\x02\x054\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\0p\x15\0\x02\x1B(\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02\x044\0\x02\x044\0\x02fD\0\x02fD\0\x02\x1B(\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02\x054\0\x02\x054\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x1B4\0\x02\x1B4\0\x02\x054\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0g\x004\0\"p5\0\"\x054\0\"fD\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0\"fD\0g\x004\0g\x004\0\"\x054\0\"\x054\0\"\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%fD\0%fD\0%fD\0%fD\0%fD\0%fD\0%fD\0%fD\0%fD\0%fD\0%fD\0%\x054\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0W\x05\x04\0WfD\0WfD\0WfD\0WfD\0WfD\0WfD\0WfD\0WfD\0WfD\0W\x04\x04\0W\x04\x04\0W\x1B(\0W\x17(\0W\x17(\0W\x17(\0W\x04\x04\0g\0\x04\0g\0\x04\0WfD\0W\x19\x04\0W\x19\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~fD\0~fD\0~fD\0~fD\0~\x04\x04\0~fD\0~fD\0~fD\0~fD\0~fD\0~fD\0~fD\0~fD\0~fD\0~\x04\x04\0~fD\0~fD\0~fD\0~\x04\x04\0~fD\0~fD\0~fD\0~fD\0~fD\0g\0\x04\0g\0\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0g\0\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0T\x05\x04\0TfD\0TfD\0TfD\0g\0\x04\0g\0\x04\0T\x17\x04\0g\0\x04\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0\"\x054\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x1A4\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02p\x15\0\x02p\x15\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x044\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\0p\x15\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\nfD\0\nfD\0\nfD\0\nH\x01\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\nfD\0\nH\x01\0\nfD\0\n\x05\0\0\nH\x01\0\nH\x01\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nH\x01\0\nH\x01\0\nH\x01\0\nH\x01\0\nfD\0\nH\x01\0\nH\x01\0\n\x05\0\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\nfD\0\nfD\0\nfD\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\nfD\0\nfD\0\0\x17\0\0\0\x17\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\x17\0\0\n\x04\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\x04\x05\0\0\x04fD\0\x04H\x01\0\x04H\x01\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0\x04\x05\0\0g\0\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0g\0\0\0\x04fD\0\x04\x05\0\0\x04h\0\0\x04H\x01\0\x04fD\0\x04fD\0\x04fD\0\x04fD\0g\0\0\0g\0\0\0\x04H\x01\0\x04H\x01\0g\0\0\0g\0\0\0\x04H\x01\0\x04H\x01\0\x04fD\0\x04\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x04h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04fD\0\x04fD\0g\0\0\0g\0\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\x05\0\0\x04\x05\0\0\x04\x19\x10\0\x04\x19\x10\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x1B\0\0\x04\x19\x10\0\x04\x05\0\0\x04\x17\0\0\x04fD\0g\0\0\0\x10fD\0\x10fD\0\x10H\x01\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0g\0\0\0\x10fD\0g\0\0\0\x10H\x01\0\x10H\x01\0\x10fD\0\x10fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10fD\0\x10fD\0g\0\0\0g\0\0\0\x10fD\0\x10fD\0\x10fD\0g\0\0\0g\0\0\0g\0\0\0\x10fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10fD\0\x10fD\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10fD\0\x10\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0FfD\0\x0FfD\0\x0FH\x01\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0g\0\0\0\x0FfD\0\x0F\x05\0\0\x0FH\x01\0\x0FH\x01\0\x0FfD\0\x0FfD\0\x0FfD\0\x0FfD\0\x0FfD\0g\0\0\0\x0FfD\0\x0FfD\0\x0FH\x01\0g\0\0\0\x0FH\x01\0\x0FH\x01\0\x0FfD\0g\0\0\0g\0\0\0\x0F\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0FfD\0\x0FfD\0g\0\0\0g\0\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\x17\0\0\x0F\x19\x10\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0F\x05\0\0\x0FfD\0\x0FfD\0\x0FfD\0\x0FfD\0\x0FfD\0\x0FfD\0g\0\0\0\x1FfD\0\x1FH\x01\0\x1FH\x01\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0g\0\0\0\x1FfD\0\x1F\x05\0\0\x1Fh\0\0\x1FfD\0\x1FH\x01\0\x1FfD\0\x1FfD\0\x1FfD\0\x1FfD\0g\0\0\0g\0\0\0\x1FH\x01\0\x1FH\x01\0g\0\0\0g\0\0\0\x1FH\x01\0\x1FH\x01\0\x1FfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1FfD\0\x1FfD\0\x1Fh\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1FfD\0\x1FfD\0g\0\0\0g\0\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\x1B\0\0\x1F\x05\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#fD\0#\x05\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0g\0\0\0#\x05\0\0g\0\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#h\0\0#H\x01\0#fD\0#H\x01\0#H\x01\0g\0\0\0g\0\0\0g\0\0\0#H\x01\0#H\x01\0#H\x01\0g\0\0\0#H\x01\0#H\x01\0#H\x01\0#fD\0g\0\0\0g\0\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x19\x10\0#\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0$fD\0$H\x01\0$H\x01\0$H\x01\0$fD\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0g\0\0\0$fD\0$\x05\0\0$fD\0$fD\0$H\x01\0$H\x01\0$H\x01\0$H\x01\0g\0\0\0$fD\0$fD\0$fD\0g\0\0\0$fD\0$fD\0$fD\0$fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0$fD\0$fD\0g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0g\0\0\0g\0\0\0$\x05\0\0$\x05\0\0$fD\0$fD\0g\0\0\0g\0\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0$\x17\0\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x1B\0\0\x15\x05\0\0\x15fD\0\x15H\x01\0\x15H\x01\0\x15\x17\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0g\0\0\0\x15fD\0\x15\x05\0\0\x15H\x01\0\x15f\0\0\x15h\0\0\x15H\x01\0\x15h\0\0\x15H\x01\0\x15H\x01\0g\0\0\0\x15f\0\0\x15h\0\0\x15h\0\0g\0\0\0\x15h\0\0\x15h\0\0\x15fD\0\x15fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x15h\0\0\x15h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15fD\0\x15fD\0g\0\0\0g\0\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1AfD\0\x1AfD\0\x1AH\x01\0\x1AH\x01\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0g\0\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0g\0\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1AfD\0\x1AfD\0\x1A\x05\0\0\x1Ah\0\0\x1AH\x01\0\x1AfD\0\x1AfD\0\x1AfD\0\x1AfD\0g\0\0\0\x1AH\x01\0\x1AH\x01\0\x1AH\x01\0g\0\0\0\x1AH\x01\0\x1AH\x01\0\x1AH\x01\0\x1AfD\0\x1Ae\x01\0\x1A\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1Ah\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1AfD\0\x1AfD\0g\0\0\0g\0\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x1B\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0g\0\0\0!fD\0!H\x01\0!H\x01\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0g\0\0\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0!\x05\0\0g\0\0\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0g\0\0\0g\0\0\0!fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0!h\0\0!H\x01\0!H\x01\0!fD\0!fD\0!fD\0g\0\0\0!fD\0g\0\0\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0g\0\0\0g\0\0\0!H\x01\0!H\x01\0!\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&fD\0&\x05\0\0&E\x01\0&fD\0&fD\0&fD\0&fD\0&fD\0&fD\0&fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x19\x10\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x04\0\0&fD\0&fD\0&fD\0&fD\0&fD\0&fD\0&fD\0&fD\0&\x17\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\x17\0\0&\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18fD\0\x18\x05\0\0\x18E\x01\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18\x05\0\0g\0\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x04\0\0g\0\0\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0\x18fD\0g\0\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0g\0\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0'\x05\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x1B\0\0'\x17\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'fD\0'fD\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x1B\0\0'fD\0'\x1B\0\0'fD\0'\x1B\0\0'fD\0'\x14(\0'\x15(\0'\x14(\0'\x15(\0'H\x01\0'H\x01\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0g\0\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'H\x01\0'fD\0'fD\0'fD\0'fD\0'fD\0'\x17\0\0'fD\0'fD\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0g\0\0\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0'fD\0g\0\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'fD\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0g\0\0\0'\x1B\0\0'\x1B\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0'\x17\0\0'\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x08\0\0\x1C\x08\0\0\x1CfD\0\x1CfD\0\x1CfD\0\x1CH\x01\0\x1CfD\0\x1CfD\0\x1CfD\0\x1CfD\0\x1CfD\0\x1CfD\0\x1C\x08\0\0\x1CfD\0\x1CfD\0\x1CH\x01\0\x1CH\x01\0\x1CfD\0\x1CfD\0\x1C\x05\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CH\x01\0\x1CH\x01\0\x1CfD\0\x1CfD\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD\0\x1CfD\0\x1C\x05\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD\0\x1CfD\0\x1CfD\0\x1CfD\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD\0\x1C\x08\0\0\x1CH\x01\0\x1CfD\0\x1CfD\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1CfD\0\x1C\x05\0\0\x1C\x08\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1CfD\0\x1C\x1B\0\0\x1C\x1B\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0g\0\0\0\x0C\x01\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0C\x01\0\0g\0\0\0g\0\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\0\x17\0\0\x0C\x04\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0BfD\0\x0BfD\0\x0BfD\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0g\0\0\0g\0\0\0g\0\0\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0g\0\0\0g\0\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0g\0\0\0g\0\0\0(\x13(\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x1B\0\0(\x17\0\0(\x05\0\0\x1D\x0C$\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x14(\0\x1D\x15(\0g\0\0\0g\0\0\0g\0\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0\0\x17\0\0\0\x17\0\0\0\x17\0\0 \n\0\0 \n\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*fD\0*fD\0*fD\0*h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0*\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+fD\0+fD\0+h\0\0\0\x17\0\0\0\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,fD\0,fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0g\0\0\0-\x05\0\0-\x05\0\0g\0\0\0-fD\0-fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17fD\0\x17fD\0\x17H\x01\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17fD\0\x17H\x01\0\x17H\x01\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17fD\0\x17\x17\0\0\x17\x17\0\0\x17\x17\0\0\x17\x04\0\0\x17\x17\0\0\x17\x17\0\0\x17\x17\0\0\x17\x19\x10\0\x17\x05\0\0\x17fD\0g\0\0\0g\0\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x17(\0\x1B\x17(\0\0\x17(\0\0\x17(\0\x1B\x17(\0\0\x17(\0\x1B\x13(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1BfD\x01\x1BfD\x01\x1BfD\x01\x1B0H\0\x1BfD\x01\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x04\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1BfD\0\x1BfD\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1BfD\0\x1B\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\0g\0\0\x000fD\x000fD\x000fD\x000H\x01\x000H\x01\x000H\x01\x000H\x01\x000fD\x000fD\x000H\x01\x000H\x01\x000H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\x000H\x01\x000H\x01\x000fD\x000H\x01\x000H\x01\x000H\x01\x000H\x01\x000H\x01\x000H\x01\x000fD\x000fD\x000fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\x000\x1B(\0g\0\0\0g\0\0\0g\0\0\x000\x17(\x000\x17(\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\x0B\0\0g\0\0\0g\0\0\0g\0\0\0;\x1B(\0;\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007fD\x007fD\x007H\x01\x007H\x01\x007fD\0g\0\0\0g\0\0\x007\x17\0\x007\x17\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0jH\x01\0jfD\0jH\x01\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0g\0\0\0jfD\0j\x08\0\0jfD\0j\x08\0\0j\x08\0\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jH\x01\0jH\x01\0jH\x01\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0jfD\0g\0\0\0g\0\0\0jfD\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x04\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0g\0\0\0g\0\0\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01gD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0>fD\0>fD\0>fD\0>fD\0>H\x01\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>fD\0>h\0\0>fD\0>fD\0>fD\0>fD\0>fD\0>h\0\0>fD\0>h\0\0>H\x01\0>H\x01\0>fD\0>h\0\0>h\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0g\0\0\0>\x17\0\0>\x17\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>fD\0>fD\0>fD\0>fD\0>fD\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x17\0\0>\x17\0\0>\x17\0\0qfD\0qfD\0qH\x01\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0qH\x01\0qfD\0qfD\0qfD\0qfD\0qH\x01\0qH\x01\0qfD\0qfD\0qh\0\0qfD\0qfD\0qfD\0q\x05\0\0q\x05\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?fD\0?H\x01\0?fD\0?fD\0?H\x01\0?H\x01\0?H\x01\0?fD\0?H\x01\0?fD\0?fD\0?h\0\0?h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0?\x17\0\0?\x17\0\0?\x17\0\0?\x17\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RfD\0RfD\0RfD\0RfD\0RH\x01\0RH\x01\0RfD\0RfD\0g\0\0\0g\0\0\0g\0\0\0R\x17\0\0R\x17\0\0R\x17\0\0R\x17\0\0R\x17\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0g\0\0\0g\0\0\0g\0\0\0R\x05\0\0R\x05\0\0R\x05\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x17\0\0m\x17\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0g\0\0\0g\0\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x01fD\0\x01fD\0\x01fD\0\0\x17\0\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\0H\x01\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\0\x05\0\0\0\x05\0\0\0\x05\0\0\0\x05\0\0\x01fD\0\0\x05\0\0\0\x05\0\0\0H\x01\0\x01fD\0\x01fD\0\0\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x08\x02\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x08\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x03\0\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x03\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x1A(\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x03\0\0\x0E\x1A(\0\x0E\x1A(\0g\0\0\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\x000H\0\x01pH\0\x010J\0\x000\0\0\x000\x04\0\0\x13(\0\0\x13(\0\0\x13(\0\0\x13(\0\0\x13(\0\0\x13(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x14(\0\0\x1C(\0\0\x1C(\0\0\x1D(\0\0\x14(\0\0\x1C(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0-$\x04\0.\x1C\x04\x000,\0\x0008\0\x000@\0\x0000\0\x000<\0\0\x0C\x18\0\0\x17\x10\0\0\x17\x10\0\0\x17\x10\0\0\x17\x10\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17\xA8\0\0\x17(\0\0\x17(\0\0\x16(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x18\x18\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x17\xA8\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x18(\0\0\x17(\0\0\x16(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x0C$\0\x000H\0\x000H\0\x000H\0\x000H\0\x000H\0g H\0\x000P\0\x000T\0\x000L\0\x000X\0\x000H\0\x000H\0\x000H\0\x000H\0\x000H\0\x000H\0\0\x0B\x08\0\x19\x04\0\0g\0\0\0g\0\0\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x18\x0C\0\0\x18\x0C\0\0\x18(\0\0\x14(\0\0\x15(\0\x19\x04\0\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x18\x0C\0\0\x18\x0C\0\0\x18(\0\0\x14(\0\0\x15(\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0g\0\0\0g\0\0\0g\0\0\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01gD\0\x01gD\0\x01gD\0\x01fD\0\x01gD\0\x01gD\0\x01gD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\x0E\x01\0\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\x19\x01\0\0\x19\x01\0\0\0\x01\0\0\0\x01\0\0\0\x1B\x10\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\x19\x01\0\0\0\x01\0\0\0\x02\0\0\0\x05\0\0\0\x05\0\0\0\x05\0\0\0\x05\0\0\0\x02\x80\0\0\x1B(\0\0\x1B(\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\x19\x02\0\0\0\x1B\0\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\x01\0\0\x19\x02\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\0\x0B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\x0C\0\0\x18\x10\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x14(\0\0\x15(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\xA8\0\0\x18\xA8\0\0\x18\xA8\0\0\x18\xA8\0\0\x18(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\xA8\0\0\x18\xA8\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x04\0\0\x19\x04\0\0\x19\x01\0\0\x19\x01\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x02\0\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07fD\0\x07fD\0\x07\x01\0\0\x07\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x07\x17(\0\x07\x17(\0\x07\x17(\0\x07\x17(\0\x07\x0B(\0\x07\x17(\0\x07\x17(\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0g\0\0\0\x0C\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0C\x02\0\0g\0\0\0g\0\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0<\x04\0\0<\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0<fD\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x04(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x13(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x17(\0\0\x14(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1B(\0\0\x1B(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x13(\0g\0\0\0g\0\0\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0g\0\0\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0C$\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1B(\0\x11\x04\0\0\0\x05\0\0\x11\n\0\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x1B(\0\0\x1B(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x13(\0\0\x14(\0\0\x15(\0\0\x15(\0\0\x1B(\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x12h\0\0\x12h\0\0\0\x13\xA8\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x1B(\0\0\x1B(\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\x04\0\0\0\x05\0\0\0\x17\xA8\0\0\x1B(\0\0\x1B(\0g\0\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0g\0\0\0g\0\0\0\x01fD\0\x01fD\0\0\x1A(\0\0\x1A(\0\x14\x04\0\0\x14\x04\0\0\x14\x05\0\0\0\x13(\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\0\x17(\0\0\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B(\0\x12\x1B(\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B(\0\x12\x1B(\0\x12\x1B(\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x04\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0g\0\0\0g\0\0\0g\0\0\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x17\0\0\x83\x17\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x04\0\0c\x17(\0c\x17(\0c\x17(\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\x05\0\0c\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x05\0\0\x08fD\0\x08gD\0\x08gD\0\x08gD\0\x08\x17(\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08fD\0\x08\x17(\0\x08\x04(\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x04\0\0\x08\x04\0\0\x08fD\0\x08fD\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82fD\0\x82fD\0\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x1A(\0\0\x1A(\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\0\x04(\0\0\x1A\0\0\0\x1A\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x05\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x01\0\0\x19\x02\0\0\x19\x05\0\0\x19\x04\0\0\x19\x04\0\0\x19\x02\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0:\x05\0\0:\x05\0\0:fD\0:\x05\0\0:\x05\0\0:\x05\0\0:fD\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:fD\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:H\x01\0:H\x01\0:fD\0:fD\0:H\x01\0:\x1B(\0:\x1B(\0:\x1B(\0:\x1B(\0:fD\0g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B\0\0\0\x1B\0\0\0\x19\x10\0\0\x1B\x10\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x05\0\0Z\x17(\0Z\x17(\0Z\x17(\0Z\x17(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0oH\x01\0oH\x01\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0ofD\0ofD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0o\x17\0\0o\x17\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\nfD\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x05\0\0\n\x17\0\0\n\x05\0\0\n\x05\0\0\nfD\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0OfD\0OfD\0OfD\0OfD\0OfD\0OfD\0OfD\0OfD\0\0\x17\0\0O\x17\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0nfD\0nfD\0nfD\0nfD\0nfD\0nfD\0nfD\0nfD\0nfD\0nH\x01\0nh\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0n\x17\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0g\0\0\0g\0\0\0g\0\0\0NfD\0NfD\0NfD\0NH\x01\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0NfD\0NH\x01\0NH\x01\0NfD\0NfD\0NfD\0NfD\0NH\x01\0NH\x01\0NfD\0NfD\0NH\x01\0NH\x01\0Nh\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0g\0\0\0\0\x04\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0N\x17\0\0N\x17\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD\0\x1C\x04\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0g\0\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0BfD\0BfD\0BfD\0BfD\0BfD\0BfD\0BH\x01\0BfD\0BfD\0BH\x01\0BH\x01\0BfD\0BfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0B\x05\0\0B\x05\0\0B\x05\0\0BfD\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0BfD\0BH\x01\0g\0\0\0g\0\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0g\0\0\0g\0\0\0B\x17\0\0B\x17\0\0B\x17\0\0B\x17\0\0\x1C\x04\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x1B\0\0\x1C\x1B\0\0\x1C\x1B\0\0\x1C\x05\0\0\x1C\x08\0\0\x1CfD\0\x1C\x08\0\0\x1C\x05\0\0\x1C\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7FfD\0\x7F\x05\0\0\x7FfD\0\x7FfD\0\x7FfD\0\x7F\x05\0\0\x7F\x05\0\0\x7FfD\0\x7FfD\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7FfD\0\x7FfD\0\x7F\x05\0\0\x7FfD\0\x7F\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x04\0\0\x7F\x17\0\0\x7F\x17\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0sH\x01\0sfD\0sfD\0sH\x01\0sH\x01\0s\x17\0\0s\x17\0\0s\x05\0\0s\x04\0\0s\x04\0\0sH\x01\0sfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x1A\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x0E\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x04\0\0\0\x1A(\0\0\x1A(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0sH\x01\0sH\x01\0sfD\0sH\x01\0sH\x01\0sfD\0sH\x01\0sH\x01\0s\x17\0\0sH\x01\0sfD\0g\0\0\0g\0\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x12\xC5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xC5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x13\x05\x04\0\x13fD\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x18\x0C\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x02\x054\0\x02\x054\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\0\x15(\0\0\x14(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x194\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\x01fD\x01\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x08fD\0\x08fD\0\0\x17(\0\0\x13(\0\0\x13(\0\0\x16(\0\0\x16(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x16(\0\0\x16(\0\0\x16(\0\0\x17\x18\0\0\x17(\0\0\x17\x18\0g\0\0\0\0\x17(\0\0\x17\x18\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x18\x0C\0\0\x13\x0C\0\0\x18(\0\0\x18(\0\0\x18(\0g\0\0\0\0\x17(\0\0\x19\x10\0\0\x17\x10\0\0\x17(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0g\x004\0\x000H\0g\0\0\0\0\x17(\0\0\x17(\0\0\x17\x10\0\0\x19\x10\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x18\x0C\0\0\x17\x18\0\0\x13\x0C\0\0\x17\x18\0\0\x17\x18\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\x17\x18\0\0\x17(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x17(\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x14(\0\0\x18(\0\0\x15(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\0\x04\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\0d\0\0\0d\0\0g\0\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0g\0\0\0g\0\0\0\0\x19\x10\0\0\x19\x10\0\0\x18(\0\0\x1A(\0\0\x1B(\0\0\x19\x10\0\0\x19\x10\0g\0\0\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0g\0\0\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0\x000(\0\x000(\0\x000(\0\0\x1B(\0\0\x1B(\0g\0H\0g\0H\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\0g\0\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\0g\0\0\x001\x05\0\x001\x05\0\0g\0\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x17\0\0\0\x17(\0\0\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x1B(\0\x0E\x1B\0\0\x0E\x1B\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0\x0E\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\x01fD\0g\0\0\0g\0\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0g\0\0\0g\0\0\0g\0\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x01fD\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x0B\0\0\x1E\x0B\0\0\x1E\x0B\0\0\x1E\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\n\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\n\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0YfD\0YfD\0YfD\0YfD\0YfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\0g\0\0\x005\x17\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x17\0\0=\n\0\0=\n\0\0=\n\0\0=\n\0\0=\n\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\0g\0\0\0g\0\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9F\x17\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0g\0\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0g\0\0\0\xC5\x01\0\0\xC5\x01\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0g\0\0\0g\0\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0g\0\x04\0g\0\x04\0/\x05\x04\0g\0\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0g\0\x04\0/\x05\x04\0/\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0/\x05\x04\0g\0\x04\0g\0\x04\0/\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0g\0\x04\0t\x17\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x1B\x04\0\x90\x1B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0g\0\x04\0\xA2\x05\x04\0\xA2\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0g\0\x04\0g\0\x04\0g\0\x04\0[\x17(\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0l\x17\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0g\0\x04\0g\0\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\09\x05\x04\09fD\09fD\09fD\0g\0\x04\09fD\09fD\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\09fD\09fD\09fD\09fD\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\0g\0\x04\09\x05\x04\09\x05\x04\09\x05\x04\0g\0\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\0g\0\x04\0g\0\x04\09fD\09fD\09fD\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\09fD\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x0B\x04\0\x85\x0B\x04\0\x85\x17\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x0B\x04\0\x8E\x0B\x04\0\x8E\x0B\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x1B\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0y\x05\x04\0yfD\0yfD\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0y\x0B\x04\0y\x0B\x04\0y\x0B\x04\0y\x0B\x04\0y\x0B\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0g\0\x04\0g\0\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0{\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0{\x17\x04\0{\x17\x04\0{\x17\x04\0{\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0{\x0B\x04\0{\x0B\x04\0{\x0B\x04\0{\x0B\x04\0{\x0B\x04\0{\x0B\x04\0{\x0B\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6\x054\0\xB6fD\0\xB6fD\0\xB6fD\0\xB6fD\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\x05\x04\0\xC9\x05\x04\0\xC9\x05\x04\0\xC9\x05\x04\0\xC9\x04\x04\0\xC9\x05\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xC9fD\0\xC9fD\0\xC9fD\0\xC9fD\0\xC9fD\0\xC9\x13(\0\xC9\x04\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xC9\x18\x04\0\xC9\x18\x04\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0g\0\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0g\0\x04\0\xC0fD\0\xC0fD\0\xC0\x13\x04\0g\0\x04\0g\0\x04\0\xC0\x05\x04\0\xC0\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x044\0\x02\x054\0\x02\x054\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x17(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\x02fD\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7\x054\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7fD\0\xB7\x0B4\0\xB7\x0B4\0\xB7\x0B4\0\xB7\x0B4\0\xB7\x174\0\xB7\x174\0\xB7\x174\0\xB7\x174\0\xB7\x174\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2\x05\x04\0\xC2fD\0\xC2fD\0\xC2fD\0\xC2fD\0\xC2\x17\x04\0\xC2\x17\x04\0\xC2\x17\x04\0\xC2\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x05\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0AH\x01\0AfD\0AH\x01\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0AfD\0AfD\0AfD\0AfD\0AfD\0AfD\0AfD\0AfD\0A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0g\0\0\0g\0\0\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0AfD\0A\x05\0\0A\x05\0\0AfD\0AfD\0A\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0AfD\0xfD\0xfD\0xH\x01\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0xH\x01\0xH\x01\0xH\x01\0xfD\0xfD\0xfD\0xfD\0xH\x01\0xH\x01\0xfD\0xfD\0x\x17\0\0x\x17\0\0xp\x01\0x\x17\0\0x\x17\0\0xfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0xp\x01\0g\0\0\0g\0\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0vfD\0vfD\0vfD\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0vfD\0vfD\0vfD\0vfD\0vfD\0vH\x01\0vfD\0vfD\0vfD\0vfD\0vfD\0g\0\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\x17\0\0v\x17\0\0v\x17\0\0v\x17\0\0v\x05\0\0vH\x01\0vH\x01\0v\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0fD\0\xA0\x17\0\0\xA0\x17\0\0\xA0\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x97fD\0\x97fD\0\x97H\x01\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97H\x01\0\x97H\x01\0\x97H\x01\0\x97fD\0\x97fD\0\x97fD\0\x97fD\0\x97fD\0\x97fD\0\x97fD\0\x97fD\0\x97fD\0\x97H\x01\0\x97h\0\0\x97\x05\0\0\x97e\x01\0\x97e\x01\0\x97\x05\0\0\x97\x17\0\0\x97\x17\0\0\x97\x17\0\0\x97\x17\0\0\x97fD\0\x97fD\0\x97fD\0\x97fD\0\x97\x17\0\0\x97H\x01\0\x97fD\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\x05\0\0\x97\x17\0\0\x97\x05\0\0\x97\x17\0\0\x97\x17\0\0\x97\x17\0\0g\0\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0g\0\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9DH\x01\0\x9DH\x01\0\x9DH\x01\0\x9DfD\0\x9DfD\0\x9DH\x01\0\x9DH\x01\0\x9DfD\0\x9Dh\0\0\x9DfD\0\x9DfD\0\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9DfD\0\x9D\x05\0\0\x9DfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91fD\0\x91H\x01\0\x91H\x01\0\x91H\x01\0\x91fD\0\x91fD\0\x91fD\0\x91fD\0\x91fD\0\x91fD\0\x91fD\0\x91fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x89fD\0\x89fD\0\x89H\x01\0\x89H\x01\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x01fD\0\x89fD\0\x89\x05\0\0\x89h\0\0\x89H\x01\0\x89fD\0\x89H\x01\0\x89H\x01\0\x89H\x01\0\x89H\x01\0g\0\0\0g\0\0\0\x89H\x01\0\x89H\x01\0g\0\0\0g\0\0\0\x89H\x01\0\x89H\x01\0\x89h\0\0g\0\0\0g\0\0\0\x89\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x89h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89H\x01\0\x89H\x01\0g\0\0\0g\0\0\0\x89fD\0\x89fD\0\x89fD\0\x89fD\0\x89fD\0\x89fD\0\x89fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0g\0\0\0\xCF\x05\0\0g\0\0\0g\0\0\0\xCF\x05\0\0g\0\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0g\0\0\0\xCF\x05\0\0\xCFh\0\0\xCFH\x01\0\xCFH\x01\0\xCFfD\0\xCFfD\0\xCFfD\0\xCFfD\0\xCFfD\0g\0\0\0\xCFh\0\0g\0\0\0g\0\0\0\xCFh\0\0g\0\0\0\xCFh\0\0\xCFh\0\0\xCFh\0\0\xCFH\x01\0g\0\0\0\xCFH\x01\0\xCFH\x01\0\xCFfD\0\xCFh\0\0\xCFfD\0\xCFe\x01\0\xCFfD\0\xCF\x05\0\0\xCF\x17\0\0\xCF\x17\0\0g\0\0\0\xCF\x17\0\0\xCF\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCFfD\0\xCFfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAAH\x01\0\xAAH\x01\0\xAAH\x01\0\xAAfD\0\xAAfD\0\xAAfD\0\xAAfD\0\xAAfD\0\xAAfD\0\xAAfD\0\xAAfD\0\xAAH\x01\0\xAAH\x01\0\xAAfD\0\xAAfD\0\xAAfD\0\xAAH\x01\0\xAAfD\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\x17\0\0\xAA\x17\0\0g\0\0\0\xAA\x17\0\0\xAAfD\0\xAA\x05\0\0\xAA\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9Eh\0\0\x9EH\x01\0\x9EH\x01\0\x9EfD\0\x9EfD\0\x9EfD\0\x9EfD\0\x9EfD\0\x9EfD\0\x9EH\x01\0\x9EfD\0\x9EH\x01\0\x9EH\x01\0\x9Eh\0\0\x9EH\x01\0\x9EfD\0\x9EH\x01\0\x9EfD\0\x9EfD\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x17\0\0\x9E\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6h\0\0\xA6H\x01\0\xA6H\x01\0\xA6fD\0\xA6fD\0\xA6fD\0\xA6fD\0g\0\0\0g\0\0\0\xA6H\x01\0\xA6H\x01\0\xA6H\x01\0\xA6H\x01\0\xA6fD\0\xA6fD\0\xA6H\x01\0\xA6fD\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6fD\0\xA6fD\0g\0\0\0g\0\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3H\x01\0\xA3H\x01\0\xA3H\x01\0\xA3fD\0\xA3fD\0\xA3fD\0\xA3fD\0\xA3fD\0\xA3fD\0\xA3fD\0\xA3fD\0\xA3H\x01\0\xA3H\x01\0\xA3fD\0\xA3H\x01\0\xA3fD\0\xA3\x17\0\0\xA3\x17\0\0\xA3\x17\0\0\xA3\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0g\0\0\0g\0\0\0g\0\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99fD\0\x99H\x01\0\x99fD\0\x99H\x01\0\x99H\x01\0\x99fD\0\x99fD\0\x99fD\0\x99fD\0\x99fD\0\x99fD\0\x99h\0\0\x99fD\0\x99\x05\0\0\x99\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0g\0\0\0g\0\0\0\xA1fD\0\xA1H\x01\0\xA1fD\0\xA1\x08\0\0\xA1\x08\0\0\xA1fD\0\xA1fD\0\xA1fD\0\xA1fD\0\xA1H\x01\0\xA1fD\0\xA1fD\0\xA1fD\0\xA1fD\0\xA1fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\x0B\0\0\xA1\x0B\0\0\xA1\x17\0\0\xA1\x17\0\0\xA1\x17\0\0\xA1\x1B\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2H\x01\0\xB2H\x01\0\xB2H\x01\0\xB2fD\0\xB2fD\0\xB2fD\0\xB2fD\0\xB2fD\0\xB2fD\0\xB2fD\0\xB2fD\0\xB2H\x01\0\xB2fD\0\xB2fD\0\xB2\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x92\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0g\0\0\0g\0\0\0\xBE\x05\0\0g\0\0\0g\0\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0g\0\0\0\xBE\x05\0\0\xBE\x05\0\0g\0\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBEh\0\0\xBEH\x01\0\xBEH\x01\0\xBEH\x01\0\xBEH\x01\0\xBEH\x01\0g\0\0\0\xBEH\x01\0\xBEH\x01\0g\0\0\0g\0\0\0\xBEfD\0\xBEfD\0\xBEh\0\0\xBEfD\0\xBEe\x01\0\xBEH\x01\0\xBEe\x01\0\xBEH\x01\0\xBEfD\0\xBE\x17\0\0\xBE\x17\0\0\xBE\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0g\0\0\0g\0\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBBH\x01\0\xBBH\x01\0\xBBH\x01\0\xBBfD\0\xBBfD\0\xBBfD\0\xBBfD\0g\0\0\0g\0\0\0\xBBfD\0\xBBfD\0\xBBH\x01\0\xBBH\x01\0\xBBH\x01\0\xBBH\x01\0\xBBfD\0\xBB\x05\0\0\xBB\x17\0\0\xBB\x05\0\0\xBBH\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB1\x05\0\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1f\0\0\xB1f\0\0\xB1fD\0\xB1fD\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1H\x01\0\xB1\x05\0\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1fD\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB0\x05\0\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0H\x01\0\xB0H\x01\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0fD\0\xB0H\x01\0\xB0fD\0\xB0fD\0\xB0\x17\0\0\xB0\x17\0\0\xB0\x17\0\0\xB0\x05\0\0\xB0\x17\0\0\xB0\x17\0\0\xB0\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x97fD\0\x97H\x01\0\x97fD\0\x97fD\0\x97fD\0\x97H\x01\0\x97fD\0\x97H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0g\0\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8H\x01\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8fD\0g\0\0\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8fD\0\xA8H\x01\0\xA8f\0\0\xA8\x05\0\0\xA8\x17\0\0\xA8\x17\0\0\xA8\x17\0\0\xA8\x17\0\0\xA8\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0g\0\0\0g\0\0\0g\0\0\0\xA9\x17\0\0\xA9\x17\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0g\0\0\0g\0\0\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0g\0\0\0\xA9H\x01\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9fD\0\xA9H\x01\0\xA9fD\0\xA9fD\0\xA9H\x01\0\xA9fD\0\xA9fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0g\0\0\0\xAF\x05\0\0\xAF\x05\0\0g\0\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAFfD\0\xAFfD\0\xAFfD\0\xAFfD\0\xAFfD\0\xAFfD\0g\0\0\0g\0\0\0g\0\0\0\xAFfD\0g\0\0\0\xAFfD\0\xAFfD\0g\0\0\0\xAFfD\0\xAFfD\0\xAFfD\0\xAFfD\0\xAFfD\0\xAFfD\0\xAFe\x01\0\xAFfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0g\0\0\0\xB3\x05\0\0\xB3\x05\0\0g\0\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3H\x01\0\xB3H\x01\0\xB3H\x01\0\xB3H\x01\0\xB3H\x01\0g\0\0\0\xB3fD\0\xB3fD\0g\0\0\0\xB3H\x01\0\xB3H\x01\0\xB3fD\0\xB3H\x01\0\xB3fD\0\xB3\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x04\0\0\xD3\x05\0\0\xD3\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4fD\0\xB4fD\0\xB4H\x01\0\xB4H\x01\0\xB4\x17\0\0\xB4\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xC6fD\0\xC6fD\0\xC6e\x01\0\xC6H\x01\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0g\0\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6H\x01\0\xC6H\x01\0\xC6fD\0\xC6fD\0\xC6fD\0\xC6fD\0\xC6fD\0g\0\0\0g\0\0\0g\0\0\0\xC6H\x01\0\xC6H\x01\0\xC6fD\0\xC6h\0\0\xC6fD\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x83\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x19\x10\0#\x19\x10\0#\x19\x10\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#\x17\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0g\0\0\0e\x17\0\0e\x17\0\0e\x17\0\0e\x17\0\0e\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x17\0\0\xC1\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0G0\0\0GfD\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0GfD\0GfD\0GfD\0GfD\0GfD\0GfD\0GfD\0GfD\0GfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAfD\0\xCAH\x01\0\xCAH\x01\0\xCAH\x01\0\xCAfD\0\xCAfD\0\xCAfD\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0g\0\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x95\x17\0\0\x95\x17\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0g\0\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0g\0\0\0g\0\0\0\x86fD\0\x86fD\0\x86fD\0\x86fD\0\x86fD\0\x86\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0KfD\0KfD\0KfD\0KfD\0KfD\0KfD\0KfD\0K\x17\0\0K\x17\0\0K\x17\0\0K\x17\0\0K\x17\0\0K\x1B\0\0K\x1B\0\0K\x1B\0\0K\x1B\0\0K\x04\0\0K\x04\0\0K\x04\0\0K\x04\0\0K\x17\0\0K\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0g\0\0\0K\x0B\0\0K\x0B\0\0K\x0B\0\0K\x0B\0\0K\x0B\0\0g\0\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0K\x05\0\0K\x05\0\0K\x05\0\0\xCB\x04\0\0\xCB\x04\0\0\xCB\x04\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB%\x01\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB%\x01\0\xCB%\x01\0\xCB%\x01\0\xCB%\x01\0\xCB\x04\0\0\xCB\x04\0\0\xCB\x17\0\0\xCB\x17\0\0\xCB\x17\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x17\0\0\xB5\x17\0\0\xB5\x17\0\0\xB5\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0g\0\0\0g\0\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\\fD\0\\\x05\0\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\\fD\0\\fD\0\\fD\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\x9A\x04\0\0\x96\x04\0\0\x11\x17(\0\x11\x04\0\0\xBFfD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x11h\0\0\x11h\0\0\x11\x04\0\0\x11\x04\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBF\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0g\0\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0g\0\0\0\x16\x04\0\0\x16\x04\0\0g\0\0\0\x16\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x14\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0g\0\0\0g\0\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0g\0\0\0g\0\0\0\x87\x1B\0\0\x87fD\0\x87fD\0\x87\x17\0\0\x000H\0\x000H\0\x000H\0\x000H\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x18(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0h\0\0\0h\0\0\x01fD\0\x01fD\0\x01fD\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0h\0\0\0h\0\0\0h\0\0\x000H\0\x000H\0\x000H\0\x000H\0\x000H\0\x000H\0\x000H\0\x000H\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\0\x1B\0\0\0\x1B\0\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\x01fD\0\x01fD\0\x01fD\0\x01fD\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0EfD\0\x0EfD\0\x0EfD\0\x0E\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0g\0\0\0\0\x02\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0g\0\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x02\0\0g\0\0\0g\0\0\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD\0pfD\0pfD\0pfD\0pfD\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD\0p\x1B\0\0p\x1B\0\0p\x17\0\0p\x17\0\0p\x17\0\0p\x17\0\0p\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0pfD\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\08fD\08fD\08fD\08fD\08fD\08fD\08fD\0g\0\0\08fD\08fD\08fD\08fD\08fD\08fD\08fD\08fD\08fD\0g\0\0\0g\0\0\08fD\08fD\08fD\08fD\08fD\0g\0\0\08fD\08fD\0g\0\0\08fD\08fD\08fD\08fD\08fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x08fD\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0g\0\0\0g\0\0\0g\0\0\0\xBAfD\0\xBAfD\0\xBAfD\0\xBAfD\0\xBAfD\0\xBAfD\0\xBAfD\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0g\0\0\0g\0\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBA\x05\0\0\xBA\x1B\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4fD\0g\0\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBCfD\0\xBCfD\0\xBCfD\0\xBCfD\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBC\x19\x10\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x04\0\0\xC7fD\0\xC7fD\0\xC7fD\0\xC7fD\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCCfD\0\xCCfD\0\xCC\x05\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCC\x17\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0g\0\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2fD\0\xD2\x05\0\0\xD2\x05\0\0\xD2fD\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2fD\0\xD2fD\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2fD\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD2\x05\0\0\xD2\x04\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0g\0\x04\0g\0\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8CfD\0\x8CfD\0\x8CfD\0\x8CfD\0\x8CfD\0\x8CfD\0\x8CfD\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x01\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7\x02\x04\0\xA7fD\0\xA7fD\0\xA7fD\0\xA7fD\0\xA7fD\0\xA7fD\0\xA7fD\0\xA7\x04\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA7\x17\x04\0\xA7\x17\x04\0g\x004\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x1B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x194\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x18(\0\x02\x18(\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B(\0\0\x0B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\x14\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\x80\0\0\x1B\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0z\xA8\0\0z\xA8\0\0z\xA8\0\0z\xA8\0\0z\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0H\0g\0H\0\x11\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0H\0g\0H\0g H\0\x000H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0\0pH\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\0H\0g\0H\0\0\0\0\0\0\0\0\0") } , 0u32) };
+pub const COMPOSITE: icu_collections::codepointtrie::CodePointTrie<'static, u32> = unsafe { icu_collections :: codepointtrie :: CodePointTrie :: from_parts_unstable_unchecked_v1 (icu_collections :: codepointtrie :: CodePointTrieHeader { high_start : 1114112u32 , shifted12_high_start : 272u16 , index3_null_offset : 32767u16 , data_null_offset : 8862u32 , null_value : 4718695u32 , trie_type : icu_collections :: codepointtrie :: TrieType :: Small , } , unsafe { zerovec :: ZeroVec :: from_bytes_unchecked (b"\0\0@\0\x80\0\xC0\0\0\x01@\x01\x80\x01\xC0\x01\0\x02@\x02\x80\x02\xC0\x02\0\x03@\x03\x80\x03\xC0\x03\0\x04@\x04\x80\x04\xC0\x04\0\x05@\x05\x80\x05\xC0\x05\0\x06@\x06\x80\x06\xC0\x06\0\x07@\x07\x80\x07\xC0\x07\0\x08@\x08\x80\x08\xC0\x08\0\t@\t\x80\t\xC0\t\0\n@\n\x80\n\xC0\n\0\x0B@\x0B\x80\x0B\xC0\x0B\0\x0C@\x0C\x80\x0C\xC0\x0C\0\r@\r\x80\r\xC0\r\0\x0E@\x0E\x80\x0E\xC0\x0E\0\x0F@\x0F\x80\x0F\xC0\x0F\xE4\x03$\x04\xE4\x05\xE4\x06\xE4\t$\x0B\x04\x0CD\x0Fd\x0Fd\x0F\xC4\x0F\x84\x10\xE4\x10\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x04\x11\x04\x11\x04\x11$\x11\x84\x11\x04\x11\x04\x11$\x11\xA4\x11\xA4\x11\xA4\x11\xE4\x11\xA4\x11\xA4\x11\xA4\x11\xE4\x11\0\0\x10\0 \x000\0@\0P\0`\0p\0\x80\0\x90\0\xA0\0\xB0\0\xC0\0\xD0\0\xE0\0\xF0\0\0\x01\0\x01\0\x010\x01@\x01\0\x01\0\x01p\x01\x80\x01\x90\x01\xA0\x01\xB0\x01\xC0\x01\xD0\x01\0\x01\xF0\x01\0\x01\0\x01\0\x010\x02@\x02\xE0\0\xE0\0\xE0\0\xE0\0\x90\x02\xE0\0\xB0\x02\xC0\x02\xD0\x02\xE0\x02\xF0\x02\0\x03\0\x03\0\x03\0\x03\0\x03\0\x03\0\x03p\x03\x80\x03\x90\x03\xA0\x03\xB0\x03\xC0\x03\xD0\x03\xE0\x03\xF0\x03\0\x04\0\x04\0\x040\x040\x040\x04`\x04`\x04\x80\x04`\x04`\x04`\x04\xC0\x04`\x04`\x04`\x04`\x04`\x04`\x040\x05@\x05P\x05`\x05`\x05\x80\x05\x90\x05\xA0\x05\xB0\x05\xC0\x05\xD0\x05\xE0\x05\xF0\x05\0\x06\x10\x06 \x060\x06@\x06P\x06`\x06p\x06\x80\x06\x90\x06\xA0\x06\xA0\x06\xC0\x06\xD0\x06\xE0\x06\xF0\x06\0\x07\x10\x07 \x070\x07@\x07P\x07`\x07p\x07\x80\x07\x80\x07\xA0\x07\xB0\x07\xC0\x07\xD0\x07\xE0\x07\xF0\x07\0\x08\x10\x08 \x080\x08@\x08P\x08`\x08p\x08\x80\x08\x90\x08\xA0\x08\xB0\x08\xC0\x08\xD0\x08\xE0\x08\xD0\x08\0\t\x10\t\x10\t0\t@\tP\t`\tp\t\x80\t\x90\t\xA0\t\xB0\t\xC0\t\xD0\t\xE0\t\xF0\t\0\n\x10\n \n0\n@\nP\n`\np\n\x80\n\x90\n\xA0\n\xB0\n\xC0\n\xD0\n\xE0\n\xF0\n\0\x0B\x10\x0B \x0B0\x0B@\x0BP\x0B`\x0Bp\x0B\x80\x0B\x90\x0B\xA0\x0B\xB0\x0B\xC0\x0B\xD0\x0B\xE0\x0B\xF0\x0B\0\x0C\x10\x0C \x0C0\x0C@\x0CP\x0C`\x0Cp\x0C\x80\x0C\x90\x0C\xA0\x0C\xB0\x0C\xC0\x0C\xD0\x0C\xE0\x0C\xF0\x0C\0\r\x10\r \r0\r@\rP\r`\rp\r\x80\r\x90\r\xA0\r\xB0\r\xC0\r\xD0\r\xE0\r\xF0\r\0\x0E\x10\x0E\x10\x0E0\x0E@\x0EP\x0E`\x0E`\x0E\x80\x0E\x90\x0E\xA0\x0E\xB0\x0E\xC0\x0E\xD0\x0E`\x0E`\x0E\0\x0F\x10\x0F \x0F0\x0F@\x0FP\x0F`\x0Fp\x0F\x80\x0F\x90\x0F\xA0\x0F\xB0\x0F\xC0\x0F\xD0\x0F`\x0E`\x0E\0\x10\0\x10\x05\x10\x14\x10$\x104\x10C\x10R\x10`\x10p\x10\x80\x10\x80\x10\x8A\x10\x9A\x10\x9A\x10\x9F\x10\xAF\x10\xAF\x10\xAF\x10\xAF\x10\xAF\x10\xAF\x10\xBF\x10\xBF\x10\xBF\x10\xBF\x10\xC7\x10\xCF\x10\xCF\x10\xCF\x10\xCF\x10\xCF\x10\xDF\x10\xDF\x10\xDF\x10\xDF\x10\xE6\x10\xF6\x10\xDF\x10\xDF\x10\xE6\x10\xDF\x10\xDF\x10\xEE\x10\xFE\x10\x07\x11\xDF\x10\xDF\x10\xDF\x10\xFE\x10\xDF\x10\xDF\x10\xDF\x10\x0F\x11\x1F\x11(\x11\xDF\x108\x11H\x11H\x11H\x11H\x11H\x11R\x11b\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11c\x11f\x11c\x11v\x11{\x11\x8B\x11\x8B\x11\x8B\x11\x8B\x11\x90\x11\x9F\x11\xAF\x11\xBD\x11\xCD\x11\xDB\x11\xEB\x11\xF9\x11\t\x12\x18\x12(\x12(\x12(\x124\x12B\x12N\x12^\x12n\x12~\x12\x8E\x12\x9E\x12\x9E\x12\xAB\x12\x9E\x12\x9E\x12\xB2\x12\xC2\x12\x9E\x12\xC9\x12c\x11c\x11c\x11c\x11\xD9\x12\xE9\x12\xEA\x12\xFA\x12\n\x13\x1A\x13*\x13,\x135\x13E\x13E\x13I\x13E\x13K\x13[\x13k\x13k\x13{\x13\x84\x13\x94\x13\x94\x13\x94\x13\x9F\x13\xAF\x13\xBC\x13\xCC\x13\xCC\x13\xDC\x13\xEC\x13\0\x03\xFB\x13\xFD\x13`\x0E\r\x14\x12\x14\x12\x14\x1E\x14,\x14<\x14K\x14W\x14g\x14j\x14y\x14\x89\x14\x99\x14\x99\x14\xA3\x14\xB2\x14\xC2\x14\xC2\x14\xCE\x14\xDA\x14\xEA\x14\xFA\x14\x04\x15\x0C\x15\x1C\x15\x80\x10\x80\x10,\x15<\x15L\x15[\x15d\x15\xE0\0\xE0\0t\x15\x80\x15\x80\x15\x83\x15\x91\x15\x9C\x15\xE0\0\xA5\x02\x80\x15\x81\x15\0\x03\0\x03\0\x03\0\x03\0\x01\0\x01\0\x01\0\x01\0\x01\0\x01\0\x01\0\x01\0\x01\xAC\x15\0\x01\0\x01\0\x01\0\x01\0\x01\0\x01\xBC\x15\xCC\x15\xBC\x15\xBC\x15\xCC\x15\xDC\x15\xBC\x15\xEC\x15\xFC\x15\xFC\x15\xFC\x15\x0C\x16\x1B\x16+\x16;\x16K\x16[\x16k\x16{\x16\x8B\x16\x9A\x16\xA8\x16\xB8\x16\xC8\x16\xD8\x16\xE8\x16\xF8\x16\xF8\x16\x06\x17\x16\x17%\x174\x17D\x17T\x17b\x17r\x17\x82\x17\x92\x17\xA2\x17\xA2\x17\xAF\x17\xBF\x17\xCF\x17\xDE\x17\xE0\x17\xF0\x17\xDE\x17\xFC\x17\0\x18\x0E\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\x1E\x18*\x18:\x18E\x18K\x18K\x18K\x18P\x18\xDE\x17]\x18\0\x18i\x18m\x18}\x18\x8B\x18\x97\x18\xDE\x17\xDE\x17\xA2\x18`\x0E\xB2\x18`\x0E\x92\x17\x92\x17\xC2\x18\xCA\x18K\x18K\x18\xD8\x18K\x18\xDE\x18\x92\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17*\x18\xEE\x18\xF4\x18\xDE\x17\xDE\x17\xFC\x18\x0C\x19\x19\x19)\x199\x19I\x19U\x19d\x19t\x19\xDE\x17\x80\x19\x8B\x19\x9B\x19\xA4\x19\xB4\x19\xBB\x19\xCB\x19\xD6\x19\xE6\x19\xEB\x19\x98\x19\xF7\x19\x06\x1A\x13\x1A\x1D\x1A\x92\x17)\x1A8\x1A9\x1AI\x1A\0\x18S\x1A\0\x18c\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1Ac\x1A\0\x18\0\x18\0\x18s\x1A\0\x18\0\x18\0\x18\0\x18\x80\x1A\x88\x1A\0\x18\0\x18\0\x18\x91\x1A\0\x18\x9D\x1A\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\0\x18\xAD\x1A\xB9\x19\xDE\x17\0\x18\xBD\x1A\xCD\x1A\xDE\x17\xD9\x1A\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xE9\x1A\xE9\x1A\xE9\x1A\xF9\x1A\xF9\x1A\xF9\x1A\t\x1B\x18\x1B(\x1B(\x1B(\x1B(\x1B(\x1B(\x1B4\x1BC\x1B\x9A\x10\x9A\x10S\x1Bc\x1Bc\x1Bc\x1Bk\x1B{\x1B\xDF\x10\x8B\x1B\x9B\x1B\x9B\x1B\x9B\x1B\x9B\x1B\xAB\x1B\xAB\x1B\xBB\x1B\xC9\x1B\xD9\x1B\xE9\x1B\xF9\x1B\t\x1C`\x0E`\x0E\x19\x1C\x1F\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C+\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C\x19\x1C;\x1C`\x0E\xDE\x17K\x1CY\x1Ci\x1Cy\x1C\x89\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x93\x1C\xA3\x1C\xA4\x1C\xA4\x1C\xA4\x1C\xA4\x1C\xA9\x1C\xB9\x1C\xBE\x1C\xBE\x1C\xCE\x1C\xCF\x1C\xCF\x1C\xCF\x1C\xCF\x1C\xD0\x1C\xE0\x1C\xBE\x1C\xBE\x1C\xDE\x17\xDE\x17\xF0\x1C\xA4\x1C\0\x1D\x03\x1D\x13\x1DK\x18\x1D\x1D-\x1D\0\x1D=\x1D\x13\x1DL\x1DK\x18\x91\x17V\x1Df\x1Df\x1Dg\x1Df\x1Df\x1Df\x1Df\x1Df\x1Dn\x1DK\x18w\x1DK\x18K\x18K\x18K\x18K\x18\x82\x1DK\x18L\x18\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x84\0\xA4\0\xC4\0\xE4\0\x04\x01$\x01D\x01d\x01\x84\x01\xA4\x01\xC4\x01\xE4\x01\x04\x02$\x02D\x02d\x02\x84\x02\xA4\x02\xC4\x02\xE4\x02\x04\x03$\x03D\x03d\x03\x84\x03\xA4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\x04\x04\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xA2\x1D\xAD\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xA2\x1D\xB3\x1D\xC3\x1D\xC3\x1D\xC3\x1D\xCC\x1D\xDC\x1D\xDC\x1D\xE4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF4\x1D\xF8\x1D\xF4\x1D\x08\x1E`\x0E`\x04`\x04\x18\x1E(\x1E`\x048\x1EH\x1EH\x1EH\x1EH\x1ER\x1Eb\x1E\xF0\x02r\x1E\x82\x1E\xFE\0\0\x01\0\x01\0\x01\x92\x1E\xA0\x1E\xB0\x1E\xB6\x1E\xC0\x1E\xCC\x1E\xD8\x1E`\x0E\xE7\x1E\xF7\x1E\x03\x1F\x10\x1F \x1F0\x1F0\x1F0\x1F>\x1FN\x1FP\x1FP\x1F\\\x1Fh\x1Fx\x1F\x88\x1F\x96\x1F\xA6\x1F\xB0\x1F\xBA\x1F\xCA\x1F\xD3\x1F\xE1\x1F\xAF\x10\xF1\x1F\x01 \x05 \x05 \x12 \" 2 B R b b i x \x88 \x98 \0\x10\xA8 \xB8 \xB8 \xB8 \xC8 \xD8 \xDD \xED \xFD \x0C!\x14!\x9B\x1B\xE0\0\xE0\0$!4!D!D!D!D!D!T!T!a!q!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03D\x04d\x04\x84\x04\xA4\x04\xC4\x04\xE4\x04\x04\x05$\x05D\x05d\x05\x84\x05\xA4\x05\xC4\x05\x04\x05$\x05D\x05\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x8D!\x92!\x81!\x85!\x92!\x89!\x92!\x9E!\xBF\x10\xAE!\xCF\x10\xCF\x10\xB9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xC9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xE9!\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xED!`\x0E`\x0E\xFD!\n\"\x19\"#\"3\"C\"C\"C\"C\"C\"C\"Q\"^\"k\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"C\"n\"~\"C\"C\"C\"C\"l\"C\"C\"\x8E\"\x9E\"\x9E\"\xAE\"\xBE\"\xCE\"\xDE\"\xEE\"\xFA\"\n#\x1A#*#C\"C\"C\"C\"C\"C\"C\"0#@#P#@\0P\0`\0`#p#\x80#\xA4\x1C\x82#\xCF\x1C\xD0\x1C\x92#\x9A#\xAA#\xBA#d\x05\x84\x05\xA4\x05\xC4\x05\x04\x05$\x05D\x05d\x05\x84\x05\xA4\x05\xC4\x05\x04\x06$\x06$\x06$\x06$\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06d\x06\x84\x06\xA4\x06\xC4\x06\xCA#\xD7#\xCF#\xDC#\xEB#\xEB#`\x0E`\x0E\xD7#\xD7#\xD7#\xD7#\xD7#\xD7#\xD7#\xEE#\xFE#\x05$\x05$\x11$!$!$!$,$5$E$U$`\x0E`\x0EK\x18K\x18e$`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0Eu$x$\x88$\x88$\x88$\x97$\xA7$\xAB$\xBB$\xBB$\xCB$\xDB$\xEA$\xFA$\xFA$\x04%\x14%\x16%&%&%2%B%`\x0E`\x0ER%R%Z%b%b%r%r%r%\x82%\x84%\x94%\xA4%\xA4%\xB0%\xB8%\xBC%\xCC%\xCC%\xD4%\xE4%\xE4%\xE4%\xF0%\0&\0&\r&\x1B&)&9&9&9&E&U&U&U&U&U&U&U&U&U&U&U&U&U&U&U&U&U&U&U&^&U&_&]&`\x0Eo&\x80\x15\x80\x15t&`\x0E`\x0E`\x0E`\x0E\x84&\x8E&\x8E&\x98&\xA8&\xB2&\xC2&\xCB&\xDB&\xDC&\xEB&\xFB&\xFB&\xFB&\x0B'\x18'('2'B'H'X'^'\xFB&\xFB&n'n'~'\x86'\x96'\xA6'\x96'\x96'\xB6'\xC6'\xCF'\xD9'\xE9'\xF9'\t(\x0C(\x1C(\x1F(\xFB&\xFB&/(=(L(\\(l(l(l(v(\x86(\x90(\xA0(\xAD(\xBD(\xCD(\xDA(\xFB&\xFB&\xFB&\xFB&\xFB&\xEA(\xEA(\xEA(\xEA(\xF1(\xFB&\xFB&\xFB&\x01)\x01)\x01)\x0E)\x1E)\x1E)\x1E)+);)<)J)Z)j)z)\x84)\x94)\x9E)\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xAE)\xAF)\xBF)\xBF)\xC5)\xD5)\xE5)\xF5)\xFE)\x04*\x14*\x17*$*4*?*N*\xFE)^*l*\xFB&\xFB&|*\x8C*\xFB&\x9C*\xA5*\xB5*\xB8*\xB8*\xC0*\xC9*\xD7*\xE1*\xF1*\x01+\x04+\x04+\x14+\"+2+9+I+Y+\\+e+r+\x82+\x92+\x92+\x9F+\xAF+\xB2+\xB2+\xBF+\xCF+\xDF+\xEF+\xFA+\n,\x18,\x1C,+,:,`\x0E`\x0E`\x0EJ,Y,h,x,x,y,\x89,\x99,\xA9,\xB5,\xBC,\xCB,\xDB,\xEB,\xF9,\x01-\x11-!-!-+-:-J-Y-`\x0Ei-i-i-t-\x84-\x94-\xA3-`\x0E\xB3-\xB3-\xB3-\xC3-\xD2-\xE2-`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xF2-\xF2-\xF3-\x03.\x12.\x1A.`\x0E`\x0E*.*.*.:.I.Y.i.`\x0Ey.y.~.\x8E.\x9E.\xAE.\xBA.`\x0E\xCA.\xCF.\xDF.\xEF.\xFF.`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x0F/\x0F/\x13/\"/`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E2/2/B/B/R/_/o/{/\x83/\x93/\xA3/\xB3/`\x0E`\x0E`\x0E`\x0E\xC3/\xCD/\xCD/\xDC/\xEC/`\x0E\xFC/\x070\x070\x140#030?0?0K0U0c0c\x11s0s0s0z0\x8A0`\x0E`\x0E`\x0E`\x0E`\x0E\x9A0`\x0E`\x0E`\x0E`\x0E`\x0E\xAA0\xAA0\xB90\xC90\xD90\xE30\xE40\xF40\x041\x141\x1E1.101@1H1W1`\x0E`\x0E`\x0E`\x0Eg1r1r1\x811\x901\xA01\xB01\xBA1\xC01\xD01\xE01\xF01\xF01\xF71\x072`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x172$242C2E2Q2a2q2`\x0E`\x0E`\x0E`\x0E`\x0E\x812\x912\x9C2\xAB2\xB92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xCF2`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xDF2\xDF2\xDF2\xDF2\xDF2\xDF2\xE02\xF02\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\xC92\x003`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x103\x103\x103\x103\x103\x103\x1F3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3?3O3Y3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3\x04\x07$\x07D\x07d\x07\x84\x07\xA4\x07\xC4\x07\xE4\x07\x04\x08$\x08D\x08d\x08\x84\x08\xA4\x08\xC4\x08\xE4\x08\x04\t$\tD\td\td\td\td\t\x84\t\xA4\t\xA4\t\xC4\t\xA4\t\xA4\t\xA4\t\xA4\t\xA4\t/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3/3i3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3y3\x823`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x923\x943\xA23\xB23`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1EH\x1E\xC23\xD23\xD33\xE33\xF33\xF33\xF33\xF33\xF43\x044\x144\x164&4646464F4V4f4t4|464`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x8C4\x8F4\x9C4\xAC4`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xBC4\xBC4\xCC4\xCC4\xDC4\xE54\xF54\xFC4\x075\x135`\x0E`\x0E#5#5#5#5(5859595A5P5`\x0E`\x0E`\x0E`\x0E`5p5\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\xA4\t\x04\n$\nD\nd\td\td\td\td\td\td\td\td\td\td\td\td\nd\td\td\t\x84\n\xA4\n\xC4\n\xE4\n\x04\x0B\x04\x0B\x04\x0B\x04\x0B\x04\x0B\x04\x0B\x04\x0B\x04\x0B\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x805\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x905\x9A5`\x0E\xA05\x805\xB05`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x805\x805\x805\x805\x805\x805\x805\xBC5`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xCC5\xDC5\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\x8A\x1C\xEC5\xFA5`\x0E\n6\x166&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6&6*6`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E:6:6:6:6:6:6?6=6A6Q6a6`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x04\x0B\x04\x0B\x04\x0B\x04\x0B\x04\x0BD\x0Bd\x0Bd\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\t\x84\x0B\xA4\x0B\xC4\x0Bd\td\td\td\t\xE4\x0Bd\t\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17E\x18K\x18q6\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xA8\x18\xDE\x17}6\xDE\x17\x8D6\0\x03\0\x03\xFB\x13\0\x03.\x17K\x18K\x18K\x18K\x18K\x18K\x18K\x18\x9D6`\x0E`\x0E`\x0EK\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18K\x18\xAD6K\x18K\x18\xBD6K\x18K\x18K\x18\xC86\xD56\xE26K\x18\xEE6K\x18K\x18K\x18\xFC6`\x0E\x0C7\x0C7\x0C7\x0C7\x1A7`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x05$*7\x05$*7\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17:7\x05$J7`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0EZ7`7j7v7\\7\x867r7Z7h7\x8C7\x9C7\xAA7\x877Z7`7j7\xB77\xC47j7\xD27\xE27\xF17n7Z7d7j7Z7`7j7v7\\7j7r7Z7h7n7Z7d7j7Z7`7j7\xFB7Z7\n8\x0F8\x1D8\"8j7.8Z7\x068>8t7N8j7^8Z7T8j7d8r8r8r8\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x828\x928\x928\x928\x9B8\x928\x928\x958\xAB8\xB78\xC38\xCD8`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xDD8\xE88\xF78`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x079\x0F9\x1D9-9-9-9/9`\x0E=9`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0EM9M9P9`9p9`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x809\x829`\x0E\x929\x929\x969\xA69`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xB69\xBB9\xCB9`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xDB9\xDD9\xED9`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xFD9\xFE9\x0E:\x1E:`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E.:\xE0\x10>:>:>:>:>:>:>:>:>:>:>:>:I:Y:\xFB&\xFB&i:i:w:y:\x85:\x95:\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xA5:\xA6:\xA6:\xAA:\xBA:\xFB&\xFB&\xFB&\xFB&\xA5:\xA6:\xA8:\xCA:\xFE)\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&+#C\"\xD9:\xE6:\xF4:\x04;\x12;\x1A;*;5;D;5;\xFE)\xFE)\xFE)T;\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&\xFB&x\x18\xDE\x17d;\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17l;|;{;\x8B;{;\xDE\x17j;\x9B;K\x18L\x18K\x18K\x18K\x18\xE6\x1C\xAB;\xBB;\xCA;\xD5;\xE3;\xE3;\xE3;\xED;\xF3;\x03<\x13<\x1E<+<;<K<j;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;[<[<i<[<[<[<[<[<[<u<[<[<[<[<[<\x80<[<[<[<[<[<[<[<[<[<[<[<[<[<[<[<\x90<[<[<[<]<\xA0<[<\xB0<\xBF<\xCA<\xD7<\xE3<\xF2<\0=\x0F=\x1E=#=[<[<[<[<[<\xDE\x17\xDE\x17\xDE\x17[<[<[<[<-=:=F=V=\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17f;f=q=d;\xDE\x17\xDE\x17\xDE\x17h;f;\xDE\x17\xDE\x17h;\xDE\x17\x81=d;\x8D=\x9D=\xE3;\xE3;\xAD=[<[<\xB9=\xBE=[<[<[<[<[<[<[<[<[<[<[<\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17h;\x81=\xC5=\xC7=[<[<[<\xD5=\xE2=\xF1=i=\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\x01>\xDE\x17\xDE\x17\xDE\x17\xDE\x17\xDE\x17\x11>\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;\xE3;!>d\td\td\td\td\td\t$\x0CD\x0Cd\x0C\x84\x0C\xA4\x0C\xC4\x0C\xE4\x0C\x04\rd\t$\rD\rd\r\x84\r\xA4\r\xC4\r\xE4\r\x04\x0E$\x0ED\x0Ed\x0E\x84\x0E\xA4\x0E\xC4\x0E\xE4\x0E\x04\x0F$\x0F\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D`\x0E`\x0E\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xE9!\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\x84\x0F\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xA4\x0F\xC4\x03\xC4\x03\xC4\x03\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xE9!\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D1>\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xE9!`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\x92\x1D\xE9!`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E3>\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xE4\x0F\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\x04\x10\xC4\x03$\x10d\td\td\td\t\xC4\x03D\x10d\td\x10\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xEC!\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\x92\x1D\xED!`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E`\x0E\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xA4\x10\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x03\xC4\x10d\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\x10C>E>U>U>U>U>U>U>E>E>E>E>E>E>E>E>\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"\xBE\"E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>E>D\x11d\x11d\x11d\x11d\x11d\x11d\x11d\x11d\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\td\tD\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!\xD9!e>D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06D\x06\xC4\x11") } , unsafe { zerovec :: ZeroVec :: from_bytes_unchecked (b"\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/ \0\0\xAF\x1C\x04\0/ \x04\0/$\x04\0O\x1C\x04\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/\x1C\0\0/\x1C\0\0/\x1C\0\0/ \0\0\x0C$\0\0\x17(\0\0\x17(\0\0\x17\x90\0\0\x19\x10\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17\xA8\0\0\x18\x0C\0\0\x17\x18\0\0\x13\x0C\0\0\x17\x18\0\0\x17\x18\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\t\x88\0\0\x17\x18\0\0\x17(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x17(\0\0\x17(\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\0\x14(\0\0\x17(\0\0\x15(\0\0\x1A(\0\0\x16(\0\0\x1A(\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x14(\0\0\x18(\0\0\x15(\0\0\x18(\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/\x1C\x04\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0/H\0\0\x0C\x18\0\0\x17(\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x1B(\0\0\x17(\0\0\x1A(\0\0\x1B\xA8\0\x19\x05\0\0\0\x1C(\0\0\x18(\0\x000H(\0\x1B\xA8\0\0\x1A(\0\0\x1B\x10\0\0\x18\x10\0\0\x0B\x08\0\0\x0B\x08\0\0\x1A(\0\0\x02\0\0\0\x17(\0\0\x17(\0\0\x1A(\0\0\x0B\x08\0\x19\x05\0\0\0\x1D(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x17(\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\0\x18(\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x18(\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x03\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x05\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\0\x04(\0\0\x04(\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04\0\0\0\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\x05\x1A(\0\x05\x1A(\0\0\x04(\0\0\x1A(\0\0\x04\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\0\x04(\0\x0E\x1A(\0\x0E\x01\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x04\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\0\x17(\0\x0E\x01\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0E\x1A(\0\0\x1A(\0\x0E\x01\0\0\0\x17(\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x18(\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x01\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x1B\0\0\x08fD(\x08fD(\x01fD(\x01fD(\x08fD(\x08gD(\x08gD(\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0g\0\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0\x03\x01\0\0g\0\0\0g\0\0\0\x03\x04\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x17\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x17\0\0\x03\x13(\0g\0\0\0g\0\0\0\x03\x1B(\0\x03\x1B(\0\x03\x19\x10\0g\0\x04\0\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13fD(\x13\x13\x04\0\x13fD(\x13\x17\x04\0\x13fD(\x13fD(\x13\x17\x04\0\x13fD(\x13fD(\x13\x17\x04\0\x13fD(g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x17\x04\0\x13\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x02p\x15\0\x02p\x15\0\x02p\x15\0\x02p\x15\0\x02p\x15\0\0p\x15\0\x02\x18(\0\x02\x18(\0\x02\x184\0\x02\x17\x10\0\x02\x17\x10\0\x02\x194\0\0\x17\x18\0\x02\x174\0\x02\x1B(\0\x02\x1B(\0\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\0\x174\0\x0204(\x02\x174\0\x02\x174\0\0\x174\0\x02\x054\x10\x02\x054\0\x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\0\x044\x08\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054\x10\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\t\x14\0\x02\x17\x10\0\x02\x17\x14\0\x02\x17\x14\0\x02\x174\0\x02\x054\x10\x02\x054\x10\x01fD(\x02\x054 \x02\x054 \x02\x054 \x02\x054\0\x02\x054 \x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x174\0\x02\x054 \x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\0p\x15\0\x02\x1B(\0\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02\x044\0\x02\x044\0\x02fD(\x02fD(\x02\x1B(\0\x02fD(\x02fD(\x02fD(\x02fD(\x02\x054 \x02\x054 \x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\t\x08\0\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x1B4\0\x02\x1B4\0\x02\x054\x10\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0\"\x174\0g\x004\0\"p5(\"\x054 \"fD(\"\x054\x10\"\x054\x10\"\x054\x10\"\x054 \"\x054 \"\x054 \"\x054 \"\x054 \"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054 \"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054 \"\x054\x10\"\x054 \"\x054\x10\"\x054 \"\x054\x10\"\x054\x10\"\x054 \"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(\"fD(g\x004\0g\x004\0\"\x054 \"\x054\x10\"\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%\x054\0%fD(%fD(%fD(%fD(%fD(%fD(%fD(%fD(%fD(%fD(%fD(%\x054\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\t\x04\0W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10W\x05\x04\x10WfD(WfD(WfD(WfD(WfD(WfD(WfD(WfD(WfD(W\x04\x04\0W\x04\x04\0W\x1B(\0W\x17(\0W\x17(\0W\x17(\0W\x04\x04\x08g\0\x04\0g\0\x04\0WfD(W\x19\x04\0W\x19\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~\x05\x04\0~fD(~fD(~fD(~fD(~\x04\x04\0~fD(~fD(~fD(~fD(~fD(~fD(~fD(~fD(~fD(~\x04\x04\0~fD(~fD(~fD(~\x04\x04\0~fD(~fD(~fD(~fD(~fD(g\0\x04\0g\0\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0~\x17\x04\0g\0\x04\0T\x05\x04 T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04 T\x05\x04 T\x05\x04\x10T\x05\x04 T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04\x10T\x05\x04 T\x05\x04\x10T\x05\x04 T\x05\x04 T\x05\x04 TfD(TfD(TfD(g\0\x04\0g\0\x04\0T\x17\x04\0g\0\x04\0\"\x054\x10\"\x054\0\"\x054\x10\"\x054\x10\"\x054\x10\"\x054\x10\"\x054\0\"\x054 \"\x054\x10\"\x054 \"\x054 g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054 \x02\x054\x08\x02\x054\x08\x02\x054\x08\x02\x054\x10\x02\x054\0\x02\x1A4\0\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054\x10\x02p\x15\0\x02p\x15\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054 \x02\x054\0\x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054 \x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x054\x10\x02\x044\0\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\0p\x15\0\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\nfD(\nfD(\nfD(\nH\x01\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\nfD(\nH\x01\0\nfD(\n\x05\0\0\nH\x01\0\nH\x01\0\nH\x01\0\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nH\x01\0\nH\x01\0\nH\x01\0\nH\x01\0\nfD(\nH\x01\0\nH\x01\0\n\x05\0\0\x01fD(\x01fD(\x01fD(\x01fD(\nfD(\nfD(\nfD(\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\nfD(\nfD(\0\x17\0\0\0\x17\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\t\0\0\n\x17\0\0\n\x04\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\x04\x05\0\0\x04fD(\x04H\x01\0\x04H\x01\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0\x04\x05\0\0g\0\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0g\0\0\0\x04fD(\x04\x05\0\0\x04h\0\0\x04H\x01\0\x04H\x01\0\x04fD(\x04fD(\x04fD(\x04fD(g\0\0\0g\0\0\0\x04H\x01\0\x04H\x01\0g\0\0\0g\0\0\0\x04H\x01\0\x04H\x01\0\x04fD(\x04\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x04h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0g\0\0\0\x04\x05\0\0\x04\x05\0\0\x04\x05\0\0\x04fD(\x04fD(g\0\0\0g\0\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\t\0\0\x04\x05\0\0\x04\x05\0\0\x04\x19\x10\0\x04\x19\x10\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x0B\0\0\x04\x1B\0\0\x04\x19\x10\0\x04\x05\0\0\x04\x17\0\0\x04fD(g\0\0\0g\0\0\0\x10fD(\x10fD(\x10H\x01\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0g\0\0\0\x10fD(g\0\0\0\x10H\x01\0\x10H\x01\0\x10H\x01\0\x10fD(\x10fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10fD(\x10fD(g\0\0\0g\0\0\0\x10fD(\x10fD(\x10fD(g\0\0\0g\0\0\0g\0\0\0\x10fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0g\0\0\0\x10\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10\t\0\0\x10fD(\x10fD(\x10\x05\0\0\x10\x05\0\0\x10\x05\0\0\x10fD(\x10\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0FfD(\x0FfD(\x0FH\x01\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0\x0F\x05\0\0g\0\0\0g\0\0\0\x0FfD(\x0F\x05\0\0\x0FH\x01\0\x0FH\x01\0\x0FH\x01\0\x0FfD(\x0FfD(\x0FfD(\x0FfD(\x0FfD(g\0\0\0\x0FfD(\x0FfD(\x0FH\x01\0g\0\0\0\x0FH\x01\0\x0FH\x01\0\x0FfD(g\0\0\0g\0\0\0\x0F\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0F\x05\0\0\x0F\x05\0\0\x0FfD(\x0FfD(g\0\0\0g\0\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\t\0\0\x0F\x17\0\0\x0F\x19\x10\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0F\x05\0\0\x0FfD(\x0FfD(\x0FfD(\x0FfD(\x0FfD(\x0FfD(g\0\0\0\x1FfD(\x1FH\x01\0\x1FH\x01\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0g\0\0\0\x1FfD(\x1F\x05\0\0\x1Fh\0\0\x1FfD(\x1FH\x01\0\x1FfD(\x1FfD(\x1FfD(\x1FfD(g\0\0\0g\0\0\0\x1FH\x01\0\x1FH\x01\0g\0\0\0g\0\0\0\x1FH\x01\0\x1FH\x01\0\x1FfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1FfD(\x1FfD(\x1Fh\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0g\0\0\0\x1F\x05\0\0\x1F\x05\0\0\x1F\x05\0\0\x1FfD(\x1FfD(g\0\0\0g\0\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\t\0\0\x1F\x1B\0\0\x1F\x05\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0\x1F\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#fD(#\x05\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0g\0\0\0#\x05\0\0g\0\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#h\0\0#H\x01\0#fD(#H\x01\0#H\x01\0g\0\0\0g\0\0\0g\0\0\0#H\x01\0#H\x01\0#H\x01\0g\0\0\0#H\x01\0#H\x01\0#H\x01\0#fD(g\0\0\0g\0\0\0#\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\t\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x19\x10\0#\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0$fD($H\x01\0$H\x01\0$H\x01\0$fD($\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0g\0\0\0$fD($\x05\0\0$fD($fD($fD($H\x01\0$H\x01\0$H\x01\0$H\x01\0g\0\0\0$fD($fD($fD(g\0\0\0$fD($fD($fD($fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0$fD($fD(g\0\0\0$\x05\0\0$\x05\0\0$\x05\0\0g\0\0\0$\x05\0\0$\x05\0\0g\0\0\0g\0\0\0$\x05\0\0$\x05\0\0$fD($fD(g\0\0\0g\0\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0$\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0$\x17\0\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x0B(\0$\x1B\0\0\x15\x05\0\0\x15fD(\x15H\x01\0\x15H\x01\0\x15\x17\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0g\0\0\0\x15fD(\x15\x05\0\0\x15H\x01\0\x15f\0(\x15h\0\0\x15H\x01\0\x15h\0\0\x15H\x01\0\x15H\x01\0g\0\0\0\x15f\0(\x15h\0\0\x15h\0\0g\0\0\0\x15h\0\0\x15h\0\0\x15fD(\x15fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x15h\0\0\x15h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15\x05\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15fD(\x15fD(g\0\0\0g\0\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0\x15\t\0\0g\0\0\0\x15\x05\0\0\x15\x05\0\0\x15H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1AfD(\x1AfD(\x1AH\x01\0\x1AH\x01\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0g\0\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0g\0\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1AfD(\x1AfD(\x1A\x05\0\0\x1Ah\0\0\x1AH\x01\0\x1AH\x01\0\x1AfD(\x1AfD(\x1AfD(\x1AfD(g\0\0\0\x1AH\x01\0\x1AH\x01\0\x1AH\x01\0g\0\0\0\x1AH\x01\0\x1AH\x01\0\x1AH\x01\0\x1AfD(\x1Ae\x01\0\x1A\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1Ah\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1AfD(\x1AfD(g\0\0\0g\0\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\t\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x0B\0\0\x1A\x1B\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0\x1A\x05\0\0g\0\0\0!fD(!H\x01\0!H\x01\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0g\0\0\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0!\x05\0\0g\0\0\0g\0\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0!\x05\0\0g\0\0\0g\0\0\0g\0\0\0!fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0!h\0\0!H\x01\0!H\x01\0!fD(!fD(!fD(g\0\0\0!fD(g\0\0\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!H\x01\0!h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0!\t\0\0g\0\0\0g\0\0\0!H\x01\0!H\x01\0!\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&fD(&\x05\0\0&E\x01\0&fD(&fD(&fD(&fD(&fD(&fD(&fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x19\x10\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x05\0\0&\x04\0\0&fD(&fD(&fD(&fD(&fD(&fD(&fD(&fD(&\x17\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\t\0\0&\x17\0\0&\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18fD(\x18\x05\0\0\x18E\x01\0\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(\x18\x05\0\0g\0\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0\x18\x04\0\0g\0\0\0\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(\x18fD(g\0\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0\x18\t\0\0g\0\0\0g\0\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0\x18\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0'\x05\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x1B\0\0'\x17\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'fD('fD('\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\t\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x0B\0\0'\x1B\0\0'fD('\x1B\0\0'fD('\x1B\0\0'fD('\x14(\0'\x15(\0'\x14(\0'\x15(\0'H\x01\0'H\x01\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0g\0\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0'fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('H\x01\0'fD('fD('fD('fD('fD('\x17\0\0'fD('fD('\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'\x05\0\0'fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD(g\0\0\0'fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD('fD(g\0\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'fD('\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0'\x1B\0\0g\0\0\0'\x1B\0\0'\x1B\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0'\x17\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0'\x17\0\0'\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x08\0\0\x1C\x08\0\0\x1CfD(\x1CfD(\x1CfD(\x1CH\x01\0\x1CfD(\x1CfD(\x1CfD(\x1CfD(\x1CfD(\x1CfD(\x1C\x08\0\0\x1CfD(\x1CfD(\x1CH\x01\0\x1CH\x01\0\x1CfD(\x1CfD(\x1C\x05\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x17\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CH\x01\0\x1CH\x01\0\x1CfD(\x1CfD(\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD(\x1CfD(\x1C\x05\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD(\x1CfD(\x1CfD(\x1CfD(\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD(\x1C\x08\0\0\x1CH\x01\0\x1CfD(\x1CfD(\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1CfD(\x1C\x05\0\0\x1C\x08\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\x08\0\0\x1C\x08\0\0\x1C\x08\0\0\x1CfD(\x1C\x1B\0\0\x1C\x1B\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0g\0\0\0\x0C\x01\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0C\x01\0\0g\0\0\0g\0\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\0\x17\0\0\x0C\x04\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0BfD(\x0BfD(\x0BfD(\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x17\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0\x0B\x0B\0\0g\0\0\0g\0\0\0g\0\0\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0\x0B\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0\x06\x01\0\0g\0\0\0g\0\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0g\0\0\0g\0\0\0(\x13(\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x1B\0\0(\x17\0\0(\x05\0\0\x1D\x0C$\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x05\0\0\x1D\x14(\0\x1D\x15(\0g\0\0\0g\0\0\0g\0\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0\0\x17\0\0\0\x17\0\0\0\x17\0\0 \n\0\0 \n\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0 \x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*\x05\0\0*fD(*fD(*fD(*h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0*\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+\x05\0\0+fD(+fD(+h\0\0\0\x17\0\0\0\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,\x05\0\0,fD(,fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0-\x05\0\0g\0\0\0-\x05\0\0-\x05\0\0g\0\0\0-fD(-fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17\x05\0\0\x17fD(\x17fD(\x17H\x01\0\x17fD(\x17fD(\x17fD(\x17fD(\x17fD(\x17fD(\x17fD(\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17H\x01\0\x17fD(\x17H\x01\0\x17H\x01\0\x17fD(\x17fD(\x17fD(\x17fD(\x17fD(\x17fD(\x17fD(\x17\x17\0\0\x17\x17\0\0\x17\x17\0\0\x17\x04\0\0\x17\x17\0\0\x17\x17\0\0\x17\x17\0\0\x17\x19\x10\0\x17\x05\0\0\x17fD(g\0\0\0g\0\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0\x17\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0\x17\x0B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x17(\0\x1B\x17(\0\0\x17(\0\0\x17(\0\x1B\x17(\0\0\x17(\0\x1B\x13(\0\x1B\x17(\x10\x1B\x17(\0\x1B\x17(\0\x1B\x17(\x08\x1BfD)\x1BfD)\x1BfD)\x1B0H\0\x1BfD)\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0\x1B\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x04\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1B\x05\0\0\x1BfD(\x1BfD(\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1B\x05\0\x10\x1BfD(\x1B\x05\0\x10g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0(\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\x000\x05\0\0g\0\0\x000fD(0fD(0fD(0H\x01\x000H\x01\x000H\x01\x000H\x01\x000fD(0fD(0H\x01\x000H\x01\x000H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\x000H\x01\x000H\x01\x000fD(0H\x01\x000H\x01\x000H\x01\x000H\x01\x000H\x01\x000H\x01\x000fD(0fD(0fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\x000\x1B(\0g\0\0\0g\0\0\0g\0\0\x000\x17(\x000\x17(\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x000\t\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\x004\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0;\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\t\0\0;\x0B\0\0g\0\0\0g\0\0\0g\0\0\0;\x1B(\0;\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\0\x17\x1B(\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007\x05\0\x007fD(7fD(7H\x01\x007H\x01\x007fD(g\0\0\0g\0\0\x007\x17\0\x007\x17\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0j\x05\0\0jH\x01\0jfD(jH\x01\0jfD(jfD(jfD(jfD(jfD(jfD(jfD(g\0\0\0jfD(j\x08\0\0jfD(j\x08\0\0j\x08\0\0jfD(jfD(jfD(jfD(jfD(jfD(jfD(jfD(jH\x01\0jH\x01\0jH\x01\0jfD(jfD(jfD(jfD(jfD(jfD(jfD(jfD(jfD(jfD(g\0\0\0g\0\0\0jfD(j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0j\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x04\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0j\x17\0\0g\0\0\0g\0\0\0\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01gD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0>fD(>fD(>fD(>fD(>H\x01\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>fD(>h\0\0>fD(>fD(>fD(>fD(>fD(>h\0\0>fD(>h\0\0>H\x01\0>H\x01\0>fD(>h\0\0>h\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0>\x05\0\0g\0\0\0>\x17\0\0>\x17\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\t\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x17\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>fD(>fD(>fD(>fD(>fD(>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x1B\0\0>\x17\0\0>\x17\0\0>\x17\0\0qfD(qfD(qH\x01\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0qH\x01\0qfD(qfD(qfD(qfD(qH\x01\0qH\x01\0qfD(qfD(qh\0\0qfD(qfD(qfD(q\x05\0\0q\x05\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\t\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0q\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?\x05\0\0?fD(?H\x01\0?fD(?fD(?H\x01\0?H\x01\0?H\x01\0?fD(?H\x01\0?fD(?fD(?h\0\0?h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0?\x17\0\0?\x17\0\0?\x17\0\0?\x17\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0R\x05\0\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RH\x01\0RfD(RfD(RfD(RfD(RH\x01\0RH\x01\0RfD(RfD(g\0\0\0g\0\0\0g\0\0\0R\x17\0\0R\x17\0\0R\x17\0\0R\x17\0\0R\x17\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0R\t\0\0g\0\0\0g\0\0\0g\0\0\0R\x05\0\0R\x05\0\0R\x05\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\t\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x05\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x04\0\0m\x17\0\0m\x17\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0g\0\0\0g\0\0\0\x0C\x01\0\0\x0C\x01\0\0\x0C\x01\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0q\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x01fD(\x01fD(\x01fD(\0\x17\0\0\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\0H\x01\0\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\0\x05\0\0\0\x05\0\0\0\x05\0\0\0\x05\0\0\x01fD(\0\x05\0\0\0\x05\0\0\0H\x01\0\x01fD(\x01fD(\0\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x08\x02\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x0E\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x08\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0g\0\0\0\x0E\x01\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x03\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x03\0\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x03\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0g\0\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x1A(\0\x0E\x1A(\0\x0E\x1A(\0g\0\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x02\0\0g\0\0\0\x0E\x02\0\0\x0E\x02\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x01\0\0\x0E\x03\0\0\x0E\x1A(\0\x0E\x1A(\0g\0\0\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\0\x0C$\0\x000H(\x01pH\0\x010J\x08\x000\0(\x000\x04(\0\x13(\0\0\x13(\0\0\x13(\0\0\x13(\0\0\x13(\0\0\x13(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x14(\0\0\x1C(\0\0\x1C(\0\0\x1D(\0\0\x14(\0\0\x1C(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0-$\x04\0.\x1C\x04\x000,(\x0008(\x000@(\x0000(\x000<(\0\x0C\x18\0\0\x17\x10\0\0\x17\x10\0\0\x17\x10\0\0\x17\x10\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17\xA8\0\0\x17(\0\0\x17(\0\0\x16(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x18\x18\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x17\xA8\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x18(\0\0\x17(\0\0\x16(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x0C$\0\x000H(\x000H(\x000H(\x000H(\x000H(g H\0\x000P\0\x000T\0\x000L\0\x000X\0\x000H(\x000H(\x000H(\x000H(\x000H(\x000H(\0\x0B\x08\0\x19\x04\0\0g\0\0\0g\0\0\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x18\x0C\0\0\x18\x0C\0\0\x18(\0\0\x14(\0\0\x15(\0\x19\x04\0\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x18\x0C\0\0\x18\x0C\0\0\x18(\0\0\x14(\0\0\x15(\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0g\0\0\0g\0\0\0g\0\0\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0\0\x19\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0g\0\x10\0\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01gD(\x01gD(\x01gD(\x01fD(\x01gD(\x01gD(\x01gD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\x0E\x01\0\0\0\x1B(\0\0\x01\0\0\0\x1B(\0\x19\x01\0\0\x19\x01\0\0\0\x01\0\0\0\x01\0\0\0\x1B\x10\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\x19\x01\0\0\0\x01\0\0\0\x02\0\0\0\x05\0\0\0\x05\0\0\0\x05\0\0\0\x05\0\0\0\x02\x80\0\0\x1B(\0\0\x1B(\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\x19\x02\0\0\0\x1B\0\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\x01\0\0\x19\x02\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\x19\n\0\0\0\x0B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\x0C\0\0\x18\x10\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x14(\0\0\x15(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\xA8\0\0\x18\xA8\0\0\x18\xA8\0\0\x18\xA8\0\0\x18(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0.\x1B\0\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18\xA8\0\0\x18\xA8\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x01\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\08\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x04\0\0\x19\x04\0\0\x19\x01\0\0\x19\x01\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07\x02\0\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x1B(\0\x07\x01\0\0\x07\x02\0\0\x07\x01\0\0\x07\x02\0\0\x07fD(\x07fD(\x07\x01\0\0\x07\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x07\x17(\0\x07\x17(\0\x07\x17(\0\x07\x17(\0\x07\x0B(\0\x07\x17(\0\x07\x17(\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0\x0C\x02\0\0g\0\0\0\x0C\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0C\x02\0\0g\0\0\0g\0\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0<\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0<\x04\0\0<\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0<fD(\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x17(\0\0\x17(\0\0\x1C(\0\0\x1D(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x04(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x13(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x17(\0\0\x14(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1B(\0\0\x1B(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x13(\0g\0\0\0g\0\0\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0g\0\0\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0\x11\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0C$\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x1B(\0\x11\x04\0\0\0\x05\0\0\x11\n\0\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x1B(\0\0\x1B(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x13(\0\0\x14(\0\0\x15(\0\0\x15(\0\0\x1B(\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x01fD(\x01fD(\x01fD(\x01fD(\x12h\0\0\x12h\0\0\0\x13\xA8\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x04\0\0\0\x1B(\0\0\x1B(\0\x11\n\0\0\x11\n\0\0\x11\n\0\0\x11\x04\0\0\0\x05\0\0\0\x17\xA8\0\0\x1B(\0\0\x1B(\0g\0\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0g\0\0\0g\0\0\0\x01fD(\x01fD(\0\x1A(\0\0\x1A(\0\x14\x04\0\0\x14\x04\0\0\x14\x05\0\0\0\x13(\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\0\x17(\0\0\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0\x05\x05\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B(\0\x12\x1B(\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\0\x0B(\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B\0\0\x12\x1B(\0\x12\x1B(\0\x12\x1B(\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\x16\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x04\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0)\x05\0\0g\0\0\0g\0\0\0g\0\0\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0)\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x05\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x04\0\0\x83\x17\0\0\x83\x17\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x05\0\0c\x04\0\0c\x17(\0c\x17(\0c\x17(\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\t\0\0c\x05\0\0c\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x05\0\0\x08fD(\x08gD(\x08gD(\x08gD(\x08\x17(\0\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08fD(\x08\x17(\0\x08\x04(\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x01\0\0\x08\x02\0\0\x08\x04\0\0\x08\x04\0\0\x08fD(\x08fD(\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82\n\0\0\x82fD(\x82fD(\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0\x82\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x1A(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x04(\0\0\x1A(\0\0\x1A(\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\0\x04(\0\0\x1A\0\0\0\x1A\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x05\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0\x19\x02\0\0\x19\x01\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x01\0\0\x19\x02\0\0\x19\x05\0\0\x19\x04\0\0\x19\x04\0\0\x19\x02\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0\x19\x05\0\0:\x05\0\0:\x05\0\0:fD(:\x05\0\0:\x05\0\0:\x05\0\0:fD(:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:fD(:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:\x05\0\0:H\x01\0:H\x01\0:fD(:fD(:H\x01\0:\x1B(\0:\x1B(\0:\x1B(\0:\x1B(\0:fD(g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x1B\0\0\0\x1B\0\0\0\x19\x10\0\0\x1B\x10\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x10Z\x05\0\x18Z\x05\0\0Z\x17(\0Z\x17(\0Z\x17(\0Z\x17(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0oH\x01\0oH\x01\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0o\x05\0\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0oH\x01\0ofD(ofD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0o\x17\0\0o\x17\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0o\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\nfD(\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x05\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x05\0\0\n\x17\0\0\n\x05\0\0\n\x05\0\0\nfD(O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\t\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0O\x05\0\0OfD(OfD(OfD(OfD(OfD(OfD(OfD(OfD(\0\x17\0\0O\x17\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0n\x05\0\0nfD(nfD(nfD(nfD(nfD(nfD(nfD(nfD(nfD(nH\x01\0nh\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0n\x17\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0\x12\x85\0\0g\0\0\0g\0\0\0g\0\0\0NfD(NfD(NfD(NH\x01\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0N\x05\0\0NfD(NH\x01\0NH\x01\0NfD(NfD(NfD(NfD(NH\x01\0NH\x01\0NfD(NfD(NH\x01\0NH\x01\0Nh\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0N\x17\0\0g\0\0\0\0\x04\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0N\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0N\x17\0\0N\x17\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1CfD(\x1C\x04\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0g\0\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0BfD(BfD(BfD(BfD(BfD(BfD(BH\x01\0BfD(BfD(BH\x01\0BH\x01\0BfD(BfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0B\x05\0\0B\x05\0\0B\x05\0\0BfD(B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0B\x05\0\0BfD(BH\x01\0g\0\0\0g\0\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0B\t\0\0g\0\0\0g\0\0\0B\x17\0\0B\x17\0\0B\x17\0\0B\x17\0\0\x1C\x04\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x05\0\0\x1C\x1B\0\0\x1C\x1B\0\0\x1C\x1B\0\0\x1C\x05\0\0\x1C\x08\0\0\x1CfD(\x1C\x08\0\0\x1C\x05\0\0\x1C\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7FfD(\x7F\x05\0\0\x7FfD(\x7FfD(\x7FfD(\x7F\x05\0\0\x7F\x05\0\0\x7FfD(\x7FfD(\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x05\0\0\x7FfD(\x7FfD(\x7F\x05\0\0\x7FfD(\x7F\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x7F\x05\0\0\x7F\x05\0\0\x7F\x04\0\0\x7F\x17\0\0\x7F\x17\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0sH\x01\0sfD(sfD(sH\x01\0sH\x01\0s\x17\0\0s\x17\0\0s\x05\0\0s\x04\0\0s\x04\0\0sH\x01\0sfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x1A\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x0E\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x04\0\0\0\x1A(\0\0\x1A(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0\x06\x02\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0s\x05\0\0sH\x01\0sH\x01\0sfD(sH\x01\0sH\x01\0sfD(sH\x01\0sH\x01\0s\x17\0\0sH\x01\0sfD(g\0\0\0g\0\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0s\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x12\xC5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xC5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0\x12\xE5\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0\x12%\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0\x12\x05\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x12\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0\x11\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0\x03\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x13\x05\x04\0\x13fD(\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x18\x0C\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0g\0\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x13\x05\x04\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1A4\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\0\x15(\0\0\x14(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0g\0H\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x194\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\x01fD)\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x08fD(\x08fD(\0\x17(\0\0\x13(\0\0\x13(\0\0\x16(\0\0\x16(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x17(\0\0\x16(\0\0\x16(\0\0\x16(\0\0\x17\x18\0\0\x17(\0\0\x17\x18\0g\0\0\0\0\x17(\0\0\x17\x18\0\0\x17(\0\0\x17(\0\0\x13(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x14(\0\0\x15(\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x18\x0C\0\0\x13\x0C\0\0\x18(\0\0\x18(\0\0\x18(\0g\0\0\0\0\x17(\0\0\x19\x10\0\0\x17\x10\0\0\x17(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0g\x004\0\x000H(g\0\0\0\0\x17(\0\0\x17(\0\0\x17\x10\0\0\x19\x10\0\0\x17\x10\0\0\x17(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x18\x0C\0\0\x17\x18\0\0\x13\x0C\0\0\x17\x18\0\0\x17\x18\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\x17\x18\0\0\x17(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x17(\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\0\x14(\0\0\x18(\0\0\x15(\0\0\x18(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x14(\0\0\x15(\0\0\x17(\0\0\x17(\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\0\x04\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\0d\0\0\0d\0\0g\0\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0g\0\0\0\x12\x05\0\0\x12\x05\0\0\x12\x05\0\0g\0\0\0g\0\0\0g\0\0\0\0\x19\x10\0\0\x19\x10\0\0\x18(\0\0\x1A(\0\0\x1B(\0\0\x19\x10\0\0\x19\x10\0g\0\0\0\0\x1B(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x1B(\0\0\x1B(\0g\0\0\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0\x000((\x000((\x000((\0\x1B(\0\0\x1B(\0g\0H\0g\0H\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\0g\0\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\0g\0\0\x001\x05\0\x001\x05\0\0g\0\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\x001\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x17\0\0\0\x17(\0\0\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\n(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x0B(\0\x0E\x0B(\0\x0E\x1B(\0\x0E\x1B\0\0\x0E\x1B\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0\x0E\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\x01fD(g\0\0\0g\0\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0k\x05\0\0g\0\0\0g\0\0\0g\0\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0h\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x01fD(\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x0B\0\0\x1E\x0B\0\0\x1E\x0B\0\0\x1E\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1E\x05\0\0\x1E\x05\0\0\x1E\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\n\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\x05\0\0\r\n\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0Y\x05\0\0YfD(YfD(YfD(YfD(YfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\x005\x05\0\0g\0\0\x005\x17\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x05\0\0=\x17\0\0=\n\0\0=\n\0\0=\n\0\0=\n\0\0=\n\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x01\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\0\t\x02\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x003\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\x002\x05\0\0g\0\0\0g\0\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\x002\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0\xAB\x01\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0\xAB\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0\x88\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0\x9F\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9F\x17\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0g\0\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0\xC5\x01\0\0g\0\0\0\xC5\x01\0\0\xC5\x01\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0\xC5\x02\0\0\xC5\x02\0\0g\0\0\0g\0\0\0g\0\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0\xCE\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0S\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0g\0\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0\x19\x04\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0g\0\x04\0g\0\x04\0/\x05\x04\0g\0\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0/\x05\x04\0g\0\x04\0/\x05\x04\0/\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0/\x05\x04\0g\0\x04\0g\0\x04\0/\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0t\x05\x04\0g\0\x04\0t\x17\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0t\x0B\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x05\x04\0\x90\x1B\x04\0\x90\x1B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x90\x0B\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0\x8F\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0\x8F\x0B\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0\xA2\x05\x04\0g\0\x04\0\xA2\x05\x04\0\xA2\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0\xA2\x0B\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x05\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0[\x0B\x04\0g\0\x04\0g\0\x04\0g\0\x04\0[\x17(\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0l\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0l\x17\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0\xD1\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0V\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x05\x04\0\x8D\x05\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0g\0\x04\0g\0\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\0\x8D\x0B\x04\09\x05\x04\09fD(9fD(9fD(g\0\x04\09fD(9fD(g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\09fD(9fD(9fD(9fD(9\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\0g\0\x04\09\x05\x04\09\x05\x04\09\x05\x04\0g\0\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\09\x05\x04\0g\0\x04\0g\0\x04\09fD(9fD(9fD(g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\09fD(9\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\09\x0B\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\09\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x05\x04\0\x85\x0B\x04\0\x85\x0B\x04\0\x85\x17\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x05\x04\0\x8E\x0B\x04\0\x8E\x0B\x04\0\x8E\x0B\x04\0y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04 y\x05\x04\0y\x05\x04 y\x1B\x04\0y\x05\x04 y\x05\x04 y\x05\x04\0y\x05\x04\0y\x05\x04\x18y\x05\x04 y\x05\x04 y\x05\x04 y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04\x18y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04\x10y\x05\x04 y\x05\x04\x10y\x05\x04\x10y\x05\x04 y\x05\x04\0y\x05\x04\0y\x05\x04 yfD(yfD(g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0y\x0B\x04\x10y\x0B\x04\x10y\x0B\x04\x10y\x0B\x04\x10y\x0B\x04 y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0y\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0u\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0u\x17(\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0}\x05\x04\0g\0\x04\0g\0\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0}\x0B\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0z\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0z\x0B\x04\0{\x05\x04\x10{\x05\x04 {\x05\x04\x10{\x05\x04 {\x05\x04 {\x05\x04 {\x05\x04\x10{\x05\x04\x10{\x05\x04\x10{\x05\x04 {\x05\x04\x10{\x05\x04\x10{\x05\x04 {\x05\x04\x10{\x05\x04 {\x05\x04 {\x05\x04\x10{\x05\x04 g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0{\x17\x04\0{\x17\x04\0{\x17\x04\0{\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0{\x0B\x04 {\x0B\x04 {\x0B\x04 {\x0B\x04 {\x0B\x04\x10{\x0B\x04\x10{\x0B\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0X\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0L\x01\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0L\x02\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0L\x0B\x04\0\xB6\x054\x18\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054\x10\xB6\x054 \xB6\x054\x10\xB6fD(\xB6fD(\xB6fD(\xB6fD(g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0\xB6\t\x14\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\t\x14\0\xC9\x05\x04\0\xC9\x05\x04\0\xC9\x05\x04\0\xC9\x05\x04\0\xC9\x04\x04\0\xC9\x05\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0\xC9\x01\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xC9fD(\xC9fD(\xC9fD(\xC9fD(\xC9fD(\xC9\x13(\0\xC9\x04\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0\xC9\x02\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xC9\x18\x04\0\xC9\x18\x04\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0\x02\x0B\x14\0g\0\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0\xC0\x05\x04\0g\0\x04\0\xC0fD(\xC0fD(\xC0\x13\x04\0g\0\x04\0g\0\x04\0\xC0\x05\x04\0\xC0\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\x004\0g\x004\0\x02\x054 \x02\x054\x10\x02\x054\x10\x02\x044\0\x02\x054\x10\x02\x054\x10g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x17(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0\x02\x1B(\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\x02fD(\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x05\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x0B\x04\0\xB8\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054 \xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\x10\xB7\x054\0\xB7fD(\xB7fD(\xB7fD(\xB7fD(\xB7fD(\xB7fD(\xB7fD(\xB7fD(\xB7fD(\xB7fD(\xB7\x0B4\x10\xB7\x0B4\x10\xB7\x0B4\x10\xB7\x0B4 \xB7\x174\0\xB7\x174\0\xB7\x174\0\xB7\x174\0\xB7\x174\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04 \xC2\x05\x04 \xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2\x05\x04\x10\xC2fD(\xC2fD(\xC2fD(\xC2fD(\xC2\x17\x04\0\xC2\x17\x04\0\xC2\x17\x04\0\xC2\x17\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xBD\x05\x04\x10\xBD\x05\x04\0\xBD\x05\x04\x10\xBD\x05\x04\x10\xBD\x05\x04 \xBD\x05\x04 \xBD\x05\x04 \xBD\x05\x04\0\xBD\x05\x04\x10\xBD\x05\x04 \xBD\x05\x04 \xBD\x05\x04\x10\xBD\x05\x04\x10\xBD\x05\x04 \xBD\x05\x04\x10\xBD\x05\x04\x10\xBD\x05\x04\0\xBD\x05\x04\x10\xBD\x05\x04 \xBD\x05\x04 \xBD\x05\x04\x10\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04\0\xBD\x0B\x04 \xBD\x0B\x04\x10\xBD\x0B\x04\x18g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0\xB9\x05\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0AH\x01\0AfD(AH\x01\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0A\x05\0\0AfD(AfD(AfD(AfD(AfD(AfD(AfD(AfD(A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0A\x17\0\0g\0\0\0g\0\0\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\x0B(\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0A\t\0\0AfD(A\x05\0\0A\x05\0\0AfD(AfD(A\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0AfD(xfD(xfD(xH\x01\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0x\x05\0\0xH\x01\0xH\x01\0xH\x01\0xfD(xfD(xfD(xfD(xH\x01\0xH\x01\0xfD(xfD(x\x17\0\0x\x17\0\0xp\x01\0x\x17\0\0x\x17\0\0xfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0xp\x01\0g\0\0\0g\0\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0\x98\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0\x98\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0vfD(vfD(vfD(v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0v\x05\0\0vfD(vfD(vfD(vfD(vfD(vH\x01\0vfD(vfD(vfD(vfD(vfD(g\0\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\t\0\0v\x17\0\0v\x17\0\0v\x17\0\0v\x17\0\0v\x05\0\0vH\x01\0vH\x01\0v\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0\x05\0\0\xA0fD(\xA0\x17\0\0\xA0\x17\0\0\xA0\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x97fD(\x97fD(\x97H\x01\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97\x05\0\0\x97H\x01\0\x97H\x01\0\x97H\x01\0\x97fD(\x97fD(\x97fD(\x97fD(\x97fD(\x97fD(\x97fD(\x97fD(\x97fD(\x97H\x01\0\x97h\0\0\x97\x05\0\0\x97e\x01\0\x97e\x01\0\x97\x05\0\0\x97\x17\0\0\x97\x17\0\0\x97\x17\0\0\x97\x17\0\0\x97fD(\x97fD(\x97fD(\x97fD(\x97\x17\0\0\x97H\x01\0\x97fD(\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\t\0\0\x97\x05\0\0\x97\x17\0\0\x97\x05\0\0\x97\x17\0\0\x97\x17\0\0\x97\x17\0\0g\0\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0!\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0g\0\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9D\x05\0\0\x9DH\x01\0\x9DH\x01\0\x9DH\x01\0\x9DfD(\x9DfD(\x9DH\x01\0\x9DH\x01\0\x9DfD(\x9Dh\0\0\x9DfD(\x9DfD(\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9D\x17\0\0\x9DfD(\x9D\x05\0\0\x9DfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0g\0\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x05\0\0\xA4\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91\x05\0\0\x91fD(\x91H\x01\0\x91H\x01\0\x91H\x01\0\x91fD(\x91fD(\x91fD(\x91fD(\x91fD(\x91fD(\x91fD(\x91fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0\x91\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x89fD(\x89fD(\x89H\x01\0\x89H\x01\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0g\0\0\0\x01fD(\x89fD(\x89\x05\0\0\x89h\0\0\x89H\x01\0\x89fD(\x89H\x01\0\x89H\x01\0\x89H\x01\0\x89H\x01\0g\0\0\0g\0\0\0\x89H\x01\0\x89H\x01\0g\0\0\0g\0\0\0\x89H\x01\0\x89H\x01\0\x89h\0\0g\0\0\0g\0\0\0\x89\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x89h\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x89\x05\0\0\x89\x05\0\0\x89\x05\0\0\x89H\x01\0\x89H\x01\0g\0\0\0g\0\0\0\x89fD(\x89fD(\x89fD(\x89fD(\x89fD(\x89fD(\x89fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0g\0\0\0\xCF\x05\0\0g\0\0\0g\0\0\0\xCF\x05\0\0g\0\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0\xCF\x05\0\0g\0\0\0\xCF\x05\0\0\xCFh\0\0\xCFH\x01\0\xCFH\x01\0\xCFfD(\xCFfD(\xCFfD(\xCFfD(\xCFfD(g\0\0\0\xCFh\0\0g\0\0\0g\0\0\0\xCFh\0\0g\0\0\0\xCFh\0\0\xCFh\0\0\xCFh\0\0\xCFH\x01\0g\0\0\0\xCFH\x01\0\xCFH\x01\0\xCFfD(\xCFh\0\0\xCFfD(\xCFe\x01\0\xCFfD(\xCF\x05\0\0\xCF\x17\0\0\xCF\x17\0\0g\0\0\0\xCF\x17\0\0\xCF\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCFfD(\xCFfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAAH\x01\0\xAAH\x01\0\xAAH\x01\0\xAAfD(\xAAfD(\xAAfD(\xAAfD(\xAAfD(\xAAfD(\xAAfD(\xAAfD(\xAAH\x01\0\xAAH\x01\0\xAAfD(\xAAfD(\xAAfD(\xAAH\x01\0\xAAfD(\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x05\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\x17\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\t\0\0\xAA\x17\0\0\xAA\x17\0\0g\0\0\0\xAA\x17\0\0\xAAfD(\xAA\x05\0\0\xAA\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9E\x05\0\0\x9Eh\0\0\x9EH\x01\0\x9EH\x01\0\x9EfD(\x9EfD(\x9EfD(\x9EfD(\x9EfD(\x9EfD(\x9EH\x01\0\x9EfD(\x9EH\x01\0\x9EH\x01\0\x9Eh\0\0\x9EH\x01\0\x9EfD(\x9EH\x01\0\x9EfD(\x9EfD(\x9E\x05\0\0\x9E\x05\0\0\x9E\x17\0\0\x9E\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0\x9E\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6h\0\0\xA6H\x01\0\xA6H\x01\0\xA6fD(\xA6fD(\xA6fD(\xA6fD(g\0\0\0g\0\0\0\xA6H\x01\0\xA6H\x01\0\xA6H\x01\0\xA6H\x01\0\xA6fD(\xA6fD(\xA6H\x01\0\xA6fD(\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x17\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6\x05\0\0\xA6fD(\xA6fD(g\0\0\0g\0\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3\x05\0\0\xA3H\x01\0\xA3H\x01\0\xA3H\x01\0\xA3fD(\xA3fD(\xA3fD(\xA3fD(\xA3fD(\xA3fD(\xA3fD(\xA3fD(\xA3H\x01\0\xA3H\x01\0\xA3fD(\xA3H\x01\0\xA3fD(\xA3\x17\0\0\xA3\x17\0\0\xA3\x17\0\0\xA3\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0\xA3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0\x1B\x17(\0g\0\0\0g\0\0\0g\0\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99\x05\0\0\x99fD(\x99H\x01\0\x99fD(\x99H\x01\0\x99H\x01\0\x99fD(\x99fD(\x99fD(\x99fD(\x99fD(\x99fD(\x99h\0\0\x99fD(\x99\x05\0\0\x99\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0\x99\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0\x1C\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0g\0\0\0g\0\0\0\xA1fD(\xA1H\x01\0\xA1fD(\xA1\x08\0\0\xA1\x08\0\0\xA1fD(\xA1fD(\xA1fD(\xA1fD(\xA1H\x01\0\xA1fD(\xA1fD(\xA1fD(\xA1fD(\xA1fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\t\0\0\xA1\x0B\0\0\xA1\x0B\0\0\xA1\x17\0\0\xA1\x17\0\0\xA1\x17\0\0\xA1\x1B\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0\xA1\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2\x05\0\0\xB2H\x01\0\xB2H\x01\0\xB2H\x01\0\xB2fD(\xB2fD(\xB2fD(\xB2fD(\xB2fD(\xB2fD(\xB2fD(\xB2fD(\xB2H\x01\0\xB2fD(\xB2fD(\xB2\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x01\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\x02\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\t\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0\x92\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x92\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0g\0\0\0g\0\0\0\xBE\x05\0\0g\0\0\0g\0\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0g\0\0\0\xBE\x05\0\0\xBE\x05\0\0g\0\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBE\x05\0\0\xBEh\0\0\xBEH\x01\0\xBEH\x01\0\xBEH\x01\0\xBEH\x01\0\xBEH\x01\0g\0\0\0\xBEH\x01\0\xBEH\x01\0g\0\0\0g\0\0\0\xBEfD(\xBEfD(\xBEh\0\0\xBEfD(\xBEe\x01\0\xBEH\x01\0\xBEe\x01\0\xBEH\x01\0\xBEfD(\xBE\x17\0\0\xBE\x17\0\0\xBE\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0\xBE\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0g\0\0\0g\0\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBB\x05\0\0\xBBH\x01\0\xBBH\x01\0\xBBH\x01\0\xBBfD(\xBBfD(\xBBfD(\xBBfD(g\0\0\0g\0\0\0\xBBfD(\xBBfD(\xBBH\x01\0\xBBH\x01\0\xBBH\x01\0\xBBH\x01\0\xBBfD(\xBB\x05\0\0\xBB\x17\0\0\xBB\x05\0\0\xBBH\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB1\x05\0\0\xB1fD(\xB1fD(\xB1fD(\xB1fD(\xB1fD(\xB1fD(\xB1f\0(\xB1f\0(\xB1fD(\xB1fD(\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1\x05\0\0\xB1fD(\xB1fD(\xB1fD(\xB1fD(\xB1fD(\xB1fD(\xB1H\x01\0\xB1\x05\0\0\xB1fD(\xB1fD(\xB1fD(\xB1fD(\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1\x17\0\0\xB1fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB0\x05\0\0\xB0fD(\xB0fD(\xB0fD(\xB0fD(\xB0fD(\xB0fD(\xB0H\x01\0\xB0H\x01\0\xB0fD(\xB0fD(\xB0fD(\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0\x05\0\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0e\x01\0\xB0fD(\xB0fD(\xB0fD(\xB0fD(\xB0fD(\xB0fD(\xB0fD(\xB0H\x01\0\xB0fD(\xB0fD(\xB0\x17\0\0\xB0\x17\0\0\xB0\x17\0\0\xB0\x05\0\0\xB0\x17\0\0\xB0\x17\0\0\xB0\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0\xA5\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0\n\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x97fD(\x97H\x01\0\x97fD(\x97fD(\x97fD(\x97H\x01\0\x97fD(\x97H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x05\0\0\xCD\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0\xCD\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0g\0\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8\x05\0\0\xA8H\x01\0\xA8fD(\xA8fD(\xA8fD(\xA8fD(\xA8fD(\xA8fD(\xA8fD(g\0\0\0\xA8fD(\xA8fD(\xA8fD(\xA8fD(\xA8fD(\xA8fD(\xA8H\x01\0\xA8f\0(\xA8\x05\0\0\xA8\x17\0\0\xA8\x17\0\0\xA8\x17\0\0\xA8\x17\0\0\xA8\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\t\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0\xA8\x0B\0\0g\0\0\0g\0\0\0g\0\0\0\xA9\x17\0\0\xA9\x17\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0\xA9\x05\0\0g\0\0\0g\0\0\0\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(g\0\0\0\xA9H\x01\0\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9fD(\xA9H\x01\0\xA9fD(\xA9fD(\xA9H\x01\0\xA9fD(\xA9fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0g\0\0\0\xAF\x05\0\0\xAF\x05\0\0g\0\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAF\x05\0\0\xAFfD(\xAFfD(\xAFfD(\xAFfD(\xAFfD(\xAFfD(g\0\0\0g\0\0\0g\0\0\0\xAFfD(g\0\0\0\xAFfD(\xAFfD(g\0\0\0\xAFfD(\xAFfD(\xAFfD(\xAFfD(\xAFfD(\xAFfD(\xAFe\x01\0\xAFfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0\xAF\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0g\0\0\0\xB3\x05\0\0\xB3\x05\0\0g\0\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3\x05\0\0\xB3H\x01\0\xB3H\x01\0\xB3H\x01\0\xB3H\x01\0\xB3H\x01\0g\0\0\0\xB3fD(\xB3fD(g\0\0\0\xB3H\x01\0\xB3H\x01\0\xB3fD(\xB3H\x01\0\xB3fD(\xB3\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0\xB3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x05\0\0\xD3\x04\0\0\xD3\x05\0\0\xD3\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0\xD3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4\x05\0\0\xB4fD(\xB4fD(\xB4H\x01\0\xB4H\x01\0\xB4\x17\0\0\xB4\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xC6fD(\xC6fD(\xC6e\x01\0\xC6H\x01\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0g\0\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6\x05\0\0\xC6H\x01\0\xC6H\x01\0\xC6fD(\xC6fD(\xC6fD(\xC6fD(\xC6fD(g\0\0\0g\0\0\0g\0\0\0\xC6H\x01\0\xC6H\x01\0\xC6fD(\xC6h\0\0\xC6fD(\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\x17\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6\t\0\0\xC6fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x83\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x0B\0\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x19\x10\0#\x19\x10\0#\x19\x10\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0#\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0#\x17\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0e\n\0\0g\0\0\0e\x17\0\0e\x17\0\0e\x17\0\0e\x17\0\0e\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0e\x05\0\0e\x05\0\0e\x05\0\0e\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x05\0\0\xC1\x17\0\0\xC1\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(G0\0(GfD(G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0GfD(GfD(GfD(GfD(GfD(GfD(GfD(GfD(GfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0G\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0\x9C\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCA\x05\0\0\xCAfD(\xCAfD(\xCAfD(\xCAfD(\xCAfD(\xCAfD(\xCAfD(\xCAfD(\xCAfD(\xCAfD(\xCAH\x01\0\xCAH\x01\0\xCAH\x01\0\xCAfD(\xCAfD(\xCAfD(\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0\xCA\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0\x82\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0\x95\x05\0\0g\0\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0\x95\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x95\x17\0\0\x95\x17\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0\xC3\x05\0\0g\0\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0\xC3\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0\x86\x05\0\0g\0\0\0g\0\0\0\x86fD(\x86fD(\x86fD(\x86fD(\x86fD(\x86\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0KfD(KfD(KfD(KfD(KfD(KfD(KfD(K\x17\0\0K\x17\0\0K\x17\0\0K\x17\0\0K\x17\0\0K\x1B\0\0K\x1B\0\0K\x1B\0\0K\x1B\0\0K\x04\0\0K\x04\0\0K\x04\0\0K\x04\0\0K\x17\0\0K\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0K\t\0\0g\0\0\0K\x0B\0\0K\x0B\0\0K\x0B\0\0K\x0B\0\0K\x0B\0\0g\0\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0K\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0K\x05\0\0K\x05\0\0K\x05\0\0\xCB\x04\0\0\xCB\x04\0\0\xCB\x04\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB%\x01\0\xCB\x05\0\0\xCB\x05\0\0\xCB\x05\0\0\xCB%\x01\0\xCB%\x01\0\xCB%\x01\0\xCB%\x01\0\xCB\x04\0\0\xCB\x04\0\0\xCB\x17\0\0\xCB\x17\0\0\xCB\x17\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0\xCB\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x01\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x02\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x0B\0\0\xB5\x17\0\0\xB5\x17\0\0\xB5\x17\0\0\xB5\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0\xD0\x01\0\0g\0\0\0g\0\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0\xD0\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0\\\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\\fD(\\\x05\0\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0\\H\x01\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\\fD(\\fD(\\fD(\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\\\x04\0\0\x9A\x04\0\0\x96\x04\0\0\x11\x17(\0\x11\x04\0\0\xBFfD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x11h\0\0\x11h\0\0\x11\x04\0\0\x11\x04\0\0\x11\n\0\0\x11\n\0\0\x11\n\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0\xBF\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBF\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0\x9A\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0g\0\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0\x16\x04\0\0g\0\0\0\x16\x04\0\0\x16\x04\0\0g\0\0\0\x16\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x14\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x14\x05\0\0\x14\x05\0\0\x14\x05\0\0g\0\0\0g\0\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0\x16\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0\x96\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0\x87\x05\0\0g\0\0\0g\0\0\0\x87\x1B\0\0\x87fD(\x87fD(\x87\x17\0\0\x000H(\x000H(\x000H(\x000H(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x18(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\0\0g\0\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0h\0\0\0h\0\0\x01fD(\x01fD(\x01fD(\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0h\0\0\0h\0\0\0h\0\0\x000H(\x000H(\x000H(\x000H(\x000H(\x000H(\x000H(\x000H(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\0\x1B\0\0\0\x1B\0\0\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\x01fD(\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\x01fD(\x01fD(\x01fD(\x01fD(\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0E\x1B(\0\x0EfD(\x0EfD(\x0EfD(\x0E\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0\0\x0B\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0g\0\0\0\0\x02\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x01\0\0g\0\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0g\0\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0g\0\0\0g\0\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x01\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x18(\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x02\0\0\0\x01\0\0\0\x02\0\0g\0\0\0g\0\0\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD(pfD(pfD(pfD(pfD(p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD(p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0p\x1B\0\0pfD(p\x1B\0\0p\x1B\0\0p\x17\0\0p\x17\0\0p\x17\0\0p\x17\0\0p\x17\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(pfD(\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x05\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0\x19\x02\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\08fD(8fD(8fD(8fD(8fD(8fD(8fD(g\0\0\08fD(8fD(8fD(8fD(8fD(8fD(8fD(8fD(8fD(g\0\0\0g\0\0\08fD(8fD(8fD(8fD(8fD(g\0\0\08fD(8fD(g\0\0\08fD(8fD(8fD(8fD(8fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0\x08\x04\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\x08fD(\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0\xBA\x05\0\0g\0\0\0g\0\0\0g\0\0\0\xBAfD(\xBAfD(\xBAfD(\xBAfD(\xBAfD(\xBAfD(\xBAfD(\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0\xBA\x04\0\0g\0\0\0g\0\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0\xBA\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBA\x05\0\0\xBA\x1B\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4\x05\0\0\xC4fD(g\0\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBC\x05\0\0\xBCfD(\xBCfD(\xBCfD(\xBCfD(\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0\xBC\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xBC\x19\x10\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x05\0\0\xC7\x04\0\0\xC7fD(\xC7fD(\xC7fD(\xC7fD(\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0\xC7\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCC\x05\0\0\xCCfD(\xCCfD(\xCC\x05\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0\xCC\t\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xCC\x17\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0g\0\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2fD(\xD2\x05\0\0\xD2\x05\0\0\xD2fD(\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2fD(\xD2fD(\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2\x05\0\0\xD2fD(g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0\xD2\x05\0\0\xD2\x04\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x0B\x05\0\0\x0B\x05\0\0g\0\0\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0\x8C\x05\x04\0g\0\x04\0g\0\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8C\x0B\x04\0\x8CfD(\x8CfD(\x8CfD(\x8CfD(\x8CfD(\x8CfD(\x8CfD(g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x01\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7\x02\x04\x10\xA7fD(\xA7fD(\xA7fD(\xA7fD(\xA7fD(\xA7fD(\xA7fD(\xA7\x04\x04(g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0\xA7\t\x04\0g\0\x04\0g\0\x04\0g\0\x04\0g\0\x04\0\xA7\x17\x04\0\xA7\x17\x04\0g\x004\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x1B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x194\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0\0\x0B4\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0g\x004\0g\x004\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0g\x004\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x054\0\x02\x18(\0\x02\x18(\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0g\x004\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B\x08\0\0\x0B(\0\0\x0B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\0\x9B\x81\x02\x14\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\x80\0\0\x1B\0\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0\0\x1B\0\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\x80\0\0\x1B\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0z\xA8\0\0z\xA8\0\0z\xA8\0\0z\xA8\0\0z\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0\0\x18(\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B(\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0\0\x1B\xA8\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0\0\x1B\xA8\0\0\x1B(\0\0\x1B(\0\0\x1B(\0g\0\0\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\x1B(\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\t\x08\0\0\x1B(\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0\x80\0g\0H\0g\0H\0\x11\x05\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0\0\0g\0H\0g\0H\0g H\0\x000H(g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0g H\0\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(\0pH(g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\x11\0\0g\0H\0g\0H\0g\0H\0\0\0\0\0") } , 0u32) };
@@ -5,25 +5,88 @@
 //!
 //! - Re-exported ICU4X data providers for grapheme, word, and line breaking, plus Unicode normalization tables used by Parley.
 //! - A locale-invariant `CompositeProps` provider backed by a compact `CodePointTrie`, allowing the engine to obtain all required character properties with a single lookup.
+//! - The compact [`dictionary`] format used for user supplied word segmentation dictionaries.
+//! - The [`blob`] format used to load all of the above at runtime instead of baking it into the binary.
 
 #![no_std]
 
-use icu_properties::props::{BidiClass, GeneralCategory, GraphemeClusterBreak, Script};
+#[cfg(feature = "runtime")]
+extern crate alloc;
+
+use icu_properties::props::{
+    BidiClass, GeneralCategory, GraphemeClusterBreak, JoiningType, Script,
+};
 
 /// Baked data.
 #[cfg(feature = "baked")]
 pub mod generated;
 
+pub mod blob;
+pub mod dictionary;
+
 /// Lookup for [`Properties`]
-#[derive(Clone, Debug, Copy)]
-pub struct CompositeProps;
+#[derive(Clone)]
+pub struct CompositeProps {
+    source: CompositeSource,
+}
+
+#[derive(Clone)]
+enum CompositeSource {
+    #[cfg(feature = "baked")]
+    Baked,
+    #[cfg(feature = "runtime")]
+    Runtime(alloc::sync::Arc<RuntimeTrie>),
+}
+
+#[cfg(feature = "runtime")]
+type RuntimeTrie = yoke::Yoke<
+    icu_collections::codepointtrie::CodePointTrie<'static, u32>,
+    alloc::boxed::Box<[u8]>,
+>;
+
+impl core::fmt::Debug for CompositeProps {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompositeProps").finish_non_exhaustive()
+    }
+}
 
-#[cfg(feature = "baked")]
 impl CompositeProps {
+    /// Returns the lookup backed by the baked data.
+    #[cfg(feature = "baked")]
+    pub const fn baked() -> Self {
+        Self {
+            source: CompositeSource::Baked,
+        }
+    }
+
+    /// Creates a lookup from a postcard serialized `CodePointTrie`, as stored
+    /// in the composite section of a [`blob`].
+    ///
+    /// Returns `None` if the data cannot be deserialized.
+    #[cfg(feature = "runtime")]
+    pub fn try_from_postcard(data: alloc::boxed::Box<[u8]>) -> Option<Self> {
+        let trie = RuntimeTrie::try_attach_to_cart(data, |data| postcard::from_bytes(data)).ok()?;
+        Some(Self {
+            source: CompositeSource::Runtime(alloc::sync::Arc::new(trie)),
+        })
+    }
+
     /// Returns the properties for a given character.
     #[inline(always)]
+    #[cfg_attr(
+        not(any(feature = "baked", feature = "runtime")),
+        expect(
+            unused_variables,
+            reason = "A `CompositeProps` cannot be created without the `baked` or `runtime` feature."
+        )
+    )]
     pub fn properties(&self, ch: u32) -> Properties {
-        Properties(generated::COMPOSITE.get32(ch))
+        match self.source {
+            #[cfg(feature = "baked")]
+            CompositeSource::Baked => Properties(generated::COMPOSITE.get32(ch)),
+            #[cfg(feature = "runtime")]
+            CompositeSource::Runtime(ref trie) => Properties(trie.get().get32(ch)),
+        }
     }
 }
 
@@ -40,6 +103,7 @@ impl Properties {
     const IS_VARIATION_SELECTOR_BITS: u32 = 1;
     const IS_REGION_INDICATOR_BITS: u32 = 1;
     const IS_MANDATORY_LINE_BREAK_BITS: u32 = 1;
+    const JOINING_TYPE_BITS: u32 = 3;
 
     const SCRIPT_SHIFT: u32 = 0;
     const GC_SHIFT: u32 = Self::SCRIPT_SHIFT + Self::SCRIPT_BITS;
@@ -52,6 +116,8 @@ impl Properties {
         Self::IS_VARIATION_SELECTOR_SHIFT + Self::IS_VARIATION_SELECTOR_BITS;
     const IS_MANDATORY_LINE_BREAK_SHIFT: u32 =
         Self::IS_REGION_INDICATOR_SHIFT + Self::IS_REGION_INDICATOR_BITS;
+    const JOINING_TYPE_SHIFT: u32 =
+        Self::IS_MANDATORY_LINE_BREAK_SHIFT + Self::IS_MANDATORY_LINE_BREAK_BITS;
 
     /// Packs the given arguments into a single u32.
     #[cfg(feature = "datagen")]
@@ -64,11 +130,13 @@ impl Properties {
         is_variation_selector: bool,
         is_region_indicator: bool,
         is_mandatory_linebreak: bool,
+        joining_type: JoiningType,
     ) -> Self {
         let s = script.to_icu4c_value() as u32;
         let gc = gc as u32;
         let gcb = gcb.to_icu4c_value() as u32;
         let bidi = bidi.to_icu4c_value() as u32;
+        let jt = joining_type.to_icu4c_value() as u32;
 
         Self(
             (s << Self::SCRIPT_SHIFT)
@@ -78,7 +146,8 @@ impl Properties {
                 | ((is_emoji_or_pictographic as u32) << Self::IS_EMOJI_OR_PICTOGRAPH_SHIFT)
                 | ((is_variation_selector as u32) << Self::IS_VARIATION_SELECTOR_SHIFT)
                 | ((is_region_indicator as u32) << Self::IS_REGION_INDICATOR_SHIFT)
-                | ((is_mandatory_linebreak as u32) << Self::IS_MANDATORY_LINE_BREAK_SHIFT),
+                | ((is_mandatory_linebreak as u32) << Self::IS_MANDATORY_LINE_BREAK_SHIFT)
+                | (jt << Self::JOINING_TYPE_SHIFT),
         )
     }
 
@@ -162,6 +231,18 @@ impl Properties {
             Self::IS_MANDATORY_LINE_BREAK_BITS,
         ) != 0
     }
+
+    /// Returns the joining type for the character.
+    #[inline(always)]
+    pub fn joining_type(&self) -> JoiningType {
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "joining type data only occupies JOINING_TYPE_BITS bits"
+        )]
+        JoiningType::from_icu4c_value(
+            self.get(Self::JOINING_TYPE_SHIFT, Self::JOINING_TYPE_BITS) as u8
+        )
+    }
 }
 
 impl From<Properties> for u32 {
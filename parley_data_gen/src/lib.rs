@@ -4,19 +4,21 @@
 //! See `./main.rs`.
 
 use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
-use icu_collections::codepointtrie::TrieType;
+use icu_collections::codepointtrie::{CodePointTrie, TrieType};
 use icu_locale::LocaleFallbacker;
 use icu_properties::props::{GeneralCategory, GraphemeClusterBreak, Script};
 use icu_properties::{
     CodePointMapData, CodePointSetData,
     props::{
-        BidiClass, Emoji, ExtendedPictographic, LineBreak, RegionalIndicator, VariationSelector,
+        BidiClass, Emoji, ExtendedPictographic, JoiningType, LineBreak, RegionalIndicator,
+        VariationSelector,
     },
 };
 use icu_provider_export::prelude::*;
 use icu_provider_source::SourceDataProvider;
-use parley_data::Properties;
+use parley_data::{Properties, blob, dictionary};
 use std::io::{BufWriter, Write};
+use std::sync::{Arc, Mutex};
 
 const COPYRIGHT_HEADER: &str =
     "// Copyright 2025 the Parley Authors\n// SPDX-License-Identifier: Apache-2.0 OR MIT\n";
@@ -31,37 +33,19 @@ pub fn generate(out: std::path::PathBuf) {
         let icu4x_data_dir = out.clone().join("icu4x_data");
         std::fs::create_dir_all(&icu4x_data_dir).unwrap();
 
-        ExportDriver::new(
-            [DataLocaleFamily::single(DataLocale::default())],
-            DeduplicationStrategy::None.into(),
-            LocaleFallbacker::new_without_data(),
-        )
-        .with_markers([
-            icu_properties::provider::PropertyEnumBidiMirroringGlyphV1::INFO,
-            icu_properties::provider::PropertyNameShortScriptV1::INFO,
-            icu_segmenter::provider::SegmenterBreakGraphemeClusterV1::INFO,
-            icu_segmenter::provider::SegmenterBreakWordOverrideV1::INFO,
-            icu_segmenter::provider::SegmenterLstmAutoV1::INFO,
-            icu_segmenter::provider::SegmenterBreakWordV1::INFO,
-            icu_segmenter::provider::SegmenterBreakLineV1::INFO,
-            icu_normalizer::provider::NormalizerNfcV1::INFO,
-            icu_normalizer::provider::NormalizerNfdDataV1::INFO,
-            icu_normalizer::provider::NormalizerNfdSupplementV1::INFO,
-            icu_normalizer::provider::NormalizerNfdTablesV1::INFO,
-        ])
-        .with_segmenter_models([])
-        .export(
-            &icu4x_source_provider,
-            icu_provider_export::baked_exporter::BakedExporter::new(icu4x_data_dir.clone(), {
-                let mut o = icu_provider_export::baked_exporter::Options::default();
-                o.overwrite = true;
-                o.use_separate_crates = true;
-                o.pretty = true;
-                o
-            })
-            .unwrap(),
-        )
-        .expect("Datagen should be successful");
+        export_driver()
+            .export(
+                &icu4x_source_provider,
+                icu_provider_export::baked_exporter::BakedExporter::new(icu4x_data_dir.clone(), {
+                    let mut o = icu_provider_export::baked_exporter::Options::default();
+                    o.overwrite = true;
+                    o.use_separate_crates = true;
+                    o.pretty = true;
+                    o
+                })
+                .unwrap(),
+            )
+            .expect("Datagen should be successful");
         std::fs::write(
             icu4x_data_dir.clone().join("mod.rs"),
             COPYRIGHT_HEADER.to_string()
@@ -73,37 +57,7 @@ pub fn generate(out: std::path::PathBuf) {
 
     // Generate `CompositeProps` data
     {
-        // Dense values table for 0..=0x10FFFF
-        let mut values = Vec::<u32>::with_capacity(0x110000);
-        for cp in 0_u32..=0x10FFFF {
-            let v = Properties::new(
-                CodePointMapData::<Script>::new().get32(cp),
-                CodePointMapData::<GeneralCategory>::new().get32(cp),
-                CodePointMapData::<GraphemeClusterBreak>::new().get32(cp),
-                CodePointMapData::<BidiClass>::new().get32(cp),
-                CodePointSetData::new::<Emoji>().contains32(cp)
-                    || CodePointSetData::new::<ExtendedPictographic>().contains32(cp),
-                CodePointSetData::new::<VariationSelector>().contains32(cp),
-                CodePointSetData::new::<RegionalIndicator>().contains32(cp),
-                // See: https://github.com/unicode-org/icu4x/blob/ee5399a77a6b94efb5d4b60678bb458c5eedb25d/components/segmenter/src/line.rs#L338-L351
-                matches!(
-                    CodePointMapData::<LineBreak>::new().get32(cp),
-                    LineBreak::MandatoryBreak
-                        | LineBreak::CarriageReturn
-                        | LineBreak::LineFeed
-                        | LineBreak::NextLine
-                ),
-            );
-            values.push(v.into());
-        }
-
-        let trie = CodePointTrieBuilder {
-            data: CodePointTrieBuilderData::ValuesByCodePoint(&values),
-            default_value: 0, // not observed; we filled all entries
-            error_value: 0,
-            trie_type: TrieType::Small,
-        }
-        .build();
+        let trie = composite_trie();
 
         let composite_dir = out.join("composite");
         if !composite_dir.exists() {
@@ -137,3 +91,127 @@ pub fn generate(out: std::path::PathBuf) {
     writeln!(&mut file, "pub use composite::*;").unwrap();
     writeln!(&mut file, "pub use icu4x_data::*;").unwrap();
 }
+
+/// Builds the data that Parley loads at runtime when the baked data is not
+/// used, in the format described in [`parley_data::blob`].
+pub fn generate_blob() -> Vec<u8> {
+    /// Collects the output of the blob exporter.
+    #[derive(Clone, Default)]
+    struct Sink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let sink = Sink::default();
+    export_driver()
+        .export(
+            &SourceDataProvider::new(),
+            icu_provider_export::blob_exporter::BlobExporter::new_with_sink(Box::new(sink.clone())),
+        )
+        .expect("Datagen should be successful");
+    let icu4x_data = core::mem::take(&mut *sink.0.lock().unwrap());
+
+    let composite = postcard::to_allocvec(&composite_trie()).unwrap();
+    let composite_len = u32::try_from(composite.len()).expect("composite data fits in a u32");
+
+    let mut data = Vec::with_capacity(blob::MAGIC.len() + 5 + composite.len() + icu4x_data.len());
+    data.extend_from_slice(blob::MAGIC);
+    data.push(blob::VERSION);
+    data.extend_from_slice(&composite_len.to_le_bytes());
+    data.extend_from_slice(&composite);
+    data.extend_from_slice(&icu4x_data);
+    data
+}
+
+/// Returns an export driver for the ICU4X data that Parley requires.
+fn export_driver() -> ExportDriver {
+    ExportDriver::new(
+        [DataLocaleFamily::single(DataLocale::default())],
+        DeduplicationStrategy::None.into(),
+        LocaleFallbacker::new_without_data(),
+    )
+    .with_markers([
+        icu_properties::provider::PropertyEnumBidiMirroringGlyphV1::INFO,
+        icu_properties::provider::PropertyNameShortScriptV1::INFO,
+        icu_segmenter::provider::SegmenterBreakGraphemeClusterV1::INFO,
+        icu_segmenter::provider::SegmenterBreakWordOverrideV1::INFO,
+        icu_segmenter::provider::SegmenterLstmAutoV1::INFO,
+        icu_segmenter::provider::SegmenterBreakWordV1::INFO,
+        icu_segmenter::provider::SegmenterBreakLineV1::INFO,
+        icu_normalizer::provider::NormalizerNfcV1::INFO,
+        icu_normalizer::provider::NormalizerNfdDataV1::INFO,
+        icu_normalizer::provider::NormalizerNfdSupplementV1::INFO,
+        icu_normalizer::provider::NormalizerNfdTablesV1::INFO,
+    ])
+    .with_segmenter_models([])
+}
+
+/// Builds the trie backing `CompositeProps`.
+fn composite_trie() -> CodePointTrie<'static, u32> {
+    // Dense values table for 0..=0x10FFFF
+    let mut values = Vec::<u32>::with_capacity(0x110000);
+    for cp in 0_u32..=0x10FFFF {
+        let v = Properties::new(
+            CodePointMapData::<Script>::new().get32(cp),
+            CodePointMapData::<GeneralCategory>::new().get32(cp),
+            CodePointMapData::<GraphemeClusterBreak>::new().get32(cp),
+            CodePointMapData::<BidiClass>::new().get32(cp),
+            CodePointSetData::new::<Emoji>().contains32(cp)
+                || CodePointSetData::new::<ExtendedPictographic>().contains32(cp),
+            CodePointSetData::new::<VariationSelector>().contains32(cp),
+            CodePointSetData::new::<RegionalIndicator>().contains32(cp),
+            // See: https://github.com/unicode-org/icu4x/blob/ee5399a77a6b94efb5d4b60678bb458c5eedb25d/components/segmenter/src/line.rs#L338-L351
+            matches!(
+                CodePointMapData::<LineBreak>::new().get32(cp),
+                LineBreak::MandatoryBreak
+                    | LineBreak::CarriageReturn
+                    | LineBreak::LineFeed
+                    | LineBreak::NextLine
+            ),
+            CodePointMapData::<JoiningType>::new().get32(cp),
+        );
+        values.push(v.into());
+    }
+
+    CodePointTrieBuilder {
+        data: CodePointTrieBuilderData::ValuesByCodePoint(&values),
+        default_value: 0, // not observed; we filled all entries
+        error_value: 0,
+        trie_type: TrieType::Small,
+    }
+    .build()
+}
+
+/// Builds a word segmentation dictionary in the compact format described in
+/// [`parley_data::dictionary`] from a newline separated word list.
+///
+/// Surrounding whitespace is trimmed from every line, and empty lines or lines
+/// starting with `#` are ignored. Duplicate words are removed.
+pub fn generate_dictionary(words: &str) -> Vec<u8> {
+    let mut words: Vec<&str> = words
+        .lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty() && !word.starts_with('#'))
+        .collect();
+    words.sort_unstable();
+    words.dedup();
+
+    let mut data = Vec::with_capacity(
+        dictionary::MAGIC.len() + 1 + words.iter().map(|word| word.len() + 1).sum::<usize>(),
+    );
+    data.extend_from_slice(dictionary::MAGIC);
+    data.push(dictionary::VERSION);
+    for word in words {
+        data.extend_from_slice(word.as_bytes());
+        data.push(b'\n');
+    }
+    debug_assert!(dictionary::DictionaryData::new(&data).is_some());
+    data
+}
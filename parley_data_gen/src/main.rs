@@ -4,6 +4,9 @@
 //! A small CLI that refreshes the Unicode artefacts checked into the `parley_data` crate.
 //! It pulls data from the canonical ICU4X upstream sources, recomputes Parley's composite property trie, and
 //! writes Rust modules that can be embedded directly into the repository.
+//!
+//! It can also compile a word list into a compact word segmentation dictionary, and emit all of the
+//! Unicode data as a single blob, both of which can be loaded at runtime.
 
 fn main() {
     use std::{env, ffi::OsString, path::PathBuf, process};
@@ -15,9 +18,60 @@ fn main() {
 
     let Some(out_arg) = args.next() else {
         eprintln!("Usage: {} <output-dir>", exe.to_string_lossy());
+        eprintln!(
+            "       {} dictionary <word-list> <output-file>",
+            exe.to_string_lossy()
+        );
+        eprintln!("       {} blob <output-file>", exe.to_string_lossy());
         process::exit(1);
     };
 
+    if out_arg == "blob" {
+        let Some(output) = args.next() else {
+            eprintln!("Usage: {} blob <output-file>", exe.to_string_lossy());
+            process::exit(1);
+        };
+        if let Err(err) = std::fs::write(&output, parley_data_gen::generate_blob()) {
+            eprintln!(
+                "Failed to write blob '{}': {}",
+                PathBuf::from(output).display(),
+                err
+            );
+            process::exit(1);
+        }
+        return;
+    }
+
+    if out_arg == "dictionary" {
+        let (Some(input), Some(output)) = (args.next(), args.next()) else {
+            eprintln!(
+                "Usage: {} dictionary <word-list> <output-file>",
+                exe.to_string_lossy()
+            );
+            process::exit(1);
+        };
+        let words = match std::fs::read_to_string(&input) {
+            Ok(words) => words,
+            Err(err) => {
+                eprintln!(
+                    "Failed to read word list '{}': {}",
+                    PathBuf::from(input).display(),
+                    err
+                );
+                process::exit(1);
+            }
+        };
+        if let Err(err) = std::fs::write(&output, parley_data_gen::generate_dictionary(&words)) {
+            eprintln!(
+                "Failed to write dictionary '{}': {}",
+                PathBuf::from(output).display(),
+                err
+            );
+            process::exit(1);
+        }
+        return;
+    }
+
     let out_path = PathBuf::from(out_arg);
 
     if let Err(err) = std::fs::create_dir_all(&out_path) {
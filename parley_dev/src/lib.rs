@@ -18,6 +18,14 @@ pub fn font_dirs() -> impl Iterator<Item = PathBuf> {
     .into_iter()
 }
 
+/// The path to the Unicode data blob used to test loading data at runtime.
+///
+/// This is regenerated with `cargo run -p parley_data_gen -- blob <path>`
+/// whenever the baked data in `parley_data` is updated.
+pub fn unicode_data_blob() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/unicode_data/parley_unicode_data.blob")
+}
+
 /// The font families that are available in the assets/fonts directory.
 pub const FONT_FAMILIES: &[&str] = &["Arimo", "Roboto", "Noto Kufi Arabic"];
 
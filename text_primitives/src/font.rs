@@ -398,6 +398,56 @@ impl fmt::Display for FontStyle {
     }
 }
 
+/// Control over automatic optical sizing of variable fonts.
+///
+/// When set to [`Auto`](Self::Auto), the `opsz` axis of fonts that have one is set to match the
+/// font size.
+///
+/// In CSS, this corresponds to the `font-optical-sizing` property:
+/// <https://www.w3.org/TR/css-fonts-4/#font-optical-sizing-def>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FontOpticalSizing {
+    /// The `opsz` axis is set from the font size.
+    #[default]
+    Auto,
+    /// Optical sizing is only applied through explicit font variation settings.
+    None,
+}
+
+impl FontOpticalSizing {
+    /// Parses a CSS `font-optical-sizing` value.
+    ///
+    /// Supported syntax (after trimming ASCII whitespace):
+    /// - `auto` → `FontOpticalSizing::Auto`
+    /// - `none` → `FontOpticalSizing::None`
+    ///
+    /// This parser is case-sensitive.
+    ///
+    /// ```
+    /// use text_primitives::FontOpticalSizing;
+    ///
+    /// assert_eq!(FontOpticalSizing::parse_css("auto"), Some(FontOpticalSizing::Auto));
+    /// assert_eq!(FontOpticalSizing::parse_css(" none "), Some(FontOpticalSizing::None));
+    /// assert_eq!(FontOpticalSizing::parse_css("normal"), None);
+    /// ```
+    pub fn parse_css(s: &str) -> Option<Self> {
+        match s.trim() {
+            "auto" => Some(Self::Auto),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FontOpticalSizing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => f.write_str("auto"),
+            Self::None => f.write_str("none"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
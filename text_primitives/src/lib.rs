@@ -50,10 +50,13 @@ mod tag;
 mod text;
 
 pub use bidi::{BidiControl, BidiDirection, BidiOverride};
-pub use font::{FontStyle, FontWeight, FontWidth};
+pub use font::{FontOpticalSizing, FontStyle, FontWeight, FontWidth};
 pub use font_family::{FontFamily, FontFamilyName, ParseFontFamilyError, ParseFontFamilyErrorKind};
 pub use generic_family::GenericFamily;
 pub use language::{Language, ParseLanguageError};
 pub use script::{ParseScriptError, Script};
 pub use tag::{FontFeature, FontVariation, ParseSettingsError, ParseSettingsErrorKind, Tag};
-pub use text::{BaseDirection, OverflowWrap, TextWrapMode, WordBreak};
+pub use text::{
+    BaseDirection, BreakInside, InitialLetter, LineBreak, OverflowWrap, TextWrapMode,
+    TextWrapStyle, WordBreak,
+};
@@ -27,6 +27,27 @@ pub enum WordBreak {
     KeepAll,
 }
 
+/// Control over the strictness of line-breaking rules, named for the CSS property.
+///
+/// This mostly affects CJK text, for example whether a line may start with a small kana or a
+/// prolonged sound mark.
+///
+/// See: <https://www.w3.org/TR/css-text-3/#line-break-property>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LineBreak {
+    /// The default rules of the line breaker.
+    #[default]
+    Auto,
+    /// The least restrictive rules, typically used for short lines such as in newspapers.
+    Loose,
+    /// The most common set of rules.
+    Normal,
+    /// The most stringent set of rules.
+    Strict,
+    /// A soft wrap opportunity exists around every typographic character unit.
+    Anywhere,
+}
+
 /// Control over "emergency" line-breaking.
 ///
 /// See: <https://www.w3.org/TR/css-text-3/#overflow-wrap-property>
@@ -52,3 +73,63 @@ pub enum TextWrapMode {
     /// Do not wrap at soft-wrap opportunities.
     NoWrap,
 }
+
+/// Control over how wrapped lines are balanced.
+///
+/// See: <https://www.w3.org/TR/css-text-4/#text-wrap-style>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TextWrapStyle {
+    /// Fill each line as much as possible before wrapping.
+    #[default]
+    Auto,
+    /// Equalize the lengths of the lines, keeping the number of lines unchanged.
+    ///
+    /// Intended for short blocks of text such as headings and captions.
+    Balance,
+    /// Choose line breaks that avoid very short last lines, at the expense of speed.
+    Pretty,
+}
+
+/// Control over page and column breaks inside a span of text.
+///
+/// See: <https://www.w3.org/TR/css-break-3/#break-within>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BreakInside {
+    /// Breaks are allowed between the lines of the text.
+    #[default]
+    Auto,
+    /// Avoid breaks between the lines of the text, keeping them together.
+    Avoid,
+}
+
+/// The size and sink of an initial letter, also known as a drop cap.
+///
+/// See: <https://www.w3.org/TR/css-inline-3/#sizing-drop-initials>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InitialLetter {
+    /// The number of lines the letter spans, from the cap height of the first line to the
+    /// baseline of the last line.
+    pub size: f32,
+    /// The number of lines the letter sinks into, which sets the line whose baseline the letter
+    /// sits on. Lines beside the letter are indented by its advance.
+    pub sink: u32,
+}
+
+impl InitialLetter {
+    /// Creates an initial letter that spans and sinks into `size` lines.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "The sink is the integral part of the size"
+    )]
+    pub fn new(size: f32) -> Self {
+        Self {
+            size,
+            sink: size.max(1.) as u32,
+        }
+    }
+
+    /// Creates an initial letter that spans `size` lines and sinks into `sink` lines.
+    pub fn with_sink(size: f32, sink: u32) -> Self {
+        Self { size, sink }
+    }
+}